@@ -0,0 +1,64 @@
+//! Benchmark of endpoint URL construction, run with `cargo bench --bench url_builder`.
+//!
+//! Prints the time per URL for a few typical endpoints. Building a URL should stay well under a
+//! microsecond; a large jump means the builder started allocating again.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use loc_api::attribute_models::{AttributesSelect, SortField};
+use loc_api::endpoints::Endpoints;
+use loc_api::format_models::{Format, MediaType};
+use loc_api::param_models::{CommonParams, Facet, FacetReq, ItemParams, SearchParams};
+
+const ITERATIONS: u32 = 200_000;
+
+fn main() {
+    let common = CommonParams {
+        format: Some(Format::Json),
+        attributes: Some(AttributesSelect {
+            include: vec!["pagination".to_string(), "results".to_string()],
+            exclude: vec![],
+        }),
+        query: Some("baseball cards & trading".to_string()),
+        filter: Some(FacetReq {
+            filters: vec![
+                Facet::Subject { value: "sports".to_string() },
+                Facet::Location { value: "new york".to_string() },
+            ],
+        }),
+        per_page: Some(100),
+        page: Some(3),
+        sort: Some(SortField::DateDesc.into()),
+    };
+    let endpoints = [
+        ("search", Endpoints::Search(SearchParams { common: common.clone(), include_collections: true })),
+        ("collection", Endpoints::Collection { name: "civil war maps".to_string(), params: common.clone() }),
+        ("format", Endpoints::Format { format: MediaType::Maps, params: common }),
+        ("item", Endpoints::Item { item_id: "2014717546".to_string(), params: ItemParams::default() }),
+    ];
+
+    for (name, endpoint) in &endpoints {
+        let mut url = String::new();
+        for _ in 0..ITERATIONS / 10 {
+            url.clear();
+            endpoint.write_url("https://www.loc.gov", &mut url);
+        }
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            black_box(black_box(endpoint).url_with_base(black_box("https://www.loc.gov")));
+        }
+        let fresh = start.elapsed() / ITERATIONS;
+
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            url.clear();
+            black_box(endpoint).write_url(black_box("https://www.loc.gov"), &mut url);
+            black_box(&url);
+        }
+        let reused = start.elapsed() / ITERATIONS;
+
+        println!("{:<12} url_with_base: {:>6?}/url   write_url (reused buffer): {:>6?}/url", name, fresh, reused);
+    }
+}
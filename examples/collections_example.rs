@@ -14,7 +14,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         None,
         Some(10),
         Some(1),
-        Some(SortField::TitleS),
+        SortField::TitleS.into(),
     )?;
 
     println!("url: {}", response.1);
@@ -2,18 +2,26 @@ use loc_api::loc_client::ApiClient;
 use loc_api::attribute_models::ItemAttributes;
 
 /// Example of retrieving an item response
+///
+/// Run with `-- --offline` to answer from the fixtures bundled with the crate.
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = ApiClient::new();
+    let client = if std::env::args().any(|arg| arg == "--offline") {
+        ApiClient::offline()
+    } else {
+        ApiClient::new()
+    };
     let response = client.get_item(
         "2014717546",
         Some(ItemAttributes {
             cite_this: Some(true),
             item: Some(true),
             resources: Some(true),
+            ..Default::default()
         }),
     )?;
 
     println!("url: {}", response.1);
+    println!("{}", response.0);
 
     // Handle the item details
     if let Some(item) = response.0.item {
@@ -17,7 +17,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
         Some(10),
         Some(1),
-        Some(SortField::TitleS),
+        SortField::TitleS.into(),
     )?;
 
     println!("url: {}", response.1);
@@ -0,0 +1,230 @@
+//! # Async Client Module
+//!
+//! [`AsyncApiClient`] is a non-blocking counterpart of
+//! [`ApiClient`](crate::loc_client::ApiClient), enabled with the `async` feature.
+//!
+//! It is built on `reqwest`'s async client, which uses the browser's `fetch` on
+//! `wasm32-unknown-unknown`, so the crate can be used from browser apps. The blocking client
+//! does not compile for that target; build with `default-features = false, features = ["async"]`.
+//!
+//! The async client covers the JSON endpoints only. Middlewares, hooks, metrics, retries,
+//! throttling and caching are features of the blocking client.
+
+use crate::identifiers::{resource_path, ItemId};
+use crate::{attribute_models::*, endpoints::*, format_models::*, param_models::*, response_models::*};
+use serde::de::DeserializeOwned;
+use std::env;
+use std::error::Error;
+
+/// The default base URL, the same as the blocking client's.
+const DEFAULT_BASE_URL: &str = "https://www.loc.gov/";
+
+/// An async client for the Library of Congress API.
+///
+/// Methods mirror those of [`ApiClient`](crate::loc_client::ApiClient) and return the
+/// deserialized JSON response together with the final URL.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use loc_api::async_client::AsyncApiClient;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = AsyncApiClient::new();
+/// let (response, url) = client.search("baseball", false, None, None, Some(25), Some(1), None).await?;
+/// println!("{} results from {}", response.results.map_or(0, |r| r.len()), url);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsyncApiClient {
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl Default for AsyncApiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncApiClient {
+    /// Creates a new [`AsyncApiClient`].
+    ///
+    /// The base URL can be overridden by setting the `LOC_API_BASE_URL` environment variable
+    /// (environment variables are never set on `wasm32-unknown-unknown`).
+    pub fn new() -> Self {
+        let base_url = env::var("LOC_API_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Self::with_base_url(base_url)
+    }
+
+    /// Creates a new [`AsyncApiClient`] sending its requests to `base_url`.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        AsyncApiClient {
+            base_url: base_url.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Performs a search query using the `/search/` endpoint.
+    ///
+    /// See [`ApiClient::search`](crate::loc_client::ApiClient::search) for the parameters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn search(
+        &self,
+        query: &str,
+        include_collections: bool,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(SearchResultResponse, String), Box<dyn Error>> {
+        let endpoint = Endpoints::Search(SearchParams {
+            common: list_params(Some(query), attributes, filters, per_page, page, sort),
+            include_collections,
+        });
+        self.fetch(&endpoint).await
+    }
+
+    /// Retrieves a specific item using the `/item/{item_id}/` endpoint.
+    ///
+    /// See [`ApiClient::get_item`](crate::loc_client::ApiClient::get_item) for the parameters.
+    pub async fn get_item(
+        &self,
+        item_id: impl Into<ItemId>,
+        attributes: Option<ItemAttributes>,
+    ) -> Result<(ItemResponse, String), Box<dyn Error>> {
+        let item_id = item_id.into();
+        if item_id.as_str().is_empty() {
+            return Err("An item id is required to retrieve an item".into());
+        }
+
+        let endpoint = Endpoints::Item {
+            item_id: item_id.to_string(),
+            params: ItemParams {
+                format: Some(Format::default()),
+                attributes,
+            },
+        };
+        self.fetch(&endpoint).await
+    }
+
+    /// Retrieves a specific resource using the `/resource/{resource_id}/` endpoint.
+    ///
+    /// See [`ApiClient::get_resource`](crate::loc_client::ApiClient::get_resource) for the parameters.
+    pub async fn get_resource(
+        &self,
+        resource_id: &str,
+        attributes: Option<ResourceAttributes>,
+        page: Option<u32>,
+    ) -> Result<(ResourceResponse, String), Box<dyn Error>> {
+        let resource_id = resource_path(resource_id);
+        if resource_id.is_empty() {
+            return Err("A resource id is required to retrieve a resource".into());
+        }
+
+        let endpoint = Endpoints::Resource {
+            resource_id: resource_id.to_string(),
+            params: ResourceParams {
+                format: Some(Format::default()),
+                attributes,
+                page,
+            },
+        };
+        self.fetch(&endpoint).await
+    }
+
+    /// Retrieves items of a specific media type using the `/{format}/` endpoint.
+    ///
+    /// See [`ApiClient::get_format`](crate::loc_client::ApiClient::get_format) for the parameters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_format(
+        &self,
+        format_type: MediaType,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(FormatResponse, String), Box<dyn Error>> {
+        let endpoint = Endpoints::Format {
+            format: format_type,
+            params: list_params(query, attributes, filters, per_page, page, sort),
+        };
+        self.fetch(&endpoint).await
+    }
+
+    /// Retrieves the items of a collection using the `/collections/{name}/` endpoint.
+    ///
+    /// See [`ApiClient::get_collection`](crate::loc_client::ApiClient::get_collection) for the parameters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_collection(
+        &self,
+        collection_name: &str,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(CollectionResponse, String), Box<dyn Error>> {
+        let endpoint = Endpoints::Collection {
+            name: collection_name.replace([' ', '_'], "-"),
+            params: list_params(query, attributes, filters, per_page, page, sort),
+        };
+        self.fetch(&endpoint).await
+    }
+
+    /// Lists collections using the `/collections/` endpoint.
+    ///
+    /// See [`ApiClient::get_collections`](crate::loc_client::ApiClient::get_collections) for the parameters.
+    pub async fn get_collections(
+        &self,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(CollectionsResponse, String), Box<dyn Error>> {
+        let endpoint = Endpoints::Collections(list_params(query, attributes, filters, per_page, page, sort));
+        self.fetch(&endpoint).await
+    }
+
+    /// Requests any [`Endpoints`] value and deserializes the JSON body into `T`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized body and the final URL on success.
+    pub async fn fetch<T: DeserializeOwned + 'static>(&self, endpoint: &Endpoints) -> Result<(T, String), Box<dyn Error>> {
+        endpoint.validate()?;
+        let url = endpoint.url_with_base(&self.base_url);
+
+        let response = self.client.get(&url).send().await?.error_for_status()?;
+        let mut body = response.bytes().await?.to_vec();
+        let json = from_slice::<T>(&mut body)?;
+        Ok((json, url))
+    }
+}
+
+/// Builds the [`CommonParams`] shared by the list endpoints.
+fn list_params(
+    query: Option<&str>,
+    attributes: Option<AttributesSelect>,
+    filters: Option<FacetReq>,
+    per_page: Option<u32>,
+    page: Option<u32>,
+    sort: Option<SortOrder>,
+) -> CommonParams {
+    CommonParams {
+        format: Some(Format::default()),
+        attributes,
+        query: query.map(|q| q.replace(' ', "+")),
+        filter: filters,
+        per_page,
+        page,
+        sort,
+    }
+}
@@ -1,7 +1,11 @@
+use std::error::Error;
+use std::fmt;
+
 use serde::{Serialize, Deserialize};
 
 /// Represents the possible attributes (query parameters) that can be used in API requests.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Attributes {
     /// Common attributes applicable to multiple endpoints.
     Common(CommonAttributes),
@@ -13,6 +17,7 @@ pub enum Attributes {
 
 /// Common attributes used across multiple endpoints.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CommonAttributes {
     /// Include facet information in the response (`at=facets`).
     pub facets: Option<bool>,
@@ -45,7 +50,8 @@ pub struct CommonAttributes {
 ///
 /// Fields like `additional` capture any extra data not explicitly defined in the struct.
 /// This ensures forward compatibility with potential future changes in the API response.
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ItemAttributes {
     /// Include citation information in the response (`at=cite_this`).
     pub cite_this: Option<bool>,
@@ -53,10 +59,38 @@ pub struct ItemAttributes {
     pub item: Option<bool>,
     /// Include resource links in the response (`at=resources`).
     pub resources: Option<bool>,
+    /// Nested attributes to include, as dot paths such as `item.title` (`at=item.title`).
+    ///
+    /// Selecting only the fields a job needs, e.g. `item.title,item.date`, shrinks the
+    /// response to a fraction of the full item.
+    #[serde(default)]
+    pub fields: Vec<String>,
+    /// Blocks to leave out of the response (`at!=`).
+    #[serde(default)]
+    pub exclude: ExcludedAttributes,
+}
+
+impl ItemAttributes {
+    /// Returns the `at` query pair listing the attributes set to `true` and the `at!` pair
+    /// listing the [`ItemAttributes::exclude`]d ones, in alphabetical order, or nothing if none is.
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        self.select().query_pairs()
+    }
+
+    /// Checks the [`ItemAttributes::fields`] and that no attribute is both included and
+    /// excluded, see [`AttributesSelect::validate`].
+    pub fn validate(&self) -> Result<Vec<String>, AttributesError> {
+        self.select().validate()
+    }
+
+    fn select(&self) -> AttributesSelect {
+        select(&[(self.cite_this, "cite_this"), (self.item, "item"), (self.resources, "resources")], &self.fields, &self.exclude)
+    }
 }
 
 /// Attributes for the Resource endpoint.
-#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ResourceAttributes {
     /// Include citation information in the response (`at=cite_this`).
     pub cite_this: Option<bool>,
@@ -70,10 +104,146 @@ pub struct ResourceAttributes {
     pub resources: Option<bool>,
     /// Include segment information in the response (`at=segments`).
     pub segments: Option<bool>,
+    /// Nested attributes to include, as dot paths such as `resource.title` (`at=resource.title`).
+    #[serde(default)]
+    pub fields: Vec<String>,
+    /// Blocks to leave out of the response (`at!=`).
+    #[serde(default)]
+    pub exclude: ExcludedAttributes,
+}
+
+impl ResourceAttributes {
+    /// Returns the `at` query pair listing the attributes set to `true` and the `at!` pair
+    /// listing the [`ResourceAttributes::exclude`]d ones, in alphabetical order, or nothing if
+    /// none is.
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        self.select().query_pairs()
+    }
+
+    /// Checks the [`ResourceAttributes::fields`] and that no attribute is both included and
+    /// excluded, see [`AttributesSelect::validate`].
+    pub fn validate(&self) -> Result<Vec<String>, AttributesError> {
+        self.select().validate()
+    }
+
+    fn select(&self) -> AttributesSelect {
+        select(
+            &[
+                (self.cite_this, "cite_this"),
+                (self.item, "item"),
+                (self.page, "page"),
+                (self.resource, "resource"),
+                (self.resources, "resources"),
+                (self.segments, "segments"),
+            ],
+            &self.fields,
+            &self.exclude,
+        )
+    }
+}
+
+/// Blocks excluded from an item or resource response (`at!=`), such as the `more_like_this`
+/// and `related_items` recommendations that make up much of an `/item/` response.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::attribute_models::{ExcludedAttributes, ItemAttributes};
+///
+/// let attrs = ItemAttributes {
+///     item: Some(true),
+///     exclude: ExcludedAttributes { more_like_this: Some(true), related_items: Some(true), ..Default::default() },
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     attrs.query_pairs(),
+///     vec![("at", "item".to_string()), ("at!", "more_like_this,related_items".to_string())]
+/// );
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExcludedAttributes {
+    /// Exclude related articles and essays (`at!=articles_and_essays`).
+    pub articles_and_essays: Option<bool>,
+    /// Exclude citation information (`at!=cite_this`).
+    pub cite_this: Option<bool>,
+    /// Exclude the item details (`at!=item`).
+    pub item: Option<bool>,
+    /// Exclude similar items (`at!=more_like_this`).
+    pub more_like_this: Option<bool>,
+    /// Exclude page information (`at!=page`).
+    pub page: Option<bool>,
+    /// Exclude related items (`at!=related_items`).
+    pub related_items: Option<bool>,
+    /// Exclude individual resource details (`at!=resource`).
+    pub resource: Option<bool>,
+    /// Exclude resource links (`at!=resources`).
+    pub resources: Option<bool>,
+    /// Exclude segment information (`at!=segments`).
+    pub segments: Option<bool>,
+    /// Exclude traditional knowledge labels (`at!=traditional_knowledge_labels`).
+    pub traditional_knowledge_labels: Option<bool>,
+}
+
+impl ExcludedAttributes {
+    /// Returns `true` if no block is excluded.
+    pub fn is_empty(&self) -> bool {
+        self.names().is_empty()
+    }
+
+    /// Returns the names of the blocks set to `true`, in alphabetical order.
+    pub fn names(&self) -> Vec<&'static str> {
+        flagged(&[
+            (self.articles_and_essays, "articles_and_essays"),
+            (self.cite_this, "cite_this"),
+            (self.item, "item"),
+            (self.more_like_this, "more_like_this"),
+            (self.page, "page"),
+            (self.related_items, "related_items"),
+            (self.resource, "resource"),
+            (self.resources, "resources"),
+            (self.segments, "segments"),
+            (self.traditional_knowledge_labels, "traditional_knowledge_labels"),
+        ])
+    }
+
+    /// Sets the flag of `name`, returning `false` if no block has that name.
+    pub fn set(&mut self, name: &str, excluded: bool) -> bool {
+        let flag = match name {
+            "articles_and_essays" => &mut self.articles_and_essays,
+            "cite_this" => &mut self.cite_this,
+            "item" => &mut self.item,
+            "more_like_this" => &mut self.more_like_this,
+            "page" => &mut self.page,
+            "related_items" => &mut self.related_items,
+            "resource" => &mut self.resource,
+            "resources" => &mut self.resources,
+            "segments" => &mut self.segments,
+            "traditional_knowledge_labels" => &mut self.traditional_knowledge_labels,
+            _ => return false,
+        };
+        *flag = Some(excluded);
+        true
+    }
+}
+
+/// Returns the names of the `(flag, name)` attributes set to `true`.
+fn flagged(attributes: &[(Option<bool>, &'static str)]) -> Vec<&'static str> {
+    attributes.iter().filter(|(enabled, _)| *enabled == Some(true)).map(|(_, name)| *name).collect()
+}
+
+/// Builds the [`AttributesSelect`] of the `(flag, name)` attributes and `fields` to include and
+/// the excluded blocks.
+fn select(include: &[(Option<bool>, &'static str)], fields: &[String], exclude: &ExcludedAttributes) -> AttributesSelect {
+    AttributesSelect {
+        include: flagged(include).into_iter().map(str::to_string).chain(fields.iter().cloned()).collect(),
+        exclude: exclude.names().into_iter().map(str::to_string).collect(),
+    }
 }
 
 /// Represents the possible sort fields for the `sort` attribute.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum SortField {
     #[serde(rename = "date")]
     Date, // Sort by date (earliest to latest) - sb=date
@@ -87,11 +257,14 @@ pub enum SortField {
     ShelfId, // Sort by shelf ID (call number/physical location) - sb=shelf_id
     #[serde(rename = "shelf_id_desc")]
     ShelfIdDesc, // Sort by shelf ID descending - sb=shelf_id_desc
+    /// A sort key not modeled by the other variants, passed through verbatim (`sb=<key>`).
+    #[serde(untagged)]
+    Custom(String),
 }
 
 impl SortField {
     /// Returns the corresponding slug used in the API URL for each sort field.
-    pub fn slug(&self) -> &'static str {
+    pub fn slug(&self) -> &str {
         match self {
             SortField::Date => "date",
             SortField::DateDesc => "date_desc",
@@ -99,16 +272,99 @@ impl SortField {
             SortField::TitleSDesc => "title_s_desc",
             SortField::ShelfId => "shelf_id",
             SortField::ShelfIdDesc => "shelf_id_desc",
+            SortField::Custom(key) => key,
         }
     }
+
+    /// Starts a multi-field [`SortOrder`] sorting by `self`, then by `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::attribute_models::SortField;
+    ///
+    /// let order = SortField::Date.then(SortField::TitleS);
+    /// assert_eq!(order.slug(), "date,title_s");
+    /// ```
+    pub fn then(self, next: SortField) -> SortOrder {
+        SortOrder::from(self).then(next)
+    }
+}
+
+impl std::str::FromStr for SortField {
+    type Err = std::convert::Infallible;
+
+    /// Parses an `sb` slug; keys without a typed variant become [`SortField::Custom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::attribute_models::SortField;
+    ///
+    /// assert_eq!("date_desc".parse::<SortField>().unwrap(), SortField::DateDesc);
+    /// assert_eq!("contributor".parse::<SortField>().unwrap(), SortField::Custom("contributor".into()));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let field = match s.trim() {
+            "date" => SortField::Date,
+            "date_desc" => SortField::DateDesc,
+            "title_s" => SortField::TitleS,
+            "title_s_desc" => SortField::TitleSDesc,
+            "shelf_id" => SortField::ShelfId,
+            "shelf_id_desc" => SortField::ShelfIdDesc,
+            other => SortField::Custom(other.to_string()),
+        };
+        Ok(field)
+    }
+}
+
+/// An ordered list of sort fields for the `sb` parameter, primary field first.
+///
+/// Serialized as a comma-separated list (`sb=date,title_s`).
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct SortOrder {
+    /// The sort fields, in order of precedence.
+    pub fields: Vec<SortField>,
+}
+
+impl SortOrder {
+    /// Creates a [`SortOrder`] from fields in order of precedence.
+    pub fn new(fields: impl IntoIterator<Item = SortField>) -> Self {
+        SortOrder { fields: fields.into_iter().collect() }
+    }
+
+    /// Appends `next` as a lower-precedence sort field.
+    pub fn then(mut self, next: SortField) -> Self {
+        self.fields.push(next);
+        self
+    }
+
+    /// Returns the value of the `sb` parameter.
+    pub fn slug(&self) -> String {
+        self.fields.iter().map(|f| f.slug()).collect::<Vec<&str>>().join(",")
+    }
+}
+
+impl From<SortField> for SortOrder {
+    fn from(field: SortField) -> Self {
+        SortOrder { fields: vec![field] }
+    }
+}
+
+impl From<SortField> for Option<SortOrder> {
+    fn from(field: SortField) -> Self {
+        Some(field.into())
+    }
 }
 
 /// Represents the selection of attributes to include or exclude in the response.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AttributesSelect {
-    /// Attributes to include in the response.
+    /// Attributes to include in the response, top-level names or dot paths to nested ones.
     ///
-    /// Example: `["item", "resources"]`
+    /// Example: `["item", "resources"]` or `["item.title", "item.date"]`
     pub include: Vec<String>,
     /// Attributes to exclude from the response.
     ///
@@ -120,6 +376,7 @@ impl AttributesSelect {
     /// Converts the [`AttributesSelect`] struct into a query parameter string.
     ///
     /// The function concatenates included attributes with commas and appends exclamation marks for exclusions.
+    /// Names are sorted and deduplicated, see [`AttributesSelect::query_pairs`].
     ///
     /// # Examples
     ///
@@ -133,16 +390,190 @@ impl AttributesSelect {
     /// assert_eq!(attrs.to_query_param(), "at=item,resources&at!=more_like_this");
     /// ```
     pub fn to_query_param(&self) -> String {
-        let mut parts = Vec::new();
+        self.query_pairs()
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Returns the `at` and `at!` query pairs, omitting empty lists.
+    ///
+    /// Attribute names are trimmed, sorted and deduplicated so that equivalent selections
+    /// produce the same URL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::attribute_models::AttributesSelect;
+    ///
+    /// let attrs = AttributesSelect {
+    ///     include: vec!["results".to_string(), "pagination".to_string(), "results".to_string()],
+    ///     exclude: vec![" ".to_string()],
+    /// };
+    /// assert_eq!(attrs.query_pairs(), vec![("at", "pagination,results".to_string())]);
+    /// ```
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let canonical = |names: &[String]| {
+            let mut names: Vec<&str> = names.iter().map(|name| name.trim()).filter(|name| !name.is_empty()).collect();
+            names.sort_unstable();
+            names.dedup();
+            names.join(",")
+        };
+
+        [("at", canonical(&self.include)), ("at!", canonical(&self.exclude))]
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .collect()
+    }
+
+    /// Checks the selection before it is sent.
+    ///
+    /// # Returns
+    ///
+    /// Returns the names whose first segment is not among [`KNOWN_ATTRIBUTES`], sorted, as
+    /// warnings: the API ignores them, which is usually a typo. Returns
+    /// [`AttributesError::InvalidPath`] if a name is not a dot path of `[a-z0-9_]` segments such
+    /// as `item.title`, and [`AttributesError::Conflict`] if an attribute is included while it
+    /// or one of its parents is excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::attribute_models::{AttributesError, AttributesSelect};
+    ///
+    /// let attrs = AttributesSelect {
+    ///     include: vec!["results".to_string(), "paginaton".to_string()],
+    ///     exclude: vec!["facets".to_string()],
+    /// };
+    /// assert_eq!(attrs.validate().unwrap(), vec!["paginaton"]);
+    ///
+    /// let attrs = AttributesSelect {
+    ///     include: vec!["results".to_string(), "facets".to_string()],
+    ///     exclude: vec![" facets".to_string()],
+    /// };
+    /// assert_eq!(attrs.validate(), Err(AttributesError::Conflict(vec!["facets".to_string()])));
+    ///
+    /// let attrs = AttributesSelect {
+    ///     include: vec!["item.title".to_string(), "item.date".to_string()],
+    ///     exclude: vec!["item".to_string()],
+    /// };
+    /// assert!(matches!(attrs.validate(), Err(AttributesError::Conflict(_))));
+    ///
+    /// let attrs = AttributesSelect { include: vec!["item..title".to_string()], exclude: vec![] };
+    /// assert_eq!(attrs.validate(), Err(AttributesError::InvalidPath("item..title".to_string())));
+    /// ```
+    pub fn validate(&self) -> Result<Vec<String>, AttributesError> {
+        let names = |names: &[String]| -> Vec<String> {
+            let mut names: Vec<String> =
+                names.iter().map(|name| name.trim()).filter(|name| !name.is_empty()).map(str::to_string).collect();
+            names.sort_unstable();
+            names.dedup();
+            names
+        };
+        let (include, exclude) = (names(&self.include), names(&self.exclude));
 
-        if !self.include.is_empty() {
-            parts.push(format!("at={}", self.include.join(",")));
+        if let Some(name) = include.iter().chain(&exclude).find(|name| !is_path(name)) {
+            return Err(AttributesError::InvalidPath(name.clone()));
         }
 
-        if !self.exclude.is_empty() {
-            parts.push(format!("at!={}", self.exclude.join(",")));
+        let conflicts: Vec<String> =
+            include.iter().filter(|name| exclude.iter().any(|excluded| within(name, excluded))).cloned().collect();
+        if !conflicts.is_empty() {
+            return Err(AttributesError::Conflict(conflicts));
         }
 
-        parts.join("&")
+        let mut unknown: Vec<String> = include
+            .into_iter()
+            .chain(exclude)
+            .filter(|name| {
+                let root = name.split('.').next().unwrap_or_default();
+                KNOWN_ATTRIBUTES.binary_search(&root).is_err()
+            })
+            .collect();
+        unknown.sort_unstable();
+        Ok(unknown)
     }
 }
+
+/// Returns `true` if `name` is a dot path of non-empty `[a-z0-9_]` segments.
+fn is_path(name: &str) -> bool {
+    name.split('.').all(|segment| {
+        !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+    })
+}
+
+/// Returns `true` if `path` is `ancestor` or nested under it.
+fn within(path: &str, ancestor: &str) -> bool {
+    path.strip_prefix(ancestor).is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// The top-level response attributes documented by loc.gov, sorted, as accepted by `at` and
+/// `at!`. Dot paths such as `item.title` start with one of them.
+pub const KNOWN_ATTRIBUTES: &[&str] = &[
+    "aka",
+    "articles_and_essays",
+    "breadcrumbs",
+    "browse",
+    "calendar_url",
+    "categories",
+    "cite_this",
+    "collection",
+    "content",
+    "content_is_post",
+    "expert_resources",
+    "facet_trail",
+    "facet_views",
+    "facets",
+    "form_facets",
+    "fulltext_service",
+    "item",
+    "locations",
+    "more_like_this",
+    "newspaper_holdings_url",
+    "next",
+    "next_issue",
+    "next_sibling",
+    "options",
+    "page",
+    "pages",
+    "pagination",
+    "previous",
+    "previous_issue",
+    "previous_sibling",
+    "related_items",
+    "resource",
+    "resources",
+    "results",
+    "search",
+    "segments",
+    "shards",
+    "timestamp",
+    "title",
+    "title_url",
+    "traditional_knowledge_labels",
+    "type",
+    "views",
+    "word_coordinates_pages",
+    "word_coordinates_query",
+];
+
+/// The error returned by [`AttributesSelect::validate`] for a selection the API cannot honor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributesError {
+    /// These attributes are included while they or one of their parents are excluded.
+    Conflict(Vec<String>),
+    /// This name is not a dot path of `[a-z0-9_]` segments.
+    InvalidPath(String),
+}
+
+impl fmt::Display for AttributesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributesError::Conflict(names) => write!(f, "attributes both included and excluded: {}", names.join(", ")),
+            AttributesError::InvalidPath(name) => write!(f, "invalid attribute path: `{}`", name),
+        }
+    }
+}
+
+impl Error for AttributesError {}
@@ -0,0 +1,258 @@
+//! The `loc` command-line tool, built with the `cli` feature.
+//!
+//! ```text
+//! cargo install loc_api --features cli
+//! loc search "baseball cards" --facet subject:sports --per-page 10
+//! loc item 2014717546
+//! ```
+//!
+//! Results are printed as a table of title, date and URL by default; `--output json`, `jsonl`
+//! and `csv` select formats for piping into other tools.
+//!
+//! Exit codes: `0` on success, `1` if a request failed, `2` for invalid arguments and `3` if
+//! loc.gov answered `404 Not Found`.
+
+use std::error::Error;
+use std::io::{self, Write};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use serde_json::Value;
+
+use loc_api::attribute_models::{SortField, SortOrder};
+use loc_api::export::{csv, jsonl};
+use loc_api::format_models::MediaType;
+use loc_api::identifiers::ItemId;
+use loc_api::loc_client::{ApiClient, ClientConfig, HttpStatusError};
+use loc_api::param_models::{Facet, FacetReq};
+
+/// Search and retrieve items from the Library of Congress loc.gov API.
+#[derive(Parser)]
+#[command(name = "loc", version)]
+struct Cli {
+    /// Base URL of the API, overriding the `LOC_API_BASE_URL` environment variable.
+    #[arg(long, global = true)]
+    base_url: Option<String>,
+
+    /// Timeout of a single request, in seconds.
+    #[arg(long, global = true)]
+    timeout: Option<f64>,
+
+    /// Output format.
+    #[arg(long, short, global = true, value_enum, default_value_t = Output::Table)]
+    output: Output,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Output {
+    /// A table of title, date and URL.
+    Table,
+    /// The whole response as pretty-printed JSON.
+    Json,
+    /// One JSON object per result.
+    Jsonl,
+    /// Title, date and URL as CSV with a header row.
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Search all of loc.gov.
+    Search {
+        /// The search query.
+        query: String,
+        /// Include collections in the results.
+        #[arg(long)]
+        include_collections: bool,
+        #[command(flatten)]
+        list: ListArgs,
+    },
+    /// Retrieve an item by ID, item URL or LCCN permalink.
+    Item {
+        /// The item ID, e.g. `2014717546`.
+        id: ItemId,
+    },
+    /// List the items of a collection.
+    Collection {
+        /// The collection name, e.g. `civil war maps`.
+        name: String,
+        /// Only list items matching this query.
+        #[arg(long, short)]
+        query: Option<String>,
+        #[command(flatten)]
+        list: ListArgs,
+    },
+    /// List the collections of loc.gov.
+    Collections {
+        /// Only list collections matching this query.
+        #[arg(long, short)]
+        query: Option<String>,
+        #[command(flatten)]
+        list: ListArgs,
+    },
+    /// List the items of a format, e.g. `maps`, `photos` or `newspapers`.
+    Format {
+        /// The format slug.
+        format: MediaType,
+        /// Only list items matching this query.
+        #[arg(long, short)]
+        query: Option<String>,
+        #[command(flatten)]
+        list: ListArgs,
+    },
+}
+
+/// Options shared by the list subcommands.
+#[derive(Args)]
+struct ListArgs {
+    /// Facet filter as `key:value` (e.g. `subject:baseball`), or `key!:value` to exclude. Repeatable.
+    #[arg(long = "facet", short = 'f', value_name = "FACET")]
+    facets: Vec<Facet>,
+    /// Number of results per page.
+    #[arg(long, short = 'c')]
+    per_page: Option<u32>,
+    /// Page number to retrieve.
+    #[arg(long, short = 'p')]
+    page: Option<u32>,
+    /// Sort field, e.g. `date_desc` or `title_s`. Repeatable; later fields break ties.
+    #[arg(long, short = 's')]
+    sort: Vec<SortField>,
+}
+
+impl ListArgs {
+    fn filters(&self) -> Option<FacetReq> {
+        (!self.facets.is_empty()).then(|| FacetReq { filters: self.facets.clone() })
+    }
+
+    fn sort(&self) -> Option<SortOrder> {
+        (!self.sort.is_empty()).then(|| SortOrder::new(self.sort.clone()))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            match e.downcast_ref::<HttpStatusError>() {
+                Some(status) if status.status == 404 => ExitCode::from(3),
+                _ => ExitCode::FAILURE,
+            }
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let mut builder = ApiClient::builder().config(ClientConfig::from_env());
+    if let Some(base_url) = cli.base_url {
+        builder = builder.base_url(base_url);
+    }
+    if let Some(timeout) = cli.timeout {
+        builder = builder.timeout(Duration::try_from_secs_f64(timeout)?);
+    }
+    let client = builder.try_build()?;
+
+    match cli.command {
+        Command::Search { query, include_collections, list } => {
+            let filters = list.filters();
+            let (response, _) =
+                client.search(&query, include_collections, None, filters, list.per_page, list.page, list.sort())?;
+            print(&response, cli.output)
+        }
+        Command::Item { id } => {
+            let (response, _) = client.get_item(id, None)?;
+            print(&response, cli.output)
+        }
+        Command::Collection { name, query, list } => {
+            let filters = list.filters();
+            let (response, _) =
+                client.get_collection(&name, query.as_deref(), None, filters, list.per_page, list.page, list.sort())?;
+            print(&response, cli.output)
+        }
+        Command::Collections { query, list } => {
+            let filters = list.filters();
+            let (response, _) =
+                client.get_collections(query.as_deref(), None, filters, list.per_page, list.page, list.sort())?;
+            print(&response, cli.output)
+        }
+        Command::Format { format, query, list } => {
+            let filters = list.filters();
+            let (response, _) =
+                client.get_format(format, query.as_deref(), None, filters, list.per_page, list.page, list.sort())?;
+            print(&response, cli.output)
+        }
+    }
+}
+
+/// Writes `response` to stdout in the `output` format.
+fn print(response: &impl Serialize, output: Output) -> Result<(), Box<dyn Error>> {
+    let mut stdout = io::stdout().lock();
+    let response = serde_json::to_value(response)?;
+    match output {
+        Output::Json => {
+            serde_json::to_writer_pretty(&mut stdout, &response)?;
+            writeln!(stdout)?;
+        }
+        Output::Jsonl => {
+            let mut writer = jsonl::Writer::new(stdout);
+            for record in records(&response) {
+                writer.write_record(record)?;
+            }
+        }
+        Output::Csv => {
+            let mut writer = csv::Writer::new(stdout);
+            writer.write_row(["title", "date", "url"])?;
+            for record in records(&response) {
+                writer.write_row(row(record))?;
+            }
+        }
+        Output::Table => {
+            let rows: Vec<[String; 3]> = records(&response).into_iter().map(row).collect();
+            let title_width = rows.iter().map(|[title, ..]| title.chars().count()).max().unwrap_or(0).clamp(5, 60);
+            let date_width = rows.iter().map(|[_, date, _]| date.chars().count()).max().unwrap_or(0).max(4);
+            writeln!(stdout, "{:title_width$}  {:date_width$}  URL", "TITLE", "DATE")?;
+            for [title, date, url] in rows {
+                writeln!(stdout, "{:title_width$}  {:date_width$}  {}", truncate(&title, title_width), date, url)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the results of a list response, or the item of an item response.
+fn records(response: &Value) -> Vec<&Value> {
+    match (response.get("results"), response.get("item")) {
+        (Some(Value::Array(results)), _) => results.iter().collect(),
+        (_, Some(Value::Array(items))) => items.iter().collect(),
+        (_, Some(item)) => vec![item],
+        _ => Vec::new(),
+    }
+}
+
+/// Returns the title, date and URL of a record, empty where missing.
+fn row(record: &Value) -> [String; 3] {
+    let field = |name: &str| match record.get(name) {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Array(values)) => values.first().and_then(Value::as_str).unwrap_or_default().to_string(),
+        Some(Value::Number(value)) => value.to_string(),
+        _ => String::new(),
+    };
+    let url = Some(field("url")).filter(|url| !url.is_empty()).unwrap_or_else(|| field("id"));
+    [field("title"), field("date"), url]
+}
+
+/// Shortens `text` to `width` characters, ending with `…` if it was cut.
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(width.saturating_sub(1)).collect();
+    short.push('…');
+    short
+}
@@ -0,0 +1,166 @@
+//! # Cassette Module
+//!
+//! VCR-style record and replay of HTTP traffic, enabled with the `record` feature.
+//!
+//! A [`RecordingTransport`] sends requests through another transport and appends every
+//! request/response pair to a cassette file. A [`ReplayTransport`] later answers the same
+//! requests from the cassette without network access, which makes tests of code built on the
+//! client deterministic. Both are usually set up through
+//! [`ApiClientBuilder::record`](crate::loc_client::ApiClientBuilder::record) and
+//! [`ApiClientBuilder::replay`](crate::loc_client::ApiClientBuilder::replay).
+//!
+//! Cassettes are pretty-printed JSON files and can be committed next to the tests using them.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::middleware::{Request, Response};
+use crate::transport::{HttpTransport, TransportError};
+
+/// A recorded request/response pair.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Interaction {
+    /// The requested URL.
+    pub url: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The response headers, names in lowercase.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// The response body, if it is valid UTF-8.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The response body, if it is not valid UTF-8 (e.g. images).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_bytes: Option<Vec<u8>>,
+}
+
+impl Interaction {
+    /// Records the `response` received for `request`.
+    pub fn new(request: &Request, response: &Response) -> Self {
+        let (body, body_bytes) = match String::from_utf8(response.body.clone()) {
+            Ok(body) => (Some(body), None),
+            Err(e) => (None, Some(e.into_bytes())),
+        };
+        Interaction {
+            url: request.url.clone(),
+            status: response.status,
+            headers: response.headers.clone(),
+            body,
+            body_bytes,
+        }
+    }
+
+    /// Returns the recorded response.
+    pub fn response(&self) -> Response {
+        let body = match (&self.body, &self.body_bytes) {
+            (Some(body), _) => body.clone().into_bytes(),
+            (None, Some(bytes)) => bytes.clone(),
+            (None, None) => Vec::new(),
+        };
+        Response { status: self.status, headers: self.headers.clone(), body }
+    }
+}
+
+/// The recorded interactions, in the order the requests were sent.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct Cassette {
+    /// The recorded request/response pairs.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Reads a cassette file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Writes the cassette to `path`, creating missing parent directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_vec_pretty(self)?)
+    }
+}
+
+/// An [`HttpTransport`] recording every response of an inner transport to a cassette file.
+///
+/// The file is rewritten after each response, so a cassette is complete even if the process
+/// exits early. Requests that fail without a response are not recorded.
+pub struct RecordingTransport {
+    inner: Box<dyn HttpTransport>,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl RecordingTransport {
+    /// Records the traffic of `inner` to a new cassette at `path`, replacing any existing file.
+    pub fn new(inner: impl HttpTransport + 'static, path: impl Into<PathBuf>) -> Self {
+        Self::boxed(Box::new(inner), path.into())
+    }
+
+    pub(crate) fn boxed(inner: Box<dyn HttpTransport>, path: PathBuf) -> Self {
+        RecordingTransport { inner, path, cassette: Mutex::new(Cassette::default()) }
+    }
+}
+
+impl HttpTransport for RecordingTransport {
+    fn get(&self, request: &Request) -> Result<Response, TransportError> {
+        let response = self.inner.get(request)?;
+        let mut cassette = self.cassette.lock().unwrap_or_else(|e| e.into_inner());
+        cassette.interactions.push(Interaction::new(request, &response));
+        cassette.save(&self.path).map_err(|e| TransportError::new(&request.url, e))?;
+        Ok(response)
+    }
+
+    fn recycle_connections(&self) {
+        self.inner.recycle_connections();
+    }
+}
+
+/// An [`HttpTransport`] answering requests from a [`Cassette`], without network access.
+///
+/// Requests are matched by URL. When a URL was recorded several times its responses are
+/// replayed in order, the last one repeating. A request missing from the cassette fails with
+/// a [`TransportError`].
+pub struct ReplayTransport {
+    interactions: HashMap<String, Vec<Response>>,
+    replayed: Mutex<HashMap<String, usize>>,
+}
+
+impl ReplayTransport {
+    /// Replays the interactions of `cassette`.
+    pub fn new(cassette: Cassette) -> Self {
+        let mut interactions: HashMap<String, Vec<Response>> = HashMap::new();
+        for interaction in &cassette.interactions {
+            interactions.entry(interaction.url.clone()).or_default().push(interaction.response());
+        }
+        ReplayTransport { interactions, replayed: Mutex::new(HashMap::new()) }
+    }
+
+    /// Replays the cassette file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(Cassette::load(path)?))
+    }
+}
+
+impl HttpTransport for ReplayTransport {
+    fn get(&self, request: &Request) -> Result<Response, TransportError> {
+        let Some(responses) = self.interactions.get(&request.url) else {
+            return Err(TransportError::new(&request.url, "no recorded interaction for this URL"));
+        };
+        let mut replayed = self.replayed.lock().unwrap_or_else(|e| e.into_inner());
+        let count = replayed.entry(request.url.clone()).or_insert(0);
+        let response = responses[(*count).min(responses.len() - 1)].clone();
+        *count += 1;
+        Ok(response)
+    }
+}
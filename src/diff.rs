@@ -0,0 +1,133 @@
+//! # Diff Module
+//!
+//! Change detection between two harvests of the same query or collection, such as two JSONL
+//! files written by [`export::jsonl::Writer`](crate::export::jsonl::Writer).
+//!
+//! Items are matched by [`ResultItem::item_id`]. A matched item counts as changed when its
+//! `extract_timestamp` differs, or, when either harvest lacks the timestamp, when its
+//! [`content_hash`] differs. Items without an id are ignored.
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead};
+
+use crate::response_models::{first_string, ResultItem};
+
+/// The differences between an old and a new harvest, each list sorted by item id.
+#[derive(Debug, Clone, Default)]
+pub struct Changeset {
+    /// Items only present in the new harvest.
+    pub added: Vec<ResultItem>,
+    /// Items only present in the old harvest.
+    pub removed: Vec<ResultItem>,
+    /// Items present in both harvests whose timestamp or content changed.
+    pub changed: Vec<ChangedItem>,
+}
+
+impl Changeset {
+    /// Returns `true` if the harvests contain the same items with the same content.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// An item present in both harvests whose timestamp or content changed.
+#[derive(Debug, Clone)]
+pub struct ChangedItem {
+    /// The item id both versions share.
+    pub id: String,
+    /// The version from the old harvest.
+    pub old: ResultItem,
+    /// The version from the new harvest.
+    pub new: ResultItem,
+}
+
+/// Compares two harvests of [`ResultItem`]s.
+///
+/// When a harvest lists the same id more than once, the last occurrence wins.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::diff::diff;
+/// use loc_api::response_models::ResultItem;
+///
+/// let item = |json: &str| serde_json::from_str::<ResultItem>(json).unwrap();
+/// let old = vec![
+///     item(r#"{"id": "http://www.loc.gov/item/1/", "extract_timestamp": "2024-01-01"}"#),
+///     item(r#"{"id": "http://www.loc.gov/item/2/", "extract_timestamp": "2024-01-01"}"#),
+/// ];
+/// let new = vec![
+///     item(r#"{"id": "http://www.loc.gov/item/2/", "extract_timestamp": "2024-02-01"}"#),
+///     item(r#"{"id": "http://www.loc.gov/item/3/", "extract_timestamp": "2024-02-01"}"#),
+/// ];
+///
+/// let changes = diff(old, new);
+/// assert_eq!(changes.added.len(), 1);
+/// assert_eq!(changes.removed.len(), 1);
+/// assert_eq!(changes.changed[0].id, "2");
+/// ```
+pub fn diff<I, J>(old: I, new: J) -> Changeset
+where
+    I: IntoIterator<Item = ResultItem>,
+    J: IntoIterator<Item = ResultItem>,
+{
+    let mut old = by_id(old);
+    let mut changeset = Changeset::default();
+
+    for (id, new) in by_id(new) {
+        match old.remove(&id) {
+            Some(old) if is_changed(&old, &new) => changeset.changed.push(ChangedItem { id, old, new }),
+            Some(_) => {}
+            None => changeset.added.push(new),
+        }
+    }
+    changeset.removed = old.into_values().collect();
+    changeset
+}
+
+/// Compares two JSONL harvests, one [`ResultItem`] per line. Blank lines are skipped.
+///
+/// # Returns
+///
+/// Returns the [`Changeset`], or an error if a file cannot be read or a line is not a
+/// [`ResultItem`].
+pub fn diff_jsonl(old: impl BufRead, new: impl BufRead) -> io::Result<Changeset> {
+    Ok(diff(read_jsonl(old)?, read_jsonl(new)?))
+}
+
+/// Returns a hash of the serialized item, stable across runs and platforms.
+pub fn content_hash(item: &ResultItem) -> u64 {
+    hash_bytes(&serde_json::to_vec(item).unwrap_or_default())
+}
+
+/// Returns the 64-bit FNV-1a hash of `bytes`, stable across runs and platforms.
+pub(crate) fn hash_bytes(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3))
+}
+
+fn by_id(items: impl IntoIterator<Item = ResultItem>) -> BTreeMap<String, ResultItem> {
+    items
+        .into_iter()
+        .filter_map(|item| Some((item.item_id()?.to_string(), item)))
+        .collect()
+}
+
+fn is_changed(old: &ResultItem, new: &ResultItem) -> bool {
+    match (first_string(&old.extract_timestamp), first_string(&new.extract_timestamp)) {
+        (Some(old), Some(new)) => old != new,
+        _ => content_hash(old) != content_hash(new),
+    }
+}
+
+fn read_jsonl(reader: impl BufRead) -> io::Result<Vec<ResultItem>> {
+    let mut items = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            items.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(items)
+}
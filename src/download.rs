@@ -0,0 +1,342 @@
+//! # Download Module
+//!
+//! Helpers for downloading files to disk: the manifest returned by
+//! [`ApiClient::download_all_pages`], and the [`BulkDownloader`] for whole harvests.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::diff::hash_bytes;
+use crate::loc_client::ApiClient;
+use crate::response_models::{first_string, number, File, ImageSize};
+
+/// A page image saved by [`ApiClient::download_all_pages`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SavedPage {
+    /// The 1-based page number across all resources of the item.
+    pub page: usize,
+    /// The URL the image was downloaded from.
+    pub url: String,
+    /// The path the image was written to.
+    pub path: PathBuf,
+    /// The number of bytes written.
+    pub bytes: u64,
+}
+
+/// Picks the image among the files of one page that best matches `size`: the narrowest image
+/// at least [`ImageSize::min_width`] wide, or the widest one if none is wide enough.
+pub(crate) fn pick_image<'a>(files: &[&'a File], size: ImageSize) -> Option<&'a File> {
+    let images: Vec<(&File, u64)> = files
+        .iter()
+        .filter(|file| first_string(&file.mimetype).is_some_and(|m| m.starts_with("image/")))
+        .filter(|file| first_string(&file.url).is_some())
+        .map(|file| (*file, number(&file.width).unwrap_or(0)))
+        .collect();
+
+    let min_width = u64::from(size.min_width());
+    images
+        .iter()
+        .filter(|(_, width)| *width >= min_width)
+        .min_by_key(|(_, width)| *width)
+        .or_else(|| images.iter().max_by_key(|(_, width)| *width))
+        .map(|(file, _)| *file)
+}
+
+/// Returns the file extension to save `file` with, based on its MIME type or URL.
+pub(crate) fn extension(file: &File) -> String {
+    let from_mime = match first_string(&file.mimetype) {
+        Some("image/jpeg") => Some("jpg"),
+        Some("image/png") => Some("png"),
+        Some("image/gif") => Some("gif"),
+        Some("image/tiff") => Some("tif"),
+        Some("image/jp2") => Some("jp2"),
+        _ => None,
+    };
+    if let Some(extension) = from_mime {
+        return extension.to_string();
+    }
+
+    first_string(&file.url)
+        .and_then(|url| url.split(['?', '#']).next())
+        .and_then(|path| path.rsplit('/').next())
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_else(|| "bin".to_string())
+}
+
+/// Writes `body` to `path` through a `.part` file renamed on success, so an interrupted write
+/// never leaves a truncated file at `path`.
+pub(crate) fn write_atomic(path: &Path, body: &[u8]) -> io::Result<()> {
+    let mut part = path.as_os_str().to_owned();
+    part.push(".part");
+    fs::write(&part, body)?;
+    fs::rename(&part, path)
+}
+
+/// Returns the name a [`BulkDownloader`] saves `url` under: the last segment of the URL path
+/// with a hash of the whole URL before the extension, e.g. `default-dc0ba64733a4c694.jpg` for
+/// `https://tile.loc.gov/image-services/iiif/service:pnp:bbc:0000:0001f/full/pct:25/0/default.jpg`.
+///
+/// Many URLs share their last segment (every IIIF image ends in `default.jpg`), so the hash
+/// keeps the names of different URLs apart while staying the same from one run to the next.
+fn file_name(url: &str) -> String {
+    let last = url
+        .split(['?', '#'])
+        .next()
+        .and_then(|path| path.trim_end_matches('/').rsplit('/').next())
+        .unwrap_or_default();
+    let (stem, extension) = match last.rsplit_once('.') {
+        Some((stem, extension)) if !extension.is_empty() && extension.bytes().all(|b| b.is_ascii_alphanumeric()) => {
+            (stem, Some(extension))
+        }
+        _ => (last, None),
+    };
+    let stem: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+        .collect();
+    let stem = if stem.is_empty() { "file" } else { stem.as_str() };
+    let hash = hash_bytes(url.as_bytes());
+    match extension {
+        Some(extension) => format!("{}-{:016x}.{}", stem, hash, extension.to_lowercase()),
+        None => format!("{}-{:016x}", stem, hash),
+    }
+}
+
+/// Downloads many files concurrently and politely, with a concurrency limit, per-host rate
+/// limits and retries of transient failures.
+///
+/// Files are saved under the destination directory by the last segment of their URL path and
+/// a hash of the URL, e.g. `default-dc0ba64733a4c694.jpg`, so URLs sharing a file name such as
+/// IIIF images do not overwrite each other. Files are written through a `.part` file and
+/// renamed once complete, and files that already exist are skipped, so an interrupted run can
+/// simply be restarted.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use loc_api::download::BulkDownloader;
+/// use loc_api::loc_client::ApiClient;
+///
+/// let client = ApiClient::new();
+/// let urls = vec![
+///     "https://tile.loc.gov/storage-services/service/pnp/bbc/0000/0001f.jpg".to_string(),
+///     "https://tile.loc.gov/storage-services/service/pnp/bbc/0000/0002f.jpg".to_string(),
+/// ];
+/// let report = BulkDownloader::new(&client)
+///     .concurrency(4)
+///     .host_rate_limit("tile.loc.gov", 60)
+///     .run(urls, "images");
+/// println!("{} saved, {} skipped, {} failed", report.succeeded.len(), report.skipped.len(), report.failed.len());
+/// ```
+pub struct BulkDownloader<'a> {
+    client: &'a ApiClient,
+    concurrency: usize,
+    default_rate_limit: Option<u32>,
+    host_rate_limits: HashMap<String, u32>,
+    max_retries: u32,
+    backoff: Duration,
+    timeout: Option<Duration>,
+}
+
+impl<'a> BulkDownloader<'a> {
+    /// Creates a [`BulkDownloader`] sending its requests through `client`.
+    ///
+    /// Defaults to 4 concurrent downloads, no rate limit, 3 retries with a 1 second initial
+    /// backoff and the client's download timeout.
+    pub fn new(client: &'a ApiClient) -> Self {
+        BulkDownloader {
+            client,
+            concurrency: 4,
+            default_rate_limit: None,
+            host_rate_limits: HashMap::new(),
+            max_retries: 3,
+            backoff: Duration::from_secs(1),
+            timeout: client.download_timeout(),
+        }
+    }
+
+    /// Sets the maximum number of downloads in flight.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Limits every host without a [`BulkDownloader::host_rate_limit`] to `requests_per_minute`.
+    pub fn default_rate_limit(mut self, requests_per_minute: u32) -> Self {
+        self.default_rate_limit = Some(requests_per_minute);
+        self
+    }
+
+    /// Limits requests to `host` (e.g. `"tile.loc.gov"`) to `requests_per_minute`.
+    pub fn host_rate_limit(mut self, host: &str, requests_per_minute: u32) -> Self {
+        self.host_rate_limits.insert(host.to_lowercase(), requests_per_minute);
+        self
+    }
+
+    /// Sets how often a transient failure (transport error, HTTP 429 or 5xx) is retried.
+    pub fn retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the wait before the first retry; it doubles with every further retry.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets the timeout of each download attempt, overriding the client's
+    /// [`download_timeout`](crate::loc_client::ApiClientBuilder::download_timeout).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Downloads every URL into `dest`, creating the directory if missing.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`DownloadReport`] with every URL listed exactly once, each list sorted by URL.
+    pub fn run<I>(&self, urls: I, dest: impl AsRef<Path>) -> DownloadReport
+    where
+        I: IntoIterator<Item = String>,
+        I::IntoIter: Send,
+    {
+        let dest = dest.as_ref();
+        let urls = Mutex::new(urls.into_iter());
+        let report = Mutex::new(DownloadReport::default());
+        let next_slot: Mutex<HashMap<String, Instant>> = Mutex::new(HashMap::new());
+        let claimed: Mutex<HashMap<PathBuf, String>> = Mutex::new(HashMap::new());
+
+        thread::scope(|scope| {
+            for _ in 0..self.concurrency {
+                scope.spawn(|| loop {
+                    let Some(url) = urls.lock().unwrap().next() else { break };
+                    let outcome = self.download(&url, dest, &next_slot, &claimed);
+                    let mut report = report.lock().unwrap();
+                    match outcome {
+                        Outcome::Saved(file) => report.succeeded.push(file),
+                        Outcome::Skipped(path) => report.skipped.push(SkippedDownload { url, path }),
+                        Outcome::Failed(error, attempts) => report.failed.push(FailedDownload { url, error, attempts }),
+                    }
+                });
+            }
+        });
+
+        let mut report = report.into_inner().unwrap();
+        report.succeeded.sort_by(|a, b| a.url.cmp(&b.url));
+        report.skipped.sort_by(|a, b| a.url.cmp(&b.url));
+        report.failed.sort_by(|a, b| a.url.cmp(&b.url));
+        report
+    }
+
+    fn download(
+        &self,
+        url: &str,
+        dest: &Path,
+        next_slot: &Mutex<HashMap<String, Instant>>,
+        claimed: &Mutex<HashMap<PathBuf, String>>,
+    ) -> Outcome {
+        let path = dest.join(file_name(url));
+        // Two workers must never write the same file: a URL listed twice is downloaded once.
+        {
+            let mut claimed = claimed.lock().unwrap();
+            match claimed.get(&path) {
+                Some(owner) if owner == url => return Outcome::Skipped(path),
+                Some(owner) => return Outcome::Failed(format!("{} is also the file name of {}", path.display(), owner), 0),
+                None => claimed.insert(path.clone(), url.to_string()),
+            };
+        }
+        if path.exists() {
+            return Outcome::Skipped(path);
+        }
+
+        let host = host(url);
+        if let Some(per_minute) = self.host_rate_limits.get(&host).copied().or(self.default_rate_limit) {
+            let interval = Duration::from_secs(60) / per_minute.max(1);
+            let wait = {
+                let mut slots = next_slot.lock().unwrap();
+                let now = Instant::now();
+                let slot = slots.get(&host).copied().unwrap_or(now).max(now);
+                slots.insert(host, slot + interval);
+                slot - now
+            };
+            if !wait.is_zero() {
+                self.client.metrics().rate_limited(url, wait);
+                thread::sleep(wait);
+            }
+        }
+
+        let body = match self.client.execute_url_with_retry(url, self.max_retries, self.backoff, self.timeout, &[]) {
+            Ok((body, _)) => body,
+            Err((e, attempts)) => return Outcome::Failed(e.to_string(), attempts),
+        };
+        let written = fs::create_dir_all(dest).and_then(|_| write_atomic(&path, &body));
+        match written {
+            Ok(()) => Outcome::Saved(SavedFile { url: url.to_string(), path, bytes: body.len() as u64 }),
+            Err(e) => Outcome::Failed(e.to_string(), 1),
+        }
+    }
+}
+
+enum Outcome {
+    Saved(SavedFile),
+    Skipped(PathBuf),
+    Failed(String, u32),
+}
+
+/// Returns the lowercase host of `url`.
+fn host(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    rest.split(['/', '?', '#']).next().unwrap_or_default().to_lowercase()
+}
+
+/// The summary of a [`BulkDownloader::run`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DownloadReport {
+    /// Files downloaded and saved.
+    pub succeeded: Vec<SavedFile>,
+    /// Files that failed after all retries.
+    pub failed: Vec<FailedDownload>,
+    /// Files skipped because they already existed.
+    pub skipped: Vec<SkippedDownload>,
+}
+
+/// A file saved by [`BulkDownloader::run`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SavedFile {
+    /// The URL the file was downloaded from.
+    pub url: String,
+    /// The path the file was written to.
+    pub path: PathBuf,
+    /// The number of bytes written.
+    pub bytes: u64,
+}
+
+/// A download that failed in [`BulkDownloader::run`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FailedDownload {
+    /// The URL that could not be downloaded.
+    pub url: String,
+    /// The last error.
+    pub error: String,
+    /// The number of attempts made.
+    pub attempts: u32,
+}
+
+/// A download skipped by [`BulkDownloader::run`] because the file already existed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SkippedDownload {
+    /// The URL of the file.
+    pub url: String,
+    /// The existing file.
+    pub path: PathBuf,
+}
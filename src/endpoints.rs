@@ -5,9 +5,10 @@
 //! and their respective parameters.
 
 use std::error::Error;
+use std::fmt;
 
 use serde::{Serialize, Deserialize};
-use crate::{param_models::*, format_models::*};
+use crate::{attribute_models::*, param_models::*, format_models::*};
 
 /// Represents the various endpoints available in the Library of Congress API.
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -60,38 +61,290 @@ pub enum Endpoints {
     },
 }
 
-fn to_url_helper(common: &CommonParams) -> String {
-    let format = common.format.unwrap_or(Format::Json).slug();
-    let attributes = match common.attributes {
-        Some(ref attrs) => attrs.to_query_param(),
-        None => "".to_string(),
-    };
-    let query = match common.query {
-        Some(ref q) => format!("&q={}", q),
-        None => "".to_string(),
-    };
-    let filter = match common.filter {
-        Some(ref f) => format!("&fa={}", f.to_query_param()),
-        None => "".to_string(),
-    };
-    let per_page = match common.per_page {
-        Some(c) => format!("&c={}", c),
-        None => "".to_string(),
-    };
-    let page = match common.page {
-        Some(p) => format!("&sp={}", p),
-        None => "1".to_string(),
-    };
-    let sort = match common.sort {
-        Some(s) => format!("&sb={}", s.slug()),
-        None => "".to_string(),
-    };
+/// The error returned by [`Endpoints::parse`] when a URL cannot be mapped to an endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEndpointError {
+    /// The URL that could not be parsed.
+    pub url: String,
+    /// Why the URL was rejected.
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ParseEndpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot parse {:?} as a loc.gov endpoint: {}", self.url, self.reason)
+    }
+}
+
+impl Error for ParseEndpointError {}
+
+/// Description of one [`Endpoints`] variant, as listed by [`Endpoints::catalog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct EndpointInfo {
+    /// The variant name, e.g. `"Collection"`.
+    pub name: &'static str,
+    /// What the endpoint returns.
+    pub description: &'static str,
+    /// The path template, placeholders in braces, e.g. `"/collections/{name}/"`.
+    pub path: &'static str,
+    /// The placeholders of [`EndpointInfo::path`], in order.
+    pub path_params: &'static [ParamInfo],
+    /// The query parameters the endpoint accepts.
+    pub query_params: &'static [ParamInfo],
+    /// The name of the struct holding the query parameters, e.g. `"CommonParams"`.
+    pub params_type: &'static str,
+    /// The name of the response model in [`response_models`](crate::response_models), e.g.
+    /// `"CollectionResponse"`.
+    pub response_type: &'static str,
+}
+
+/// A path or query parameter of an endpoint, see [`EndpointInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ParamInfo {
+    /// The name in the URL: the query key (e.g. `"q"`) or the path placeholder (e.g. `"name"`).
+    pub key: &'static str,
+    /// The field of the parameter struct it is set from, e.g. `"query"`. For
+    /// [`SearchParams`](crate::param_models::SearchParams) the fields are those of its `common`
+    /// parameters.
+    pub field: &'static str,
+    /// What the parameter does.
+    pub description: &'static str,
+    /// The accepted values, empty if the value is free-form.
+    pub values: &'static [&'static str],
+}
+
+const FORMAT_PARAM: ParamInfo = ParamInfo {
+    key: "fo",
+    field: "format",
+    description: "Response format, JSON unless set.",
+    values: &["json", "yaml"],
+};
+
+/// The blocks of [`ExcludedAttributes`].
+const EXCLUDED_VALUES: [&str; 10] = [
+    "articles_and_essays",
+    "cite_this",
+    "item",
+    "more_like_this",
+    "page",
+    "related_items",
+    "resource",
+    "resources",
+    "segments",
+    "traditional_knowledge_labels",
+];
+
+/// The query parameters of the list endpoints, one per key emitted by
+/// [`CommonParams::query_pairs`](crate::param_models::CommonParams::query_pairs). The catalog is
+/// checked against the emitted keys of fully populated parameters in the endpoint tests.
+const LIST_PARAMS: [ParamInfo; 8] = [
+    FORMAT_PARAM,
+    ParamInfo {
+        key: "at",
+        field: "attributes.include",
+        description: "Comma-separated attributes to include, e.g. `results,pagination`, or dot paths to nested ones.",
+        values: &[],
+    },
+    ParamInfo {
+        key: "at!",
+        field: "attributes.exclude",
+        description: "Comma-separated top-level attributes to exclude.",
+        values: &[],
+    },
+    ParamInfo { key: "q", field: "query", description: "Keyword search in metadata and full text.", values: &[] },
+    ParamInfo {
+        key: "fa",
+        field: "filter",
+        description: "`|`-separated facet filters as `facet:value`, or `facet!:value` to exclude.",
+        values: &[],
+    },
+    ParamInfo { key: "c", field: "per_page", description: "Number of results per page.", values: &[] },
+    ParamInfo { key: "sp", field: "page", description: "1-based page number.", values: &[] },
+    ParamInfo {
+        key: "sb",
+        field: "sort",
+        description: "Comma-separated sort fields; other keys are passed through.",
+        values: &["date", "date_desc", "title_s", "title_s_desc", "shelf_id", "shelf_id_desc"],
+    },
+];
+
+const CATALOG: [EndpointInfo; 6] = [
+    EndpointInfo {
+        name: "Search",
+        description: "Searches all of loc.gov.",
+        path: "/search/",
+        path_params: &[],
+        query_params: &LIST_PARAMS,
+        params_type: "SearchParams",
+        response_type: "SearchResultResponse",
+    },
+    EndpointInfo {
+        name: "Collections",
+        description: "Lists the digital collections.",
+        path: "/collections/",
+        path_params: &[],
+        query_params: &LIST_PARAMS,
+        params_type: "CommonParams",
+        response_type: "CollectionsResponse",
+    },
+    EndpointInfo {
+        name: "Collection",
+        description: "Lists the items of a collection.",
+        path: "/collections/{name}/",
+        path_params: &[ParamInfo {
+            key: "name",
+            field: "name",
+            description: "The collection name; spaces become hyphens.",
+            values: &[],
+        }],
+        query_params: &LIST_PARAMS,
+        params_type: "CommonParams",
+        response_type: "CollectionResponse",
+    },
+    EndpointInfo {
+        name: "Format",
+        description: "Lists the items of a format.",
+        path: "/{format}/",
+        path_params: &[ParamInfo {
+            key: "format",
+            field: "format",
+            description: "The format slug.",
+            values: &[
+                "audio",
+                "books",
+                "film-and-videos",
+                "legislation",
+                "manuscripts",
+                "maps",
+                "newspapers",
+                "photos",
+                "notated-music",
+                "web-archives",
+            ],
+        }],
+        query_params: &LIST_PARAMS,
+        params_type: "CommonParams",
+        response_type: "FormatResponse",
+    },
+    EndpointInfo {
+        name: "Item",
+        description: "Returns an item with its resources.",
+        path: "/item/{item_id}/",
+        path_params: &[ParamInfo { key: "item_id", field: "item_id", description: "The item ID.", values: &[] }],
+        query_params: &[
+            FORMAT_PARAM,
+            ParamInfo {
+                key: "at",
+                field: "attributes",
+                description: "Comma-separated parts of the item to include, or dot paths such as `item.title`.",
+                values: &["cite_this", "item", "resources"],
+            },
+            ParamInfo {
+                key: "at!",
+                field: "attributes.exclude",
+                description: "Comma-separated parts of the item to exclude, e.g. `more_like_this,related_items`.",
+                values: &EXCLUDED_VALUES,
+            },
+        ],
+        params_type: "ItemParams",
+        response_type: "ItemResponse",
+    },
+    EndpointInfo {
+        name: "Resource",
+        description: "Returns a resource with its files.",
+        path: "/resource/{resource_id}/",
+        path_params: &[ParamInfo {
+            key: "resource_id",
+            field: "resource_id",
+            description: "The resource ID.",
+            values: &[],
+        }],
+        query_params: &[
+            FORMAT_PARAM,
+            ParamInfo {
+                key: "at",
+                field: "attributes",
+                description: "Comma-separated parts of the resource to include, or dot paths such as `resource.title`.",
+                values: &["cite_this", "item", "page", "resource", "resources", "segments"],
+            },
+            ParamInfo {
+                key: "at!",
+                field: "attributes.exclude",
+                description: "Comma-separated parts of the resource to exclude.",
+                values: &EXCLUDED_VALUES,
+            },
+            ParamInfo {
+                key: "sp",
+                field: "page",
+                description: "1-based page or segment of a multi-part resource.",
+                values: &[],
+            },
+        ],
+        params_type: "ResourceParams",
+        response_type: "ResourceResponse",
+    },
+];
+
+impl std::str::FromStr for Endpoints {
+    type Err = ParseEndpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Endpoints::parse(s)
+    }
+}
+
+/// Decodes `%XX` escapes in a query component. Invalid escapes are kept as they are.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// The host of the loc.gov API, used by [`Endpoints::to_url`].
+const LOC_BASE_URL: &str = "https://www.loc.gov";
 
-    format!(
-        "?fo={}&{}{}{}{}{}{}",
-        format, attributes, query, filter, per_page, page, sort
-    )
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
 
+/// Appends query pairs to `out` as a query string, percent-encoding the characters that would
+/// break it (`&`, `#`, whitespace, control and non-ASCII characters). Spaces become `+`;
+/// characters the API uses as separators (`+`, `,`, `:`, `|`, `!`) and existing `%` escapes are
+/// kept.
+fn write_query(pairs: &[(&str, String)], out: &mut String) {
+    for (index, (key, value)) in pairs.iter().enumerate() {
+        if index > 0 {
+            out.push('&');
+        }
+        out.push_str(key);
+        out.push('=');
+        for byte in value.bytes() {
+            match byte {
+                b' ' => out.push('+'),
+                b'&' | b'#' | 0..=0x1f | 0x7f..=0xff => {
+                    out.push('%');
+                    out.push(HEX_DIGITS[usize::from(byte >> 4)] as char);
+                    out.push(HEX_DIGITS[usize::from(byte & 0x0f)] as char);
+                }
+                _ => out.push(byte as char),
+            }
+        }
+    }
 }
 
 impl Endpoints {
@@ -115,7 +368,7 @@ impl Endpoints {
     ///     }),
     ///     per_page: Some(25),
     ///     page: Some(1),
-    ///     sort: Some(SortField::TitleS),
+    ///     sort: Some(SortField::TitleS.into()),
     /// };
     ///
     /// let format_params = CommonParams {
@@ -132,155 +385,286 @@ impl Endpoints {
     /// assert_eq!(url, "https://www.loc.gov/film-and-videos/?fo=json&at=pagination,results&q=dog&fa=subject:animals&c=25&sp=1&sb=title_s");
     /// ```
     pub fn to_url(&self) -> Result<String, Box<dyn Error>> {
-        let base_url = "https://www.loc.gov";
-
-        match self {
-            Endpoints::Search(params) => {
-                let mut url = format!("{}/search/", base_url);
-
-                let query_string = to_url_helper(&params.common);
+        Ok(self.url_with_base(LOC_BASE_URL))
+    }
 
-                if !query_string.is_empty() {
-                    url.push_str(&query_string);
-                } else {
-                    return Err("No query parameters provided".to_string().into());
-                }
+    /// Constructs the URL of the endpoint on `base_url` instead of loc.gov, e.g. a mirror or a
+    /// local proxy. The path is appended after a single `/`, whether or not `base_url` ends
+    /// with one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::endpoints::Endpoints;
+    /// use loc_api::format_models::Format;
+    /// use loc_api::param_models::ItemParams;
+    ///
+    /// let endpoint = Endpoints::Item {
+    ///     item_id: "2014717546".to_string(),
+    ///     params: ItemParams { format: Some(Format::Json), ..Default::default() },
+    /// };
+    /// assert_eq!(endpoint.url_with_base("http://localhost:8080"), "http://localhost:8080/item/2014717546/?fo=json");
+    /// assert_eq!(endpoint.url_with_base("http://localhost:8080/"), "http://localhost:8080/item/2014717546/?fo=json");
+    /// ```
+    pub fn url_with_base(&self, base_url: &str) -> String {
+        let mut url = String::with_capacity(base_url.len() + 128);
+        self.write_url(base_url, &mut url);
+        url
+    }
 
-                Ok(url)
-            },
-            Endpoints::Collections(params) => {
+    /// Appends the URL of the endpoint on `base_url` to `out`, see [`Endpoints::url_with_base`].
+    ///
+    /// The URL is written in place, so a buffer reused across requests avoids allocating.
+    pub fn write_url(&self, base_url: &str, out: &mut String) {
+        out.push_str(base_url.trim_end_matches('/'));
+        out.push('/');
+        match self {
+            Endpoints::Search(_) => out.push_str("search/"),
+            Endpoints::Collections(_) => out.push_str("collections/"),
+            Endpoints::Collection { name, .. } => {
+                out.push_str("collections/");
                 // collection param must be in "kebab-case"
-                let mut url = format!("{}/collections/", base_url);
-
-                let query_string = to_url_helper(&params);
-
-                if !query_string.is_empty() {
-                    url.push_str(&query_string.replace(" ", "-"));
-                } else {
-                    return Err("No query parameters provided".to_string().into());
-                }
-
-                Ok(url)
-            },
-            Endpoints::Collection { name, params } => {
-                let mut url = format!("{}/collections/{}/", base_url, name);
-
-                let query_string = to_url_helper(&params);
-
-                if !query_string.is_empty() {
-                    url.push_str(&query_string.replace(" ", "-"));
-                } else {
-                    return Err("No query parameters provided".to_string().into());
-                }
+                out.extend(name.chars().map(|c| if c == ' ' { '-' } else { c }));
+                out.push('/');
+            }
+            Endpoints::Format { format, .. } => {
+                out.push_str(format.slug());
+                out.push('/');
+            }
+            Endpoints::Item { item_id, .. } => {
+                out.push_str("item/");
+                out.push_str(item_id);
+                out.push('/');
+            }
+            Endpoints::Resource { resource_id, .. } => {
+                out.push_str("resource/");
+                out.push_str(resource_id);
+                out.push('/');
+            }
+        }
+        out.push('?');
+        write_query(&self.query_pairs(), out);
+    }
 
-                Ok(url)
-            },
-            Endpoints::Format { format, params } => {
-                let format_slug = format.slug();
-                let mut url = format!("{}/{}/", base_url, format_slug);
+    /// Describes every [`Endpoints`] variant: its path template, path and query parameters and
+    /// response model, in declaration order. The catalog serializes to JSON, e.g. to drive a
+    /// generic request form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::endpoints::Endpoints;
+    ///
+    /// let collection = Endpoints::catalog().iter().find(|e| e.name == "Collection").unwrap();
+    /// assert_eq!(collection.path, "/collections/{name}/");
+    /// assert_eq!(collection.response_type, "CollectionResponse");
+    /// assert!(collection.query_params.iter().any(|param| param.key == "fa"));
+    ///
+    /// let json = serde_json::to_string(Endpoints::catalog()).unwrap();
+    /// assert!(json.starts_with(r#"[{"name":"Search""#));
+    /// ```
+    pub fn catalog() -> &'static [EndpointInfo] {
+        &CATALOG
+    }
 
-                let query_string = to_url_helper(&params);
+    /// Returns the [`EndpointInfo`] of this endpoint's variant.
+    pub fn info(&self) -> &'static EndpointInfo {
+        let index = match self {
+            Endpoints::Search(_) => 0,
+            Endpoints::Collections(_) => 1,
+            Endpoints::Collection { .. } => 2,
+            Endpoints::Format { .. } => 3,
+            Endpoints::Item { .. } => 4,
+            Endpoints::Resource { .. } => 5,
+        };
+        &CATALOG[index]
+    }
 
-                if !query_string.is_empty() {
-                    url.push_str(&query_string);
-                } else {
-                    return Err("No query parameters provided".to_string().into());
+    /// Reconstructs an [`Endpoints`] value from a loc.gov URL, such as one built by
+    /// [`Endpoints::to_url`], the `next`/`previous` URLs of a
+    /// [`Pagination`](crate::response_models::Pagination) or the `on`/`off` URLs of a facet filter.
+    ///
+    /// The host is ignored, so URLs of a proxy or mirror parse too. Unknown query parameters
+    /// are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Endpoints`] value, or a [`ParseEndpointError`] if the path is not an API
+    /// endpoint or a parameter value is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::endpoints::Endpoints;
+    ///
+    /// let next = "https://www.loc.gov/search/?q=baseball&sp=2&fo=json&fa=subject:sports";
+    /// let endpoint = Endpoints::parse(next).unwrap();
+    /// assert!(matches!(&endpoint, Endpoints::Search(params) if params.common.page == Some(2)));
+    /// assert_eq!(
+    ///     endpoint.to_url().unwrap(),
+    ///     "https://www.loc.gov/search/?fo=json&q=baseball&fa=subject:sports&sp=2"
+    /// );
+    ///
+    /// assert!(Endpoints::parse("https://www.loc.gov/item/").is_err());
+    /// ```
+    pub fn parse(url: &str) -> Result<Endpoints, ParseEndpointError> {
+        let error = |reason| ParseEndpointError { url: url.to_string(), reason };
+
+        let without_fragment = url.trim().split('#').next().unwrap_or_default();
+        let (path, query) = without_fragment.split_once('?').unwrap_or((without_fragment, ""));
+        let path = match path.split_once("://") {
+            Some((_, rest)) => rest.find('/').map_or("", |start| &rest[start..]),
+            None => path,
+        };
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        let mut common = CommonParams::default();
+        let mut include = Vec::new();
+        let mut exclude = Vec::new();
+        let mut facets = Vec::new();
+        let mut sort = Vec::new();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let list = || percent_decode(value).split(',').map(str::to_string).filter(|v| !v.is_empty()).collect::<Vec<_>>();
+            match percent_decode(key).as_str() {
+                "fo" => {
+                    common.format = Some(match value {
+                        "json" => Format::Json,
+                        "yaml" => Format::Yaml,
+                        _ => return Err(error("unsupported `fo` format")),
+                    })
                 }
-
-                Ok(url)
-            },
-            Endpoints::Item { item_id, params } => {
-                let mut url = format!("{}/item/{}/", base_url, item_id);
-
-                let format = params.format.unwrap_or(Format::Json).slug();
-
-                let attributes = match params.attributes {
-                    Some(ref attrs) => {
-                        let mut parts = Vec::new();
-
-                        if let Some(item_attrs) = attrs.item {
-                            if item_attrs {
-                                parts.push("at=item".to_string());
-                            }
-                        }
-
-                        if let Some(resource_attrs) = attrs.resources {
-                            if resource_attrs {
-                                parts.push("at=resources".to_string());
-                            }
-                        }
-
-                        if let Some(cite_this) = attrs.cite_this {
-                            if cite_this {
-                                parts.push("at=cite_this".to_string());
-                            }
-                        }
-
-                        parts.join("&")
+                "at" => include.extend(list()),
+                "at!" => exclude.extend(list()),
+                "q" => common.query = Some(percent_decode(&value.replace('+', " "))),
+                "fa" => {
+                    for facet in percent_decode(value).split('|').filter(|f| !f.is_empty()) {
+                        facets.push(Facet::from_query_value(facet).ok_or_else(|| error("malformed `fa` facet"))?);
                     }
-                    None => "".to_string(),
-                };
-
-                let query_string = format!("?fo={}&{}", format, attributes);
-
-                if !query_string.is_empty() {
-                    url.push_str(&query_string);
                 }
-
-                Ok(url)
+                "c" => common.per_page = Some(value.parse().map_err(|_| error("`c` is not a number"))?),
+                "sp" => common.page = Some(value.parse().map_err(|_| error("`sp` is not a number"))?),
+                "sb" => sort.extend(list().iter().map(|key| key.parse::<SortField>().unwrap_or_else(|e| match e {}))),
+                _ => {}
+            }
+        }
+        let enabled = |name: &str| include.iter().any(|n| n == name).then_some(true);
+        // Included names without a flag of their own, e.g. `item.title`.
+        let fields = |flags: &[&str]| -> Vec<String> {
+            include.iter().filter(|name| !flags.contains(&name.as_str())).cloned().collect()
+        };
+        let excluded = || {
+            let mut excluded = ExcludedAttributes::default();
+            for name in &exclude {
+                excluded.set(name, true);
+            }
+            excluded
+        };
+        let selected = !include.is_empty() || !exclude.is_empty();
+
+        let endpoint = match segments.as_slice() {
+            ["item", item_id] => Endpoints::Item {
+                item_id: item_id.to_string(),
+                params: ItemParams {
+                    format: common.format,
+                    attributes: selected.then(|| ItemAttributes {
+                        cite_this: enabled("cite_this"),
+                        item: enabled("item"),
+                        resources: enabled("resources"),
+                        fields: fields(&["cite_this", "item", "resources"]),
+                        exclude: excluded(),
+                    }),
+                },
             },
-            Endpoints::Resource { resource_id, params } => {
-                let mut url = format!("{}/resource/{}/", base_url, resource_id);
-
-                let format = params.format.unwrap_or(Format::Json).slug();
-                
-                let attributes = match params.attributes {
-                    Some(ref attrs) => {
-                        let mut parts = Vec::new();
-
-                        if let Some(resource_attrs) = attrs.resource {
-                            if resource_attrs {
-                                parts.push("at=resource".to_string());
-                            }
-                        }
-
-                        if let Some(page_attrs) = attrs.page {
-                            if page_attrs {
-                                parts.push("at=page".to_string());
-                            }
-                        }
-
-                        if let Some(segment_attrs) = attrs.segments {
-                            if segment_attrs {
-                                parts.push("at=segments".to_string());
-                            }
-                        }
-
-                        if let Some(cite_this) = attrs.cite_this {
-                            if cite_this {
-                                parts.push("at=cite_this".to_string());
-                            }
-                        }
-
-                        if let Some(resources) = attrs.resources {
-                            if resources {
-                                parts.push("at=resources".to_string());
-                            }
-                        }
-
-                        parts.join("&")
-                    }
-                    None => "".to_string(),
-                };
-
-                let query_string = format!("?fo={}&{}", format, attributes);
+            ["resource", resource_id @ ..] if !resource_id.is_empty() => Endpoints::Resource {
+                resource_id: resource_id.join("/"),
+                params: ResourceParams {
+                    format: common.format,
+                    attributes: selected.then(|| ResourceAttributes {
+                        cite_this: enabled("cite_this"),
+                        item: enabled("item"),
+                        page: enabled("page"),
+                        resource: enabled("resource"),
+                        resources: enabled("resources"),
+                        segments: enabled("segments"),
+                        fields: fields(&["cite_this", "item", "page", "resource", "resources", "segments"]),
+                        exclude: excluded(),
+                    }),
+                    page: common.page,
+                },
+            },
+            ["item", ..] | ["resource", ..] => return Err(error("missing or unexpected item or resource id")),
+            [endpoint] | [endpoint, _] => {
+                if !include.is_empty() || !exclude.is_empty() {
+                    common.attributes = Some(AttributesSelect { include, exclude });
+                }
+                if !facets.is_empty() {
+                    common.filter = Some(FacetReq { filters: facets });
+                }
+                if !sort.is_empty() {
+                    common.sort = Some(SortOrder::new(sort));
+                }
 
-                if !query_string.is_empty() {
-                    url.push_str(&query_string);
+                match (*endpoint, segments.get(1)) {
+                    ("search", None) => Endpoints::Search(SearchParams { common, include_collections: false }),
+                    ("collections", None) => Endpoints::Collections(common),
+                    ("collections", Some(name)) => Endpoints::Collection { name: name.to_string(), params: common },
+                    (format, None) => Endpoints::Format {
+                        format: format.parse().unwrap_or_else(|e| match e {}),
+                        params: common,
+                    },
+                    _ => return Err(error("not a loc.gov API endpoint")),
                 }
+            }
+            _ => return Err(error("not a loc.gov API endpoint")),
+        };
+        Ok(endpoint)
+    }
 
-                Ok(url)
-            },
+    /// Validates the endpoint's attribute selection, see [`AttributesSelect::validate`].
+    ///
+    /// [`ApiClient`](crate::loc_client::ApiClient) calls this before sending a request, so a
+    /// selection the API cannot honor fails without a round trip.
+    ///
+    /// # Returns
+    ///
+    /// Returns the unknown attribute names as warnings, or an [`AttributesError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::{endpoints::*, param_models::*, attribute_models::*};
+    ///
+    /// let endpoint = Endpoints::Collections(CommonParams {
+    ///     attributes: Some(AttributesSelect {
+    ///         include: vec!["results".to_string()],
+    ///         exclude: vec!["results".to_string()],
+    ///     }),
+    ///     ..Default::default()
+    /// });
+    /// assert!(endpoint.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<Vec<String>, AttributesError> {
+        let attributes = match self {
+            Endpoints::Search(params) => &params.common.attributes,
+            Endpoints::Collections(params)
+            | Endpoints::Collection { params, .. }
+            | Endpoints::Format { params, .. } => &params.attributes,
+            Endpoints::Item { params, .. } => return params.attributes.as_ref().map_or(Ok(Vec::new()), ItemAttributes::validate),
+            Endpoints::Resource { params, .. } => return params.attributes.as_ref().map_or(Ok(Vec::new()), ResourceAttributes::validate),
+        };
+        attributes.as_ref().map_or(Ok(Vec::new()), AttributesSelect::validate)
+    }
+
+    /// Returns the query pairs of the endpoint's parameters, see [`CommonParams::query_pairs`].
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Endpoints::Search(params) => params.common.query_pairs(),
+            Endpoints::Collections(params)
+            | Endpoints::Collection { params, .. }
+            | Endpoints::Format { params, .. } => params.query_pairs(),
+            Endpoints::Item { params, .. } => params.query_pairs(),
+            Endpoints::Resource { params, .. } => params.query_pairs(),
         }
     }
 }
@@ -0,0 +1,195 @@
+//! BibTeX export.
+//!
+//! [`write_all`] turns a search result or a whole harvest into a `.bib` file. Entries are built
+//! from the [`ZoteroItem`] mapping of each item and keyed by the item ID, which for most items
+//! is the LCCN: `https://www.loc.gov/item/2014717546/` becomes `@misc{loc2014717546, …}`. Keys
+//! therefore stay the same across harvests, so documents citing them keep working.
+//!
+//! Items already written, recognized by their URL, are skipped. Different items that would share
+//! a key, such as items without a URL and the same title, get a numbered suffix.
+
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use crate::export::zotero::ZoteroItem;
+use crate::identifiers::ItemId;
+
+/// Writes every item as a BibTeX entry, skipping duplicates.
+///
+/// Returns the number of entries written.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::bibtex;
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(r#"{"results": [
+///     {"id": "http://www.loc.gov/item/2014717546/", "title": "Baseball cards", "date": "1887"},
+///     {"id": "http://www.loc.gov/item/2014717546/", "title": "Baseball cards", "date": "1887"},
+///     {"id": "http://www.loc.gov/item/sn83045462/", "title": "Evening star", "original_format": ["newspaper"]}
+/// ]}"#).unwrap();
+///
+/// let mut bib = Vec::new();
+/// let written = bibtex::write_all(page.results.as_deref().unwrap_or_default(), &mut bib).unwrap();
+/// assert_eq!(written, 2);
+///
+/// let bib = String::from_utf8(bib).unwrap();
+/// assert!(bib.starts_with("@misc{loc2014717546,\n  title = {Baseball cards},\n  year = {1887},"));
+/// assert!(bib.contains("@article{locsn83045462,"));
+/// ```
+pub fn write_all<I, W>(items: I, writer: W) -> io::Result<usize>
+where
+    I: IntoIterator,
+    I::Item: Into<ZoteroItem>,
+    W: Write,
+{
+    let mut writer = Writer::new(writer);
+    for item in items {
+        writer.write_item(&item.into())?;
+    }
+    writer.flush()?;
+    Ok(writer.written())
+}
+
+/// Appends deduplicated BibTeX entries to an underlying [`Write`].
+///
+/// Use a single [`Writer`] for all pages of a harvest, so duplicates across pages are skipped
+/// too.
+pub struct Writer<W: Write> {
+    inner: W,
+    written: usize,
+    urls: HashSet<String>,
+    keys: HashSet<String>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new [`Writer`] appending to `inner`.
+    ///
+    /// Wrap files in a [`std::io::BufWriter`] to avoid a system call per field.
+    pub fn new(inner: W) -> Self {
+        Writer { inner, written: 0, urls: HashSet::new(), keys: HashSet::new() }
+    }
+
+    /// Writes `item` as one entry, unless an item with the same URL was written before.
+    ///
+    /// Returns the citation key, or `None` if the item was skipped.
+    pub fn write_item(&mut self, item: &ZoteroItem) -> io::Result<Option<String>> {
+        if !item.url.is_empty() && !self.urls.insert(item.url.clone()) {
+            return Ok(None);
+        }
+
+        let base = citation_key(item);
+        let mut key = base.clone();
+        let mut suffix = 2;
+        while !self.keys.insert(key.clone()) {
+            key = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+
+        let entry_type = match item.item_type.as_str() {
+            "book" => "book",
+            "newspaperArticle" | "magazineArticle" => "article",
+            "manuscript" => "unpublished",
+            _ => "misc",
+        };
+        let authors: Vec<String> = item
+            .creators
+            .iter()
+            .filter_map(|creator| match (&creator.last_name, &creator.first_name, &creator.name) {
+                (Some(last), Some(first), _) => Some(format!("{}, {}", escape(last), escape(first))),
+                (Some(last), None, _) => Some(escape(last)),
+                // Braces keep BibTeX from splitting corporate names into first and last name.
+                (None, _, Some(name)) => Some(format!("{{{}}}", escape(name))),
+                (None, _, None) => None,
+            })
+            .collect();
+        let notes: Vec<String> = [
+            (!item.call_number.is_empty()).then(|| format!("Call number: {}", item.call_number)),
+            (!item.library_catalog.is_empty()).then(|| item.library_catalog.clone()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let keywords: Vec<&str> = item.tags.iter().map(|tag| tag.tag.as_str()).collect();
+
+        writeln!(self.inner, "@{}{{{},", entry_type, key)?;
+        self.field("title", &escape(&item.title))?;
+        self.field("author", &authors.join(" and "))?;
+        self.field("year", year(&item.date).unwrap_or_default())?;
+        self.field("address", &escape(&item.place))?;
+        self.field("language", &escape(&item.language))?;
+        self.field("abstract", &escape(&item.abstract_note))?;
+        self.field("keywords", &escape(&keywords.join(", ")))?;
+        self.field("url", &item.url)?;
+        self.field("note", &escape(&notes.join(". ")))?;
+        writeln!(self.inner, "}}\n")?;
+        self.written += 1;
+        Ok(Some(key))
+    }
+
+    /// Returns the number of entries written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Consumes the [`Writer`], returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn field(&mut self, name: &str, value: &str) -> io::Result<()> {
+        if value.is_empty() {
+            return Ok(());
+        }
+        writeln!(self.inner, "  {} = {{{}}},", name, value)
+    }
+}
+
+/// Derives the citation key of `item`: `loc` followed by the item ID, or by the last segment
+/// of other URLs, or by the title for items without URL.
+fn citation_key(item: &ZoteroItem) -> String {
+    let id = match ItemId::parse(&item.url) {
+        Ok(id) => id.as_str().to_string(),
+        Err(_) => {
+            let path = item.url.split(['?', '#']).next().unwrap_or_default();
+            path.rsplit('/').find(|s| !s.is_empty()).unwrap_or_default().to_string()
+        }
+    };
+    let source = if id.is_empty() { item.title.split_whitespace().take(3).collect::<Vec<_>>().join("-") } else { id };
+
+    let mut key = String::from("loc");
+    for c in source.chars() {
+        if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.') {
+            key.push(c.to_ascii_lowercase());
+        } else if !key.ends_with('-') {
+            key.push('-');
+        }
+    }
+    key
+}
+
+/// Returns the first four-digit year in `date`.
+fn year(date: &str) -> Option<&str> {
+    let bytes = date.as_bytes();
+    (0..bytes.len().saturating_sub(3))
+        .find(|&i| bytes[i..i + 4].iter().all(u8::is_ascii_digit))
+        .map(|i| &date[i..i + 4])
+}
+
+/// Escapes the characters with a special meaning in BibTeX values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '{' | '}' | '&' | '%' | '$' | '#' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
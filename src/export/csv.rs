@@ -0,0 +1,71 @@
+//! Comma-separated values (CSV) export.
+//!
+//! Rows are written as soon as they are handed to the [`Writer`]. Fields containing commas,
+//! quotes or line breaks are quoted following RFC 4180, so the output opens in spreadsheets.
+
+use std::io::{self, Write};
+
+/// Appends rows to an underlying [`Write`] as CSV.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::csv::Writer;
+///
+/// let mut writer = Writer::new(Vec::new());
+/// writer.write_row(["title", "date"]).unwrap();
+/// writer.write_row(["Baseball, 1887", "1887"]).unwrap();
+///
+/// let output = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(output, "title,date\r\n\"Baseball, 1887\",1887\r\n");
+/// ```
+pub struct Writer<W: Write> {
+    inner: W,
+    written: usize,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new [`Writer`] appending to `inner`.
+    ///
+    /// Wrap files in a [`std::io::BufWriter`] to avoid a system call per row.
+    pub fn new(inner: W) -> Self {
+        Writer { inner, written: 0 }
+    }
+
+    /// Writes one row, quoting fields where needed.
+    pub fn write_row<I, S>(&mut self, fields: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for (index, field) in fields.into_iter().enumerate() {
+            if index > 0 {
+                self.inner.write_all(b",")?;
+            }
+            let field = field.as_ref();
+            if field.contains([',', '"', '\n', '\r']) {
+                write!(self.inner, "\"{}\"", field.replace('"', "\"\""))?;
+            } else {
+                self.inner.write_all(field.as_bytes())?;
+            }
+        }
+        self.inner.write_all(b"\r\n")?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Returns the number of rows written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Consumes the [`Writer`], returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
@@ -0,0 +1,233 @@
+//! GeoJSON export.
+//!
+//! An [`Exporter`] turns geolocated items into a GeoJSON [`FeatureCollection`] (RFC 7946) for
+//! mapping tools such as QGIS, Leaflet or geojson.io. Each feature's properties are the
+//! [`NormalizedItem`] of the item.
+//!
+//! Geometries are read from the item's metadata: `latlong` (`38.8951,-77.0364`) becomes a point,
+//! and the `coordinates` bounding box of maps (`W 122°30ʹ--W 122°20ʹ/N 37°50ʹ--N 37°40ʹ`) becomes
+//! a polygon. Items with neither can be placed by a [`Geocoder`] from their location names;
+//! items that still have no geometry are skipped and counted.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::mapping::NormalizedItem;
+use crate::response_models::{all_strings, strings, ItemAttribute, ResultItem};
+
+/// Looks up the position of a named place, such as `new york` or `chesapeake bay`.
+///
+/// Implemented for closures, so any geocoding service or gazetteer can be plugged in. Each
+/// place name is looked up at most once per [`Exporter`].
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::geojson::{Exporter, Geometry};
+/// use loc_api::response_models::ResultItem;
+///
+/// let gazetteer = |place: &str| match place {
+///     "baltimore" => Some((39.29, -76.61)),
+///     _ => None,
+/// };
+///
+/// let result: ResultItem = serde_json::from_str(r#"{
+///     "id": "http://www.loc.gov/item/2014717546/",
+///     "location": ["maryland", "baltimore"]
+/// }"#).unwrap();
+///
+/// let mut exporter = Exporter::new().geocoder(gazetteer);
+/// assert!(exporter.add_result(&result));
+/// let collection = exporter.finish();
+/// assert_eq!(collection.features[0].geometry, Geometry::Point([-76.61, 39.29]));
+/// ```
+pub trait Geocoder {
+    /// Returns the `(latitude, longitude)` of `place` in decimal degrees, or `None` if unknown.
+    fn geocode(&self, place: &str) -> Option<(f64, f64)>;
+}
+
+impl<F: Fn(&str) -> Option<(f64, f64)>> Geocoder for F {
+    fn geocode(&self, place: &str) -> Option<(f64, f64)> {
+        self(place)
+    }
+}
+
+/// A GeoJSON geometry. Positions are `[longitude, latitude]`, as GeoJSON requires.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum Geometry {
+    /// A single position.
+    Point([f64; 2]),
+    /// Linear rings, the first being the exterior ring.
+    Polygon(Vec<Vec<[f64; 2]>>),
+}
+
+/// A GeoJSON feature: one item and its geometry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename = "Feature")]
+pub struct Feature {
+    /// The item ID.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub id: Option<String>,
+    /// Where the item is.
+    pub geometry: Geometry,
+    /// The item's metadata.
+    pub properties: NormalizedItem,
+}
+
+/// A GeoJSON feature collection.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(tag = "type", rename = "FeatureCollection")]
+pub struct FeatureCollection {
+    /// The features, in the order the items were added.
+    pub features: Vec<Feature>,
+}
+
+impl FeatureCollection {
+    /// Writes the collection as a GeoJSON document.
+    pub fn write<W: Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+/// Collects geolocated items into a [`FeatureCollection`].
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::geojson::{Exporter, Geometry};
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(r#"{"results": [
+///     {"id": "http://www.loc.gov/item/2014717546/", "title": "Washington", "latlong": "38.8951,-77.0364"},
+///     {"id": "http://www.loc.gov/item/2007683735/", "title": "Somewhere"}
+/// ]}"#).unwrap();
+///
+/// let mut exporter = Exporter::new();
+/// for result in page.results.iter().flatten() {
+///     exporter.add_result(result);
+/// }
+/// assert_eq!(exporter.skipped(), 1);
+///
+/// let collection = exporter.finish();
+/// assert_eq!(collection.features[0].geometry, Geometry::Point([-77.0364, 38.8951]));
+///
+/// let mut geojson = Vec::new();
+/// collection.write(&mut geojson).unwrap();
+/// assert!(String::from_utf8(geojson).unwrap().starts_with(r#"{"type":"FeatureCollection","features":[{"type":"Feature","#));
+/// ```
+#[derive(Default)]
+pub struct Exporter<'g> {
+    geocoder: Option<Box<dyn Geocoder + 'g>>,
+    geocoded: HashMap<String, Option<(f64, f64)>>,
+    collection: FeatureCollection,
+    skipped: usize,
+}
+
+impl<'g> Exporter<'g> {
+    /// Creates an [`Exporter`] that only uses coordinates found in the metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places items without coordinates with `geocoder`, trying their locations in order.
+    pub fn geocoder(mut self, geocoder: impl Geocoder + 'g) -> Self {
+        self.geocoder = Some(Box::new(geocoder));
+        self
+    }
+
+    /// Adds a search result.
+    ///
+    /// Returns `false` if no geometry was found and the result was skipped.
+    pub fn add_result(&mut self, result: &ResultItem) -> bool {
+        let geometry = all_strings(&result.latlong).iter().find_map(|latlong| point(latlong));
+        self.add(NormalizedItem::from(result), geometry)
+    }
+
+    /// Adds an item, e.g. the `item` of an [`ItemResponse`](crate::response_models::ItemResponse).
+    ///
+    /// Returns `false` if no geometry was found and the item was skipped.
+    pub fn add_item(&mut self, item: &ItemAttribute) -> bool {
+        let geometry = all_strings(&item.latlong)
+            .iter()
+            .find_map(|latlong| point(latlong))
+            .or_else(|| strings(&item.coordinates).iter().find_map(|bbox| bounding_box(bbox)));
+        self.add(NormalizedItem::from(item), geometry)
+    }
+
+    /// Returns the number of items skipped so far for lack of a geometry.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Consumes the [`Exporter`], returning the collected features.
+    pub fn finish(self) -> FeatureCollection {
+        self.collection
+    }
+
+    fn add(&mut self, item: NormalizedItem, geometry: Option<Geometry>) -> bool {
+        let geometry = geometry.or_else(|| item.location.iter().find_map(|place| self.geocode(place)));
+        let Some(geometry) = geometry else {
+            self.skipped += 1;
+            return false;
+        };
+        self.collection.features.push(Feature { id: item.id.clone(), geometry, properties: item });
+        true
+    }
+
+    fn geocode(&mut self, place: &str) -> Option<Geometry> {
+        let geocoder = self.geocoder.as_ref()?;
+        let position = *self.geocoded.entry(place.to_string()).or_insert_with(|| geocoder.geocode(place));
+        position.filter(|&(lat, lon)| valid(lat, lon)).map(|(lat, lon)| Geometry::Point([lon, lat]))
+    }
+}
+
+/// Reads a `latitude,longitude` pair in decimal degrees.
+fn point(latlong: &str) -> Option<Geometry> {
+    let (lat, lon) = latlong.split_once(',')?;
+    let (lat, lon) = (lat.trim().parse().ok()?, lon.trim().parse().ok()?);
+    valid(lat, lon).then_some(Geometry::Point([lon, lat]))
+}
+
+/// Reads a `W 122°30ʹ--W 122°20ʹ/N 37°50ʹ--N 37°40ʹ` bounding box (west, east / north, south).
+///
+/// A box that has no extent is returned as a point.
+fn bounding_box(bbox: &str) -> Option<Geometry> {
+    let bbox = bbox.trim().trim_start_matches('(').trim_end_matches(')');
+    let (longitudes, latitudes) = bbox.split_once('/')?;
+    let (west, east) = longitudes.split_once("--")?;
+    let (north, south) = latitudes.split_once("--")?;
+    let (west, east, north, south) = (degrees(west)?, degrees(east)?, degrees(north)?, degrees(south)?);
+    if !valid(north, west) || !valid(south, east) {
+        return None;
+    }
+    if west == east && north == south {
+        return Some(Geometry::Point([west, north]));
+    }
+    // Exterior rings run counterclockwise.
+    Some(Geometry::Polygon(vec![vec![[west, south], [east, south], [east, north], [west, north], [west, south]]]))
+}
+
+/// Reads a hemisphere-prefixed angle such as `W 122°30ʹ15ʺ` or `N 37.5°` as signed decimal
+/// degrees.
+fn degrees(angle: &str) -> Option<f64> {
+    let angle = angle.trim();
+    let sign = match angle.chars().next()? {
+        'N' | 'E' => 1.0,
+        'S' | 'W' => -1.0,
+        _ => return None,
+    };
+    let mut value = 0.0;
+    let parts = angle[1..].split(['°', 'ʹ', 'ʺ', '′', '″', '\'', '"']).map(str::trim).filter(|p| !p.is_empty());
+    for (part, unit) in parts.zip([1.0, 60.0, 3600.0]) {
+        value += part.parse::<f64>().ok()? / unit;
+    }
+    Some(sign * value)
+}
+
+/// Returns `true` if the latitude and longitude are within range.
+fn valid(lat: f64, lon: f64) -> bool {
+    (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)
+}
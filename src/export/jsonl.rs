@@ -0,0 +1,86 @@
+//! Newline-delimited JSON (JSONL) export.
+//!
+//! Each [`ResultItem`] is written as a single line of JSON as soon as it is handed to the
+//! [`Writer`], so a harvest only ever needs to hold the current page in memory.
+
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::response_models::{ResultItem, SearchResultResponse};
+
+/// Appends records to an underlying [`Write`] as newline-delimited JSON.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::jsonl::Writer;
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(
+///     r#"{"results": [{"id": "http://www.loc.gov/item/1/"}, {"id": "http://www.loc.gov/item/2/"}]}"#,
+/// ).unwrap();
+///
+/// let mut writer = Writer::new(Vec::new());
+/// writer.write_response(&page).unwrap();
+///
+/// let output = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(output.lines().count(), 2);
+/// ```
+pub struct Writer<W: Write> {
+    inner: W,
+    written: usize,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new [`Writer`] appending to `inner`.
+    ///
+    /// Wrap files in a [`std::io::BufWriter`] to avoid a system call per line.
+    pub fn new(inner: W) -> Self {
+        Writer { inner, written: 0 }
+    }
+
+    /// Writes a single [`ResultItem`] as one line of JSON.
+    pub fn write_item(&mut self, item: &ResultItem) -> io::Result<()> {
+        self.write_record(item)
+    }
+
+    /// Writes every result of a [`SearchResultResponse`] page, one line per item.
+    ///
+    /// Returns the number of items written from this page.
+    pub fn write_response(&mut self, response: &SearchResultResponse) -> io::Result<usize> {
+        let results = match response.results {
+            Some(ref results) => results,
+            None => return Ok(0),
+        };
+
+        for item in results {
+            self.write_item(item)?;
+        }
+
+        Ok(results.len())
+    }
+
+    /// Writes any serializable record as one line of JSON.
+    pub fn write_record<T: Serialize>(&mut self, record: &T) -> io::Result<()> {
+        serde_json::to_writer(&mut self.inner, record)?;
+        self.inner.write_all(b"\n")?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Returns the number of lines written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Consumes the [`Writer`], returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
@@ -0,0 +1,14 @@
+//! # Export Module
+//!
+//! Writers that serialize response models into formats suited for storage and
+//! downstream processing.
+
+pub mod bibtex;
+pub mod csv;
+pub mod geojson;
+pub mod jsonl;
+#[cfg(feature = "polars")]
+pub mod polars;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod zotero;
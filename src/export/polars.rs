@@ -0,0 +1,65 @@
+//! Polars export, enabled with the `polars` feature.
+//!
+//! [`to_dataframe`] turns [`NormalizedItem`]s into a [`DataFrame`] with one row per item and one
+//! column per field. Multi-valued fields such as `subjects` become `list[str]` columns, so they
+//! can be exploded or filtered with list expressions instead of being split again.
+
+use polars::prelude::*;
+
+use crate::mapping::NormalizedItem;
+
+/// Builds a [`DataFrame`] of `items`, in order.
+///
+/// The columns are `id`, `title`, `date`, `url` (`str`), `year` (`i32`), `digitized`
+/// (`bool`), and `description`, `subjects`, `contributors`, `original_format`,
+/// `online_format`, `language` and `location` (`list[str]`). Missing values are null.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::polars::to_dataframe;
+/// use loc_api::mapping::NormalizedItem;
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(r#"{"results": [
+///     {"id": "http://www.loc.gov/item/2014717546/", "title": "Baseball cards", "date": "1887", "subject": ["baseball", "cards"]},
+///     {"id": "http://www.loc.gov/item/2007683735/", "title": "Base ball", "date": "1889"}
+/// ]}"#).unwrap();
+///
+/// let items: Vec<NormalizedItem> = page.results.iter().flatten().map(NormalizedItem::from).collect();
+/// let df = to_dataframe(&items).unwrap();
+/// assert_eq!(df.height(), 2);
+/// assert_eq!(df.column("year").unwrap().i32().unwrap().get(1), Some(1889));
+/// assert_eq!(df.column("subjects").unwrap().list().unwrap().get_as_series(0).unwrap().len(), 2);
+/// ```
+pub fn to_dataframe(items: &[NormalizedItem]) -> PolarsResult<DataFrame> {
+    let text = |name: &str, field: fn(&NormalizedItem) -> Option<&str>| {
+        Column::new(name.into(), items.iter().map(field).collect::<Vec<_>>())
+    };
+
+    DataFrame::new(vec![
+        text("id", |item| item.id.as_deref()),
+        text("title", |item| item.title.as_deref()),
+        text("date", |item| item.date.as_deref()),
+        Column::new("year".into(), items.iter().map(|item| item.year).collect::<Vec<_>>()),
+        text("url", |item| item.url.as_deref()),
+        list(items, "description", |item| &item.description),
+        list(items, "subjects", |item| &item.subjects),
+        list(items, "contributors", |item| &item.contributors),
+        list(items, "original_format", |item| &item.original_format),
+        list(items, "online_format", |item| &item.online_format),
+        list(items, "language", |item| &item.language),
+        list(items, "location", |item| &item.location),
+        Column::new("digitized".into(), items.iter().map(|item| item.digitized).collect::<Vec<_>>()),
+    ])
+}
+
+/// Builds a `list[str]` column of one multi-valued field.
+fn list(items: &[NormalizedItem], name: &str, field: fn(&NormalizedItem) -> &Vec<String>) -> Column {
+    if items.is_empty() {
+        // A list column built from no rows would be typed `null`.
+        return Column::new_empty(name.into(), &DataType::List(Box::new(DataType::String)));
+    }
+    let values: Vec<Series> = items.iter().map(|item| Series::new(PlSmallStr::EMPTY, field(item))).collect();
+    Column::new(name.into(), values)
+}
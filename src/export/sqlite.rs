@@ -0,0 +1,254 @@
+//! SQLite export, enabled with the `sqlite` feature.
+//!
+//! A [`Store`] keeps harvested items in a normalized SQLite database that can be queried with
+//! any SQLite tool:
+//!
+//! - `items`: one row per item ID, with its title, date, description, URL, original format,
+//!   language and the JSON it was read from (`result_json` for search results, `item_json` for
+//!   item responses).
+//! - `subjects` and `contributors`: the subjects and contributor names of each item.
+//! - `files`: the files of each item's resources, from item responses.
+//!
+//! Items are upserted by ID, so a harvest can be stored incrementally and re-run. Values
+//! missing from a newer record keep their stored value, so storing a search result after the
+//! full item response does not lose the item's details. Results without an item ID, such as
+//! collections and web pages, are skipped.
+
+use std::path::Path;
+
+use rusqlite::{params, Connection, Transaction};
+
+use crate::identifiers::ItemId;
+use crate::response_models::{
+    all_strings, first_string, number, strings, unique, ItemOrArray, ItemResponse, ResultItem, SearchResultResponse,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS items (
+    id TEXT PRIMARY KEY,
+    title TEXT,
+    date TEXT,
+    description TEXT,
+    url TEXT,
+    original_format TEXT,
+    language TEXT,
+    result_json TEXT,
+    item_json TEXT
+);
+CREATE TABLE IF NOT EXISTS subjects (
+    item_id TEXT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    PRIMARY KEY (item_id, subject)
+);
+CREATE TABLE IF NOT EXISTS contributors (
+    item_id TEXT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    PRIMARY KEY (item_id, name)
+);
+CREATE TABLE IF NOT EXISTS files (
+    item_id TEXT NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+    resource INTEGER NOT NULL,
+    page INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    mimetype TEXT,
+    width INTEGER,
+    height INTEGER,
+    size INTEGER,
+    PRIMARY KEY (item_id, url)
+);
+CREATE INDEX IF NOT EXISTS subjects_by_subject ON subjects (subject);
+CREATE INDEX IF NOT EXISTS contributors_by_name ON contributors (name);
+";
+
+const UPSERT_ITEM: &str = "
+INSERT INTO items (id, title, date, description, url, original_format, language, result_json, item_json)
+VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+ON CONFLICT (id) DO UPDATE SET
+    title = coalesce(excluded.title, title),
+    date = coalesce(excluded.date, date),
+    description = coalesce(excluded.description, description),
+    url = coalesce(excluded.url, url),
+    original_format = coalesce(excluded.original_format, original_format),
+    language = coalesce(excluded.language, language),
+    result_json = coalesce(excluded.result_json, result_json),
+    item_json = coalesce(excluded.item_json, item_json)
+";
+
+/// A SQLite database of harvested items.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::sqlite::Store;
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(r#"{"results": [
+///     {"id": "http://www.loc.gov/item/2014717546/", "title": "Baseball cards", "subject": ["baseball", "cards"]},
+///     {"id": "http://www.loc.gov/collections/baseball-cards/", "title": "A collection"}
+/// ]}"#).unwrap();
+///
+/// let mut store = Store::open_in_memory().unwrap();
+/// assert_eq!(store.upsert_response(&page).unwrap(), 1);
+///
+/// let subjects: i64 = store
+///     .connection()
+///     .query_row("SELECT count(*) FROM subjects WHERE item_id = '2014717546'", [], |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(subjects, 2);
+/// ```
+pub struct Store {
+    connection: Connection,
+}
+
+impl Store {
+    /// Opens the database at `path`, creating the file and the tables if missing.
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens a database in memory, e.g. for tests.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    /// Uses an already opened database, creating the tables if missing.
+    pub fn with_connection(connection: Connection) -> rusqlite::Result<Self> {
+        connection.execute_batch("PRAGMA foreign_keys = ON;")?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(Store { connection })
+    }
+
+    /// Returns the underlying connection, for queries.
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// Consumes the [`Store`], returning the underlying connection.
+    pub fn into_connection(self) -> Connection {
+        self.connection
+    }
+
+    /// Upserts every result of a [`SearchResultResponse`] page in one transaction.
+    ///
+    /// Returns the number of items stored from this page.
+    pub fn upsert_response(&mut self, response: &SearchResultResponse) -> rusqlite::Result<usize> {
+        let transaction = self.connection.transaction()?;
+        let mut stored = 0;
+        for result in response.results.iter().flatten() {
+            stored += usize::from(upsert_result(&transaction, result)?);
+        }
+        transaction.commit()?;
+        Ok(stored)
+    }
+
+    /// Upserts a single search result.
+    ///
+    /// Returns `false` if the result has no item ID and was skipped.
+    pub fn upsert_result(&mut self, result: &ResultItem) -> rusqlite::Result<bool> {
+        let transaction = self.connection.transaction()?;
+        let stored = upsert_result(&transaction, result)?;
+        transaction.commit()?;
+        Ok(stored)
+    }
+
+    /// Upserts the item of an item response, replacing its stored files.
+    ///
+    /// Returns `false` if the response has no item with an item ID and was skipped.
+    pub fn upsert_item(&mut self, response: &ItemResponse) -> rusqlite::Result<bool> {
+        let Some(item) = response.item.as_ref().and_then(ItemOrArray::first) else {
+            return Ok(false);
+        };
+        let Some(id) = first_string(&item.id).or(first_string(&item.url)).and_then(|id| ItemId::parse(id).ok()) else {
+            return Ok(false);
+        };
+
+        let transaction = self.connection.transaction()?;
+        transaction.execute(
+            UPSERT_ITEM,
+            params![
+                id.as_str(),
+                first_string(&item.title),
+                first_string(&item.date),
+                joined(unique([all_strings(&item.description), all_strings(&item.summary)])),
+                first_string(&item.url),
+                joined(strings(&item.original_format)),
+                joined(strings(&item.language)),
+                None::<String>,
+                serde_json::to_string(item).ok(),
+            ],
+        )?;
+        let mut names = strings(&item.contributor_names);
+        if names.is_empty() {
+            names = strings(&item.contributors);
+        }
+        let subjects = unique([strings(&item.subject_headings), strings(&item.subjects), strings(&item.subject)]);
+        insert_related(&transaction, &id, &names, &subjects)?;
+
+        transaction.execute("DELETE FROM files WHERE item_id = ?1", [id.as_str()])?;
+        for (resource_index, resource) in response.resources.iter().flatten().enumerate() {
+            for (page_index, page) in resource.pages().iter().enumerate() {
+                for file in page {
+                    let Some(url) = first_string(&file.url) else { continue };
+                    transaction.execute(
+                        "INSERT OR REPLACE INTO files (item_id, resource, page, url, mimetype, width, height, size)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                        params![
+                            id.as_str(),
+                            resource_index + 1,
+                            page_index + 1,
+                            url,
+                            first_string(&file.mimetype),
+                            number(&file.width),
+                            number(&file.height),
+                            number(&file.size),
+                        ],
+                    )?;
+                }
+            }
+        }
+        transaction.commit()?;
+        Ok(true)
+    }
+}
+
+fn upsert_result(transaction: &Transaction, result: &ResultItem) -> rusqlite::Result<bool> {
+    let Some(id) = result.item_id() else { return Ok(false) };
+    transaction.execute(
+        UPSERT_ITEM,
+        params![
+            id.as_str(),
+            first_string(&result.title),
+            first_string(&result.date),
+            joined(all_strings(&result.description)),
+            first_string(&result.url),
+            joined(strings(&result.original_format)),
+            joined(strings(&result.language)),
+            serde_json::to_string(result).ok(),
+            None::<String>,
+        ],
+    )?;
+
+    let summary = result.item.as_ref().and_then(ItemOrArray::first);
+    let mut names = summary.map(|s| strings(&s.contributor_names)).unwrap_or_default();
+    if names.is_empty() {
+        names = strings(&result.contributor);
+    }
+    insert_related(transaction, &id, &names, &strings(&result.subject))?;
+    Ok(true)
+}
+
+/// Adds the contributor `names` and `subjects` of an item, keeping those already stored.
+fn insert_related(transaction: &Transaction, id: &ItemId, names: &[&str], subjects: &[&str]) -> rusqlite::Result<()> {
+    for name in names {
+        transaction.execute("INSERT OR IGNORE INTO contributors (item_id, name) VALUES (?1, ?2)", [id.as_str(), *name])?;
+    }
+    for subject in subjects {
+        transaction.execute("INSERT OR IGNORE INTO subjects (item_id, subject) VALUES (?1, ?2)", [id.as_str(), *subject])?;
+    }
+    Ok(())
+}
+
+/// Joins `values` with `; `, or returns `None` if there are none, so the stored value is kept.
+fn joined(values: Vec<&str>) -> Option<String> {
+    (!values.is_empty()).then(|| values.join("; "))
+}
@@ -0,0 +1,423 @@
+//! Zotero export.
+//!
+//! [`ZoteroItem`] maps the metadata of an item to Zotero's item fields. It serializes to the
+//! item JSON of the Zotero web API, and the [`Writer`] renders a whole result set as a Zotero
+//! RDF file, which Zotero imports through *File → Import…*.
+//!
+//! The item type is guessed from the item's original format, e.g. `map` or
+//! `audio recording`, falling back to `document`. Contributor names in the catalog's
+//! `Last, First, dates` form are split into last and first name; other names, such as
+//! corporate bodies, are kept whole.
+
+use std::io::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::response_models::{
+    all_strings, strings, unique, CiteThis, ItemAttribute, ItemOrArray, ItemResponse, ResultItem, SearchResultResponse,
+    StringOrArray,
+};
+
+/// The value of [`ZoteroItem::library_catalog`].
+const LIBRARY_CATALOG: &str = "Library of Congress";
+
+/// An item in the shape of the Zotero web API's item JSON.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::zotero::ZoteroItem;
+/// use loc_api::response_models::ItemAttribute;
+///
+/// let item: ItemAttribute = serde_json::from_str(r#"{
+///     "title": "The Reds and the Blues",
+///     "contributor_names": ["Doe, Jane, 1850-1900"],
+///     "original_format": ["book"],
+///     "date": "1887"
+/// }"#).unwrap();
+///
+/// let zotero = ZoteroItem::from(&item);
+/// assert_eq!(zotero.item_type, "book");
+/// assert_eq!(zotero.creators[0].last_name.as_deref(), Some("Doe"));
+/// assert_eq!(zotero.creators[0].first_name.as_deref(), Some("Jane"));
+///
+/// let json = serde_json::to_value(&zotero).unwrap();
+/// assert_eq!(json["itemType"], "book");
+/// assert_eq!(json["libraryCatalog"], "Library of Congress");
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoteroItem {
+    /// The Zotero item type, e.g. `book`, `map` or `document`.
+    pub item_type: String,
+    /// The title.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub title: String,
+    /// The authors and other contributors.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub creators: Vec<Creator>,
+    /// The description or summary.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub abstract_note: String,
+    /// The date, as given by the catalog.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub date: String,
+    /// The place of publication.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub place: String,
+    /// The languages, comma-separated.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub language: String,
+    /// The item's page on loc.gov.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub url: String,
+    /// The rights statements.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub rights: String,
+    /// The call number or shelf ID.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub call_number: String,
+    /// Always `Library of Congress`.
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub library_catalog: String,
+    /// Free-form notes; holds the loc.gov citations added by [`ZoteroItem::with_citation`].
+    #[serde(skip_serializing_if = "String::is_empty", default)]
+    pub extra: String,
+    /// The subject headings.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub tags: Vec<Tag>,
+}
+
+/// A contributor of a [`ZoteroItem`].
+///
+/// Personal names have [`Creator::last_name`] and usually [`Creator::first_name`]; other names
+/// are kept whole in [`Creator::name`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Creator {
+    /// The role, e.g. `author`.
+    pub creator_type: String,
+    /// The first name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// The last name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// The full name, for names that are not split.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl Creator {
+    /// Reads a catalog name such as `Doe, Jane, 1850-1900.` or `American Tobacco Company`.
+    pub fn parse(name: &str, creator_type: &str) -> Self {
+        let name = name.trim().trim_end_matches(['.', ',']).trim();
+        let mut parts = name.splitn(3, ',').map(str::trim);
+        let (last, first) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+        let personal = !last.is_empty() && !first.is_empty() && !first.starts_with(|c: char| c.is_ascii_digit());
+
+        Creator {
+            creator_type: creator_type.to_string(),
+            first_name: personal.then(|| first.trim_end_matches('.').to_string()),
+            last_name: personal.then(|| last.to_string()),
+            name: (!personal).then(|| name.to_string()),
+        }
+    }
+}
+
+/// A tag of a [`ZoteroItem`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct Tag {
+    /// The tag text.
+    pub tag: String,
+}
+
+impl ZoteroItem {
+    /// Maps the `item` and `cite_this` attributes of an item response, if it has an item.
+    pub fn from_response(response: &ItemResponse) -> Option<Self> {
+        let item = ZoteroItem::from(first(&response.item)?);
+        Some(match first(&response.cite_this) {
+            Some(citation) => item.with_citation(citation),
+            None => item,
+        })
+    }
+
+    /// Adds the Chicago, MLA and APA citations to [`ZoteroItem::extra`], one per line.
+    pub fn with_citation(mut self, citation: &CiteThis) -> Self {
+        for (style, value) in [("Chicago", &citation.chicago), ("MLA", &citation.mla), ("APA", &citation.apa)] {
+            for text in all_strings(value) {
+                if !self.extra.is_empty() {
+                    self.extra.push('\n');
+                }
+                self.extra.push_str(&format!("{}: {}", style, text));
+            }
+        }
+        self
+    }
+
+    fn new(item_type: String, names: Vec<&str>, subjects: Vec<&str>) -> Self {
+        ZoteroItem {
+            item_type,
+            creators: names.iter().map(|name| Creator::parse(name, "author")).collect(),
+            library_catalog: LIBRARY_CATALOG.to_string(),
+            tags: subjects.into_iter().map(|tag| Tag { tag: tag.to_string() }).collect(),
+            ..ZoteroItem::default()
+        }
+    }
+}
+
+impl From<&ItemAttribute> for ZoteroItem {
+    fn from(item: &ItemAttribute) -> Self {
+        let mut names = strings(&item.contributor_names);
+        if names.is_empty() {
+            names = strings(&item.contributors);
+        }
+        let subjects = unique([strings(&item.subject_headings), strings(&item.subjects), strings(&item.subject)]);
+
+        ZoteroItem {
+            title: all_strings(&item.title).join(" "),
+            abstract_note: unique([all_strings(&item.description), all_strings(&item.summary)]).join("\n"),
+            date: all_strings(&item.date).join(", "),
+            place: all_strings(&item.place_of_publication).join("; "),
+            language: strings(&item.language).join(", "),
+            url: link(&item.url, &item.id),
+            rights: strings(&item.rights).join("\n"),
+            call_number: first_of([strings(&item.call_number), all_strings(&item.shelf_id)]),
+            ..ZoteroItem::new(item_type(&strings(&item.original_format)), names, subjects)
+        }
+    }
+}
+
+impl From<&ResultItem> for ZoteroItem {
+    fn from(result: &ResultItem) -> Self {
+        let summary = first(&result.item);
+        let mut names = summary.map(|s| strings(&s.contributor_names)).unwrap_or_default();
+        if names.is_empty() {
+            names = strings(&result.contributor);
+        }
+        let mut subjects = summary.map(|s| strings(&s.subject_headings)).unwrap_or_default();
+        if subjects.is_empty() {
+            subjects = strings(&result.subject);
+        }
+
+        ZoteroItem {
+            title: all_strings(&result.title).join(" "),
+            abstract_note: all_strings(&result.description).join("\n"),
+            date: all_strings(&result.date).join(", "),
+            language: strings(&result.language).join(", "),
+            url: link(&result.url, &result.id),
+            call_number: first_of([
+                summary.map(|s| strings(&s.call_number)).unwrap_or_default(),
+                all_strings(&result.shelf_id),
+            ]),
+            ..ZoteroItem::new(item_type(&strings(&result.original_format)), names, subjects)
+        }
+    }
+}
+
+/// Guesses the Zotero item type from loc.gov original formats.
+fn item_type(formats: &[&str]) -> String {
+    let item_type = formats
+        .iter()
+        .find_map(|format| match format.to_lowercase().as_str() {
+            "book" => Some("book"),
+            "manuscript/mixed material" => Some("manuscript"),
+            "map" => Some("map"),
+            "photo, print, drawing" => Some("artwork"),
+            "audio recording" => Some("audioRecording"),
+            "film, video" => Some("videoRecording"),
+            "newspaper" => Some("newspaperArticle"),
+            "periodical" => Some("magazineArticle"),
+            "legislation" => Some("statute"),
+            "web page" => Some("webpage"),
+            "software, e-resource" => Some("computerProgram"),
+            _ => None,
+        })
+        .unwrap_or("document");
+    item_type.to_string()
+}
+
+fn first<T>(value: &Option<ItemOrArray<T>>) -> Option<&T> {
+    value.as_ref()?.first()
+}
+
+/// Returns the first value of the first non-empty list.
+fn first_of<const N: usize>(lists: [Vec<&str>; N]) -> String {
+    lists.into_iter().flatten().next().unwrap_or_default().to_string()
+}
+
+/// Returns the first `url`, or else the first `id`.
+fn link(url: &Option<StringOrArray>, id: &Option<StringOrArray>) -> String {
+    first_of([all_strings(url), all_strings(id)])
+}
+
+/// Writes [`ZoteroItem`]s to an underlying [`Write`] as a Zotero RDF document.
+///
+/// The document is complete once [`Writer::finish`] has written its closing tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::export::zotero::Writer;
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(r#"{"results": [
+///     {"id": "http://www.loc.gov/item/1/", "title": "Maps & charts", "original_format": ["map"]},
+///     {"id": "http://www.loc.gov/item/2/", "title": "Baseball cards"}
+/// ]}"#).unwrap();
+///
+/// let mut writer = Writer::new(Vec::new());
+/// writer.write_response(&page).unwrap();
+///
+/// let rdf = String::from_utf8(writer.finish().unwrap()).unwrap();
+/// assert!(rdf.contains(r#"<bib:Document rdf:about="http://www.loc.gov/item/1/">"#));
+/// assert!(rdf.contains("<z:itemType>map</z:itemType>"));
+/// assert!(rdf.contains("<dc:title>Maps &amp; charts</dc:title>"));
+/// assert!(rdf.ends_with("</rdf:RDF>\n"));
+/// ```
+pub struct Writer<W: Write> {
+    inner: W,
+    written: usize,
+    started: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new [`Writer`] appending to `inner`.
+    ///
+    /// Wrap files in a [`std::io::BufWriter`] to avoid a system call per element.
+    pub fn new(inner: W) -> Self {
+        Writer { inner, written: 0, started: false }
+    }
+
+    /// Writes a single [`ZoteroItem`].
+    pub fn write_item(&mut self, item: &ZoteroItem) -> io::Result<()> {
+        self.start()?;
+        let out = &mut self.inner;
+        let class = match item.item_type.as_str() {
+            "book" => "Book",
+            "manuscript" => "Manuscript",
+            "artwork" => "Illustration",
+            "audioRecording" | "videoRecording" => "Recording",
+            _ => "Document",
+        };
+        if item.url.is_empty() {
+            writeln!(out, "    <bib:{}>", class)?;
+        } else {
+            writeln!(out, "    <bib:{} rdf:about=\"{}\">", class, escape(&item.url))?;
+        }
+        element(out, "z:itemType", &item.item_type)?;
+        if !item.creators.is_empty() {
+            writeln!(out, "        <bib:authors>\n            <rdf:Seq>")?;
+            for creator in &item.creators {
+                writeln!(out, "                <rdf:li>\n                    <foaf:Person>")?;
+                match (&creator.last_name, &creator.name) {
+                    (Some(last), _) | (None, Some(last)) => {
+                        writeln!(out, "                        <foaf:surname>{}</foaf:surname>", escape(last))?
+                    }
+                    (None, None) => {}
+                }
+                if let Some(first) = &creator.first_name {
+                    writeln!(out, "                        <foaf:givenName>{}</foaf:givenName>", escape(first))?;
+                }
+                writeln!(out, "                    </foaf:Person>\n                </rdf:li>")?;
+            }
+            writeln!(out, "            </rdf:Seq>\n        </bib:authors>")?;
+        }
+        for tag in &item.tags {
+            element(out, "dc:subject", &tag.tag)?;
+        }
+        element(out, "dc:title", &item.title)?;
+        element(out, "dcterms:abstract", &item.abstract_note)?;
+        element(out, "dc:date", &item.date)?;
+        element(out, "z:language", &item.language)?;
+        if !item.url.is_empty() {
+            writeln!(
+                out,
+                "        <dc:identifier>\n            <dcterms:URI>\n                <rdf:value>{}</rdf:value>\n            </dcterms:URI>\n        </dc:identifier>",
+                escape(&item.url)
+            )?;
+        }
+        element(out, "dc:rights", &item.rights)?;
+        if !item.call_number.is_empty() {
+            writeln!(
+                out,
+                "        <dc:subject>\n            <dcterms:LCC>\n                <rdf:value>{}</rdf:value>\n            </dcterms:LCC>\n        </dc:subject>",
+                escape(&item.call_number)
+            )?;
+        }
+        element(out, "z:libraryCatalog", &item.library_catalog)?;
+        element(out, "dc:description", &item.extra)?;
+        if !item.place.is_empty() {
+            writeln!(
+                out,
+                "        <dc:publisher>\n            <foaf:Organization>\n                <vcard:adr>\n                    <vcard:Address>\n                        <vcard:locality>{}</vcard:locality>\n                    </vcard:Address>\n                </vcard:adr>\n            </foaf:Organization>\n        </dc:publisher>",
+                escape(&item.place)
+            )?;
+        }
+        writeln!(out, "    </bib:{}>", class)?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Writes every result of a [`SearchResultResponse`] page.
+    ///
+    /// Returns the number of items written from this page.
+    pub fn write_response(&mut self, response: &SearchResultResponse) -> io::Result<usize> {
+        let results = match response.results {
+            Some(ref results) => results,
+            None => return Ok(0),
+        };
+
+        for result in results {
+            self.write_item(&ZoteroItem::from(result))?;
+        }
+
+        Ok(results.len())
+    }
+
+    /// Returns the number of items written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Writes the closing tag, flushes and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.start()?;
+        self.inner.write_all(b"</rdf:RDF>\n")?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn start(&mut self) -> io::Result<()> {
+        if !self.started {
+            self.inner.write_all(
+                concat!(
+                    "<rdf:RDF\n",
+                    " xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\"\n",
+                    " xmlns:z=\"http://www.zotero.org/namespaces/export#\"\n",
+                    " xmlns:dc=\"http://purl.org/dc/elements/1.1/\"\n",
+                    " xmlns:vcard=\"http://nwalsh.com/rdf/vCard#\"\n",
+                    " xmlns:foaf=\"http://xmlns.com/foaf/0.1/\"\n",
+                    " xmlns:bib=\"http://purl.org/net/biblio#\"\n",
+                    " xmlns:dcterms=\"http://purl.org/dc/terms/\">\n",
+                )
+                .as_bytes(),
+            )?;
+            self.started = true;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `<name>value</name>` on its own line, unless `value` is empty.
+fn element(out: &mut impl Write, name: &str, value: &str) -> io::Result<()> {
+    if value.is_empty() {
+        return Ok(());
+    }
+    writeln!(out, "        <{}>{}</{}>", name, escape(value), name)
+}
+
+/// Escapes the XML special characters of `text`.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,205 @@
+//! # Feed Module
+//!
+//! Atom and RSS feeds of a saved search, for following new additions in a feed reader.
+//!
+//! [`Feed::search`] runs a search sorted by date, newest first, and keeps the title, link, date
+//! and description of each result; [`Feed::from_response`] does the same for a response
+//! fetched another way. [`Feed::write_atom`] and [`Feed::write_rss`] render the feed, e.g. to
+//! serve it over HTTP or write it to a file picked up by a feed reader.
+//!
+//! loc.gov dates such as `1887`, `1887-05` or `1887-05-01` are read as the start of that period.
+//! Atom requires a date on every entry, so entries without one get the feed's
+//! [`updated`](Feed::updated) time.
+
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::response_models::{first_string, SearchResultResponse};
+
+#[cfg(feature = "client")]
+use std::error::Error;
+
+#[cfg(feature = "client")]
+use crate::{attribute_models::SortField, endpoints::Endpoints, loc_client::ApiClient, param_models::SearchParams};
+
+/// A feed of search results.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::feed::Feed;
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(r#"{"results": [{
+///     "id": "http://www.loc.gov/item/2014717546/",
+///     "title": "Baseball players practicing",
+///     "date": "1887-05-01",
+///     "description": ["Photograph of a baseball game."]
+/// }]}"#).unwrap();
+///
+/// let feed = Feed::from_response("Baseball", "https://www.loc.gov/search/?q=baseball", &page);
+/// assert_eq!(feed.entries[0].date.as_deref(), Some("1887-05-01T00:00:00Z"));
+///
+/// let mut atom = Vec::new();
+/// feed.write_atom(&mut atom).unwrap();
+/// let atom = String::from_utf8(atom).unwrap();
+/// assert!(atom.contains("<title>Baseball players practicing</title>"));
+/// assert!(atom.contains("<updated>1887-05-01T00:00:00Z</updated>"));
+///
+/// let mut rss = Vec::new();
+/// feed.write_rss(&mut rss).unwrap();
+/// assert!(String::from_utf8(rss).unwrap().contains("<pubDate>01 May 1887 00:00:00 GMT</pubDate>"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Feed {
+    /// The feed title.
+    pub title: String,
+    /// The URL of the search on loc.gov.
+    pub link: String,
+    /// When the feed was generated, as an RFC 3339 timestamp.
+    pub updated: String,
+    /// The results, in the order of the search.
+    pub entries: Vec<FeedEntry>,
+}
+
+/// One result of a [`Feed`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedEntry {
+    /// The item title.
+    pub title: String,
+    /// The item's page on loc.gov.
+    pub link: String,
+    /// The item date as an RFC 3339 timestamp, if it has a readable one.
+    pub date: Option<String>,
+    /// The item description.
+    pub description: Option<String>,
+}
+
+impl Feed {
+    /// Runs the search in `params`, newest first, and returns its first page as a feed.
+    ///
+    /// Any sort order in `params` is replaced by [`SortField::DateDesc`]. The feed links to the
+    /// search on loc.gov.
+    #[cfg(feature = "client")]
+    pub fn search(client: &ApiClient, title: &str, mut params: SearchParams) -> Result<Feed, Box<dyn Error>> {
+        params.common.sort = SortField::DateDesc.into();
+        let endpoint = Endpoints::Search(params);
+        let (response, _) = client.fetch::<SearchResultResponse>(&endpoint)?;
+
+        // The same URL without `fo=json` is the search page on loc.gov.
+        let url = endpoint.url_with_base("https://www.loc.gov");
+        let link = url.replacen("fo=json&", "", 1).replacen("?fo=json", "", 1);
+        Ok(Feed::from_response(title, &link, &response))
+    }
+
+    /// Builds a feed of the results of `response`, timestamped now.
+    pub fn from_response(title: &str, link: &str, response: &SearchResultResponse) -> Feed {
+        let entries = response
+            .results
+            .iter()
+            .flatten()
+            .map(|result| FeedEntry {
+                title: first_string(&result.title).unwrap_or_default().to_string(),
+                link: first_string(&result.url).or_else(|| first_string(&result.id)).unwrap_or_default().to_string(),
+                date: first_string(&result.date).and_then(timestamp),
+                description: first_string(&result.description).map(str::to_string),
+            })
+            .collect();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        Feed { title: title.to_string(), link: link.to_string(), updated: rfc3339(now), entries }
+    }
+
+    /// Writes the feed as an Atom 1.0 document.
+    pub fn write_atom(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+        writeln!(out, "<feed xmlns=\"http://www.w3.org/2005/Atom\">")?;
+        writeln!(out, "  <title>{}</title>", escape(&self.title))?;
+        writeln!(out, "  <link href=\"{}\"/>", escape(&self.link))?;
+        writeln!(out, "  <id>{}</id>", escape(&self.link))?;
+        writeln!(out, "  <updated>{}</updated>", self.updated)?;
+        writeln!(out, "  <author><name>Library of Congress</name></author>")?;
+        for entry in &self.entries {
+            writeln!(out, "  <entry>")?;
+            writeln!(out, "    <title>{}</title>", escape(&entry.title))?;
+            writeln!(out, "    <link href=\"{}\"/>", escape(&entry.link))?;
+            writeln!(out, "    <id>{}</id>", escape(&entry.link))?;
+            writeln!(out, "    <updated>{}</updated>", entry.date.as_deref().unwrap_or(&self.updated))?;
+            if let Some(description) = &entry.description {
+                writeln!(out, "    <summary>{}</summary>", escape(description))?;
+            }
+            writeln!(out, "  </entry>")?;
+        }
+        writeln!(out, "</feed>")
+    }
+
+    /// Writes the feed as an RSS 2.0 document.
+    pub fn write_rss(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+        writeln!(out, "<rss version=\"2.0\">")?;
+        writeln!(out, "  <channel>")?;
+        writeln!(out, "    <title>{}</title>", escape(&self.title))?;
+        writeln!(out, "    <link>{}</link>", escape(&self.link))?;
+        writeln!(out, "    <description>{}</description>", escape(&self.title))?;
+        if let Some(date) = rfc822(&self.updated) {
+            writeln!(out, "    <lastBuildDate>{}</lastBuildDate>", date)?;
+        }
+        for entry in &self.entries {
+            writeln!(out, "    <item>")?;
+            writeln!(out, "      <title>{}</title>", escape(&entry.title))?;
+            writeln!(out, "      <link>{}</link>", escape(&entry.link))?;
+            writeln!(out, "      <guid>{}</guid>", escape(&entry.link))?;
+            if let Some(date) = entry.date.as_deref().and_then(rfc822) {
+                writeln!(out, "      <pubDate>{}</pubDate>", date)?;
+            }
+            if let Some(description) = &entry.description {
+                writeln!(out, "      <description>{}</description>", escape(description))?;
+            }
+            writeln!(out, "    </item>")?;
+        }
+        writeln!(out, "  </channel>")?;
+        writeln!(out, "</rss>")
+    }
+}
+
+/// Reads a loc.gov date (`1887`, `1887-05` or `1887-05-01`, optionally followed by more) as an
+/// RFC 3339 timestamp at the start of that period.
+fn timestamp(date: &str) -> Option<String> {
+    let mut parts = date.get(..10.min(date.len()))?.split('-');
+    let year: u32 = parts.next().filter(|y| y.len() == 4)?.parse().ok()?;
+    let month: u32 = parts.next().map_or(Some(1), |m| m.parse().ok())?;
+    let day: u32 = parts.next().map_or(Some(1), |d| d.parse().ok())?;
+    ((1..=12).contains(&month) && (1..=31).contains(&day))
+        .then(|| format!("{:04}-{:02}-{:02}T00:00:00Z", year, month, day))
+}
+
+/// Formats seconds since the Unix epoch as an RFC 3339 timestamp in UTC.
+fn rfc3339(secs: u64) -> String {
+    // Civil date from days since 1970-01-01, after Howard Hinnant's `civil_from_days`.
+    let days = secs / 86_400;
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    let time = secs % 86_400;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, time / 3600, time / 60 % 60, time % 60)
+}
+
+/// Rewrites an RFC 3339 timestamp in UTC as an RFC 822 date, without the optional weekday.
+fn rfc822(timestamp: &str) -> Option<String> {
+    const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    let (date, time) = timestamp.strip_suffix('Z')?.split_once('T')?;
+    let mut parts = date.split('-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+    let month = MONTHS.get(month.parse::<usize>().ok()?.checked_sub(1)?)?;
+    Some(format!("{} {} {} {} GMT", day, month, year, time))
+}
+
+/// Escapes the XML special characters of `text`.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,27 @@
+//! # Fixtures Module
+//!
+//! Representative loc.gov JSON responses bundled with the crate, trimmed to a few results.
+//! They back [`ApiClient::offline`](crate::loc_client::ApiClient::offline) and the mock server
+//! of the `testing` feature, and can be deserialized directly into the response models.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use loc_api::fixtures;
+//! use loc_api::response_models::{ItemResponse, SearchResultResponse};
+//!
+//! let search: SearchResultResponse = serde_json::from_str(fixtures::SEARCH).unwrap();
+//! assert_eq!(search.results.unwrap().len(), 2);
+//!
+//! let item: ItemResponse = serde_json::from_str(fixtures::ITEM).unwrap();
+//! assert!(item.cite_this.is_some());
+//! ```
+
+/// A `/search/?q=baseball` page with facets, pagination and two results.
+pub const SEARCH: &str = include_str!("fixtures/search.json");
+/// The `/item/2014717546/` response.
+pub const ITEM: &str = include_str!("fixtures/item.json");
+/// The `/resource/bbc.0001f/` response, with thumbnail, access and master files.
+pub const RESOURCE: &str = include_str!("fixtures/resource.json");
+/// The `/collections/baseball-cards/` response.
+pub const COLLECTION: &str = include_str!("fixtures/collection.json");
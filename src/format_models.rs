@@ -2,6 +2,7 @@ use serde::{Serialize, Deserialize};
 
 /// Represents the possible response formats for API requests.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Format {
     /// JSON format (`fo=json`).
     #[serde(rename = "json")]
@@ -28,7 +29,8 @@ impl Format {
 }
 
 /// Enum to represent specific format types for the `/{format}/` endpoint.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum MediaType {
     /// Audio recordings (`/audio/`).
     Audio,
@@ -50,11 +52,15 @@ pub enum MediaType {
     NotatedMusic,
     /// Web archives (`/web-archives/`).
     WebArchives,
+    /// A format endpoint not modeled by the other variants (`/{slug}/`).
+    ///
+    /// The slug is used verbatim; parse with [`str::parse`] to normalize it.
+    Custom(String),
 }
 
 impl MediaType {
     /// Returns the corresponding slug used in the API URL for each format type.
-    pub fn slug(&self) -> &'static str {
+    pub fn slug(&self) -> &str {
         match self {
             MediaType::Audio => "audio",
             MediaType::Books => "books",
@@ -66,6 +72,40 @@ impl MediaType {
             MediaType::Photos => "photos",
             MediaType::NotatedMusic => "notated-music",
             MediaType::WebArchives => "web-archives",
+            MediaType::Custom(slug) => slug,
         }
     }
 }
+
+impl std::str::FromStr for MediaType {
+    type Err = std::convert::Infallible;
+
+    /// Parses a format slug, normalizing case, surrounding slashes, spaces and underscores;
+    /// slugs without a typed variant become [`MediaType::Custom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::format_models::MediaType;
+    ///
+    /// assert_eq!("Film_and Videos".parse::<MediaType>().unwrap(), MediaType::FilmAndVideos);
+    /// assert_eq!("/Born Digital/".parse::<MediaType>().unwrap(), MediaType::Custom("born-digital".into()));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slug = s.trim().trim_matches('/').to_lowercase().replace([' ', '_'], "-");
+        let media_type = match slug.as_str() {
+            "audio" => MediaType::Audio,
+            "books" => MediaType::Books,
+            "film-and-videos" => MediaType::FilmAndVideos,
+            "legislation" => MediaType::Legislation,
+            "manuscripts" => MediaType::Manuscripts,
+            "maps" => MediaType::Maps,
+            "newspapers" => MediaType::Newspapers,
+            "photos" => MediaType::Photos,
+            "notated-music" => MediaType::NotatedMusic,
+            "web-archives" => MediaType::WebArchives,
+            _ => MediaType::Custom(slug),
+        };
+        Ok(media_type)
+    }
+}
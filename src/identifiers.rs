@@ -0,0 +1,155 @@
+//! Identifiers accepted by the LOC API, such as item IDs and LCCNs.
+//!
+//! Users rarely have a bare item ID at hand: they copy a `https://www.loc.gov/item/.../` URL out
+//! of a browser or an LCCN permalink out of a catalog record. [`ItemId`] normalizes all of these
+//! into the ID expected by the `/item/{item_id}/` endpoint.
+
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+/// The identifier of a single item, as used by the `/item/{item_id}/` endpoint.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub struct ItemId(String);
+
+impl ItemId {
+    /// Parses an item ID from a loc.gov item URL, an `lccn.loc.gov` permalink or a raw ID.
+    ///
+    /// Query strings, fragments and trailing path segments (such as `/marcxml`) are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: The URL, permalink or ID to parse.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`ItemId`], or a [`ParseItemIdError`] if the input is empty or is a URL
+    /// that does not point at an item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::identifiers::ItemId;
+    ///
+    /// let from_url = ItemId::parse("https://www.loc.gov/item/2014717546/").unwrap();
+    /// let from_lccn = ItemId::parse("https://lccn.loc.gov/2014717546").unwrap();
+    /// let from_raw = ItemId::parse("2014717546").unwrap();
+    /// assert_eq!(from_url, from_raw);
+    /// assert_eq!(from_lccn.as_str(), "2014717546");
+    ///
+    /// assert!(ItemId::parse("https://www.loc.gov/search/?q=baseball").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<ItemId, ParseItemIdError> {
+        let error = || ParseItemIdError { input: input.to_string() };
+        let trimmed = input.trim();
+
+        let without_scheme = trimmed
+            .strip_prefix("https://")
+            .or_else(|| trimmed.strip_prefix("http://"))
+            .or_else(|| trimmed.strip_prefix("//"))
+            .unwrap_or(trimmed);
+        let without_suffix = without_scheme
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default();
+
+        let mut segments = without_suffix.split('/').filter(|s| !s.is_empty());
+        let first = segments.next().ok_or_else(error)?;
+
+        let id = match first.to_lowercase().as_str() {
+            "lccn.loc.gov" => segments.next(),
+            "loc.gov" | "www.loc.gov" => match segments.next() {
+                Some("item") => segments.next(),
+                _ => None,
+            },
+            "item" => segments.next(),
+            _ if segments.next().is_none() => Some(first),
+            _ => None,
+        };
+
+        match id {
+            Some(id) => Ok(ItemId(id.to_string())),
+            None => Err(error()),
+        }
+    }
+
+    /// Returns the item ID as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::str::FromStr for ItemId {
+    type Err = ParseItemIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ItemId::parse(s)
+    }
+}
+
+/// Parses the input with [`ItemId::parse`], falling back to the trimmed input when it is not
+/// recognized so that the API reports the problem.
+impl From<&str> for ItemId {
+    fn from(input: &str) -> Self {
+        ItemId::parse(input).unwrap_or_else(|_| ItemId(input.trim().to_string()))
+    }
+}
+
+impl From<&String> for ItemId {
+    fn from(input: &String) -> Self {
+        ItemId::from(input.as_str())
+    }
+}
+
+impl From<String> for ItemId {
+    fn from(input: String) -> Self {
+        ItemId::from(input.as_str())
+    }
+}
+
+impl From<&ItemId> for ItemId {
+    fn from(id: &ItemId) -> Self {
+        id.clone()
+    }
+}
+
+/// The error returned by [`ItemId::parse`] when the input is not a recognizable item identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseItemIdError {
+    /// The input that could not be parsed.
+    pub input: String,
+}
+
+impl fmt::Display for ParseItemIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not a loc.gov item ID, item URL or LCCN permalink: {:?}", self.input)
+    }
+}
+
+impl Error for ParseItemIdError {}
+
+/// Reduces a resource ID or `https://www.loc.gov/resource/{id}/` URL to the `{id}` path,
+/// keeping the inner slashes of segment-level IDs (`sn83045462/1914-07-28/ed-1`).
+#[cfg(any(feature = "client", feature = "async"))]
+pub(crate) fn resource_path(input: &str) -> &str {
+    let input = input.trim();
+    let input = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .or_else(|| input.strip_prefix("//"))
+        .unwrap_or(input);
+    let input = input
+        .strip_prefix("www.loc.gov")
+        .or_else(|| input.strip_prefix("loc.gov"))
+        .unwrap_or(input)
+        .trim_start_matches('/');
+    let input = input.strip_prefix("resource/").unwrap_or(input);
+    let input = input.split(['?', '#']).next().unwrap_or_default();
+    input.trim_matches('/')
+}
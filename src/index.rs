@@ -0,0 +1,342 @@
+//! # Index Module
+//!
+//! A local search index of harvested items, enabled with the `tantivy` feature, for searching a
+//! harvested corpus offline without hitting the API again.
+//!
+//! An [`Index`] holds one document per item ID with the title, description, date, subjects and
+//! contributors of the item, and optionally its full text, e.g. OCR text fetched with
+//! [`ApiClient::get_page_text`](crate::loc_client::ApiClient::get_page_text). Adding an item
+//! again replaces its document.
+//!
+//! Subjects, original formats, online formats, languages, locations and years are also indexed
+//! as facets, so searches can be filtered by them and [`Index::facet_counts`] can summarize a
+//! result set like the API's own facets.
+
+use std::error::Error;
+use std::path::Path;
+
+use tantivy::collector::{Count, FacetCollector, TopDocs};
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{AllQuery, BooleanQuery, Occur, Query, QueryParser, TermQuery};
+use tantivy::schema::{Facet, FacetOptions, Field, IndexRecordOption, Schema, Value, STORED, STRING, TEXT};
+use tantivy::{IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term};
+
+use crate::identifiers::ItemId;
+use crate::response_models::{all_strings, first_string, strings, unique, ItemOrArray, ItemResponse, ResultItem};
+
+/// The facets indexed for every item, as used in [`Index::search`] filters.
+pub const FACETS: [&str; 6] = ["subject", "original_format", "online_format", "language", "location", "year"];
+
+/// Memory budget of the index writer.
+const WRITER_MEMORY: usize = 50_000_000;
+
+struct Fields {
+    id: Field,
+    title: Field,
+    description: Field,
+    date: Field,
+    url: Field,
+    subject: Field,
+    contributor: Field,
+    full_text: Field,
+    facet: Field,
+}
+
+impl Fields {
+    fn schema() -> (Schema, Fields) {
+        let mut builder = Schema::builder();
+        let fields = Fields {
+            id: builder.add_text_field("id", STRING | STORED),
+            title: builder.add_text_field("title", TEXT | STORED),
+            description: builder.add_text_field("description", TEXT | STORED),
+            date: builder.add_text_field("date", STRING | STORED),
+            url: builder.add_text_field("url", STRING | STORED),
+            subject: builder.add_text_field("subject", TEXT | STORED),
+            contributor: builder.add_text_field("contributor", TEXT | STORED),
+            full_text: builder.add_text_field("full_text", TEXT),
+            facet: builder.add_facet_field("facet", FacetOptions::default()),
+        };
+        (builder.build(), fields)
+    }
+}
+
+/// A local full-text and faceted search index of items.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::index::Index;
+/// use loc_api::response_models::SearchResultResponse;
+///
+/// let page: SearchResultResponse = serde_json::from_str(r#"{"results": [
+///     {"id": "http://www.loc.gov/item/1/", "title": "Baseball cards", "subject": ["baseball"], "date": "1887"},
+///     {"id": "http://www.loc.gov/item/2/", "title": "Civil war maps", "subject": ["maps"], "date": "1863"}
+/// ]}"#).unwrap();
+///
+/// let mut index = Index::in_memory().unwrap();
+/// for result in page.results.as_deref().unwrap_or_default() {
+///     index.add_result(result, None).unwrap();
+/// }
+/// index.commit().unwrap();
+///
+/// let hits = index.search("baseball", &[], 10).unwrap();
+/// assert_eq!(hits.len(), 1);
+/// assert_eq!(hits[0].id, "1");
+///
+/// let hits = index.search("", &[("year", "1863")], 10).unwrap();
+/// assert_eq!(hits[0].title, "Civil war maps");
+/// ```
+pub struct Index {
+    index: tantivy::Index,
+    fields: Fields,
+    writer: IndexWriter,
+    reader: IndexReader,
+}
+
+/// A document matching an [`Index::search`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    /// The item ID.
+    pub id: String,
+    /// The item title.
+    pub title: String,
+    /// The item date.
+    pub date: Option<String>,
+    /// The item's page on loc.gov.
+    pub url: Option<String>,
+    /// The relevance score, higher is better.
+    pub score: f32,
+}
+
+impl Index {
+    /// Opens the index in the directory `path`, creating the directory and the index if missing.
+    pub fn open(path: impl AsRef<Path>) -> tantivy::Result<Self> {
+        std::fs::create_dir_all(&path)?;
+        let (schema, fields) = Fields::schema();
+        let directory = MmapDirectory::open(path)?;
+        Self::new(tantivy::Index::open_or_create(directory, schema)?, fields)
+    }
+
+    /// Creates an index in memory, e.g. for tests.
+    pub fn in_memory() -> tantivy::Result<Self> {
+        let (schema, fields) = Fields::schema();
+        Self::new(tantivy::Index::create_in_ram(schema), fields)
+    }
+
+    fn new(index: tantivy::Index, fields: Fields) -> tantivy::Result<Self> {
+        let writer = index.writer(WRITER_MEMORY)?;
+        let reader = index.reader_builder().reload_policy(ReloadPolicy::Manual).try_into()?;
+        Ok(Index { index, fields, writer, reader })
+    }
+
+    /// Adds a search result, with the `full_text` of the item if it was fetched.
+    ///
+    /// Returns `false` if the result has no item ID and was skipped. The document becomes
+    /// searchable after the next [`Index::commit`].
+    pub fn add_result(&mut self, result: &ResultItem, full_text: Option<&str>) -> tantivy::Result<bool> {
+        let Some(id) = result.item_id() else { return Ok(false) };
+        let summary = result.item.as_ref().and_then(ItemOrArray::first);
+        let mut contributors = summary.map(|s| strings(&s.contributor_names)).unwrap_or_default();
+        if contributors.is_empty() {
+            contributors = strings(&result.contributor);
+        }
+
+        let document = Document {
+            id: id.as_str(),
+            title: first_string(&result.title),
+            description: all_strings(&result.description),
+            date: first_string(&result.date),
+            url: first_string(&result.url).or(first_string(&result.id)),
+            subjects: strings(&result.subject),
+            contributors,
+            full_text,
+            facets: vec![
+                ("original_format", strings(&result.original_format)),
+                ("online_format", strings(&result.online_format)),
+                ("language", strings(&result.language)),
+                ("location", strings(&result.location)),
+            ],
+        };
+        self.add(document)?;
+        Ok(true)
+    }
+
+    /// Adds the item of an item response, with its `full_text` if it was fetched.
+    ///
+    /// Returns `false` if the response has no item with an item ID and was skipped. The
+    /// document becomes searchable after the next [`Index::commit`].
+    pub fn add_item(&mut self, response: &ItemResponse, full_text: Option<&str>) -> tantivy::Result<bool> {
+        let Some(item) = response.item.as_ref().and_then(ItemOrArray::first) else {
+            return Ok(false);
+        };
+        let Some(id) = first_string(&item.id).or(first_string(&item.url)).and_then(|id| ItemId::parse(id).ok()) else {
+            return Ok(false);
+        };
+        let mut contributors = strings(&item.contributor_names);
+        if contributors.is_empty() {
+            contributors = strings(&item.contributors);
+        }
+
+        let document = Document {
+            id: id.as_str(),
+            title: first_string(&item.title),
+            description: unique([all_strings(&item.description), all_strings(&item.summary)]),
+            date: first_string(&item.date),
+            url: first_string(&item.url),
+            subjects: unique([strings(&item.subject_headings), strings(&item.subjects), strings(&item.subject)]),
+            contributors,
+            full_text,
+            facets: vec![
+                ("original_format", strings(&item.original_format)),
+                ("online_format", strings(&item.online_format)),
+                ("language", strings(&item.language)),
+                ("location", strings(&item.locations)),
+            ],
+        };
+        self.add(document)?;
+        Ok(true)
+    }
+
+    /// Makes the documents added so far searchable and persists them.
+    pub fn commit(&mut self) -> tantivy::Result<()> {
+        self.writer.commit()?;
+        self.reader.reload()
+    }
+
+    /// Returns the number of searchable documents.
+    pub fn len(&self) -> u64 {
+        self.reader.searcher().num_docs()
+    }
+
+    /// Returns `true` if no document is searchable.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Searches the title, description, subjects, contributors and full text.
+    ///
+    /// # Parameters
+    ///
+    /// - `query`: The query in tantivy's query syntax, e.g. `baseball AND cards` or
+    ///   `title:"civil war"`. An empty query matches every document.
+    /// - `filters`: `(facet, value)` pairs every hit must have, e.g. `("subject", "baseball")`;
+    ///   see [`FACETS`].
+    /// - `limit`: The maximum number of hits.
+    ///
+    /// # Returns
+    ///
+    /// Returns the best hits, best first, or an error if the query does not parse.
+    pub fn search(&self, query: &str, filters: &[(&str, &str)], limit: usize) -> Result<Vec<Hit>, Box<dyn Error>> {
+        let searcher = self.reader.searcher();
+        let query = self.query(query, filters)?;
+        let mut hits = Vec::new();
+        for (score, address) in searcher.search(&query, &TopDocs::with_limit(limit))? {
+            let document: TantivyDocument = searcher.doc(address)?;
+            let text = |field: Field| document.get_first(field).and_then(|v| v.as_str()).map(str::to_string);
+            hits.push(Hit {
+                id: text(self.fields.id).unwrap_or_default(),
+                title: text(self.fields.title).unwrap_or_default(),
+                date: text(self.fields.date),
+                url: text(self.fields.url),
+                score,
+            });
+        }
+        Ok(hits)
+    }
+
+    /// Counts the documents matching a search, like [`Index::search`] without a limit.
+    pub fn count(&self, query: &str, filters: &[(&str, &str)]) -> Result<usize, Box<dyn Error>> {
+        Ok(self.reader.searcher().search(&self.query(query, filters)?, &Count)?)
+    }
+
+    /// Counts the values of `facet` (one of [`FACETS`]) among the documents matching a search.
+    ///
+    /// # Returns
+    ///
+    /// Returns `(value, count)` pairs, most frequent first.
+    pub fn facet_counts(&self, facet: &str, query: &str, filters: &[(&str, &str)]) -> Result<Vec<(String, u64)>, Box<dyn Error>> {
+        let mut collector = FacetCollector::for_field("facet");
+        let root = Facet::from_path([facet]);
+        collector.add_facet(root.clone());
+        let counts = self.reader.searcher().search(&self.query(query, filters)?, &collector)?;
+
+        let mut values: Vec<(String, u64)> = counts
+            .get(&root.to_path_string())
+            .filter_map(|(value, count)| value.to_path().last().map(|v| (v.to_string(), count)))
+            .collect();
+        values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(values)
+    }
+
+    fn query(&self, query: &str, filters: &[(&str, &str)]) -> Result<Box<dyn Query>, Box<dyn Error>> {
+        let fields = &self.fields;
+        let text_query: Box<dyn Query> = if query.trim().is_empty() {
+            Box::new(AllQuery)
+        } else {
+            let default_fields = vec![fields.title, fields.description, fields.subject, fields.contributor, fields.full_text];
+            QueryParser::for_index(&self.index, default_fields).parse_query(query)?
+        };
+        if filters.is_empty() {
+            return Ok(text_query);
+        }
+
+        let mut clauses = vec![(Occur::Must, text_query)];
+        for (facet, value) in filters {
+            let term = Term::from_facet(fields.facet, &Facet::from_path([*facet, value.to_lowercase().as_str()]));
+            clauses.push((Occur::Must, Box::new(TermQuery::new(term, IndexRecordOption::Basic))));
+        }
+        Ok(Box::new(BooleanQuery::new(clauses)))
+    }
+
+    fn add(&mut self, document: Document) -> tantivy::Result<()> {
+        let fields = &self.fields;
+        self.writer.delete_term(Term::from_field_text(fields.id, document.id));
+
+        let mut doc = TantivyDocument::default();
+        doc.add_text(fields.id, document.id);
+        for (field, value) in [(fields.title, document.title), (fields.date, document.date), (fields.url, document.url)] {
+            if let Some(value) = value {
+                doc.add_text(field, value);
+            }
+        }
+        for description in &document.description {
+            doc.add_text(fields.description, description);
+        }
+        for subject in &document.subjects {
+            doc.add_text(fields.subject, subject);
+        }
+        for contributor in &document.contributors {
+            doc.add_text(fields.contributor, contributor);
+        }
+        if let Some(full_text) = document.full_text {
+            doc.add_text(fields.full_text, full_text);
+        }
+
+        let year = document.date.and_then(|date| date.get(..4)).filter(|year| year.bytes().all(|b| b.is_ascii_digit()));
+        let facets = document
+            .facets
+            .into_iter()
+            .chain([("subject", document.subjects.clone()), ("year", year.into_iter().collect())]);
+        for (facet, values) in facets {
+            for value in values {
+                doc.add_facet(fields.facet, Facet::from_path([facet, value.to_lowercase().as_str()]));
+            }
+        }
+
+        self.writer.add_document(doc)?;
+        Ok(())
+    }
+}
+
+/// The indexed values of one item.
+struct Document<'a> {
+    id: &'a str,
+    title: Option<&'a str>,
+    description: Vec<&'a str>,
+    date: Option<&'a str>,
+    url: Option<&'a str>,
+    subjects: Vec<&'a str>,
+    contributors: Vec<&'a str>,
+    full_text: Option<&'a str>,
+    facets: Vec<(&'static str, Vec<&'a str>)>,
+}
@@ -1,8 +1,42 @@
-#![doc = include_str!("../README.md")]
+#![cfg_attr(feature = "client", doc = include_str!("../README.md"))]
 
+#[cfg(feature = "async")]
+pub mod async_client;
 pub mod attribute_models;
+#[cfg(feature = "record")]
+pub mod cassette;
+pub mod diff;
+#[cfg(feature = "client")]
+pub mod download;
 pub mod endpoints;
+pub mod export;
+pub mod feed;
+pub mod fixtures;
 pub mod format_models;
+pub mod identifiers;
+#[cfg(feature = "tantivy")]
+pub mod index;
+pub mod mapping;
+#[cfg(feature = "client")]
+pub mod metrics;
+#[cfg(feature = "client")]
+pub mod middleware;
 pub mod param_models;
+#[cfg(feature = "client")]
+pub mod pool;
 pub mod response_models;
+pub mod sitemaps;
+pub mod snapshot;
+#[cfg(feature = "client")]
+pub mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "client")]
+pub mod throttle;
+#[cfg(feature = "client")]
+pub mod transport;
+#[cfg(feature = "client")]
 pub mod loc_client;
+
+#[cfg(feature = "schemars")]
+pub use response_models::schemas;
@@ -2,415 +2,2661 @@
 //! the Library of Congress API. It abstracts the complexities of endpoint construction,
 //! parameter management, and HTTP requests, offering straightforward methods for common operations.
 //!
-//! All methods return a tuple containing the deserialized JSON response and the final URL used
+//! All methods return a tuple containing the deserialized JSON response and the final URL used.
+//! [`ApiClient::fetch`] accepts any [`Endpoints`] value and deserializes into a caller-supplied type.
+//! The `*_with_raw` variants additionally return the untouched JSON body.
 
+#[cfg(feature = "record")]
+use crate::cassette::{RecordingTransport, ReplayTransport};
+use crate::download::{self, SavedPage};
+use crate::identifiers::{resource_path, ItemId};
+use crate::sitemaps::{Sitemap, SitemapEntry};
+use crate::metrics::{Metrics, NoopMetrics};
+use crate::stream::{ResultStream, SearchStream};
+use crate::throttle::{Throttle, ThrottleConfig};
+use crate::transport::{
+    self, HttpTransport, OfflineTransport, PoolConfig, ProxyConfig, StreamingResponse, TransportError, TransportOptions,
+};
+use crate::middleware::{self, Middleware};
+use crate::pool::{BulkItems, ClientPool};
 use crate::{response_models::*, param_models::*, attribute_models::*, format_models::*, endpoints::*};
 use std::error::Error;
-use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use serde_json::Value;
 use std::env;
+use std::fs;
+use std::io;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 pub const DEFAULT_BASE_URL: &str = "https://www.loc.gov/";
 
+/// Base URL of the LCCN permalink service, which serves bibliographic renditions of catalog records.
+pub const LCCN_BASE_URL: &str = "https://lccn.loc.gov/";
+
+/// Result of [`ApiClient::execute_url_with_retry`]: the body and metadata, or the last error
+/// with the number of attempts made.
+pub(crate) type RetryResult = Result<(Vec<u8>, ResponseMeta), (Box<dyn Error>, u32)>;
+
+/// Maximum number of redirects followed for a single request.
+const MAX_REDIRECTS: usize = 10;
+
+/// How long cached responses are served when [`ApiClientBuilder::cache_ttl`] is not set.
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Callback invoked with the URL of every request before it is sent.
+pub type RequestHook = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Callback invoked with the [`ResponseMeta`] of every response that was received.
+pub type ResponseHook = Arc<dyn Fn(&ResponseMeta) + Send + Sync>;
+
 /// A client for interacting with the Library of Congress API.
 ///
 /// Provides high-level methods to perform API requests without manually constructing
 /// parameters or URLs.
+///
+/// Cloning is cheap: clones share the same connection pool, response cache, rate limiter,
+/// hooks and middleware, so a client can be handed to other threads without wrapping it in an
+/// [`Arc`]. [`ApiClient::last_response_meta`] reports the last response received by any clone.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::loc_client::ApiClient;
+/// use std::thread;
+///
+/// let client = ApiClient::offline();
+/// let worker = client.clone();
+/// let handle = thread::spawn(move || worker.get_item("2014717546", None).is_ok());
+/// assert!(handle.join().unwrap());
+/// ```
+#[derive(Clone)]
 pub struct ApiClient {
+    inner: Arc<ClientInner>,
+}
+
+/// The state of an [`ApiClient`], shared by its clones.
+struct ClientInner {
     base_url: String,
-    client: Client,
+    transport: Box<dyn HttpTransport>,
+    last_meta: Mutex<Option<ResponseMeta>>,
+    on_request: Option<RequestHook>,
+    on_response: Option<ResponseHook>,
+    metrics: Arc<dyn Metrics>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    default_attributes: Option<AttributesSelect>,
+    default_item_attributes: Option<ItemAttributes>,
+    default_per_page: Option<u32>,
+    default_page: Option<u32>,
+    default_sort: Option<SortOrder>,
+    throttle: Option<Throttle>,
+    cache_dir: Option<PathBuf>,
+    cache_ttl: Duration,
+    default_headers: Vec<(String, String)>,
+    download_timeout: Option<Duration>,
+    retries: u32,
+    retry_backoff: Duration,
 }
 
-impl ApiClient {
-    /// Creates a new [`ApiClient`] instance.
-    ///
-    /// The base URL can be overridden by setting the `LOC_API_BASE_URL` environment variable.
+/// Deployment settings for an [`ApiClient`], usually read from the environment with
+/// [`ClientConfig::from_env`] and applied with [`ApiClientBuilder::config`].
+///
+/// Fields left as `None` keep the client defaults.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClientConfig {
+    /// The base URL (`LOC_API_BASE_URL`).
+    pub base_url: Option<String>,
+    /// The timeout of a single HTTP request (`LOC_API_TIMEOUT`, in seconds).
+    pub timeout: Option<Duration>,
+    /// The `User-Agent` header sent with every request (`LOC_API_USER_AGENT`).
+    pub user_agent: Option<String>,
+    /// Requests per minute, applied to every endpoint with [`ThrottleConfig::uniform`]
+    /// (`LOC_API_RATE_LIMIT`).
+    pub rate_limit: Option<u32>,
+    /// Directory in which successful responses are cached (`LOC_API_CACHE_DIR`).
+    pub cache_dir: Option<PathBuf>,
+    /// How long cached responses stay fresh (`LOC_API_CACHE_TTL`, in seconds).
+    pub cache_ttl: Option<Duration>,
+}
+
+impl ClientConfig {
+    /// Reads the configuration from the `LOC_API_*` environment variables.
     ///
-    /// base_url
+    /// Variables that are unset, empty or cannot be parsed are ignored.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::loc_client::{ApiClient, ClientConfig};
     ///
-    /// let client = ApiClient::new();
+    /// // Equivalent to `ApiClient::new()`.
+    /// let client = ApiClient::builder()
+    ///     .config(ClientConfig::from_env())
+    ///     .build();
     /// ```
+    pub fn from_env() -> Self {
+        let var = |name: &str| env::var(name).ok().map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+
+        ClientConfig {
+            base_url: var("LOC_API_BASE_URL"),
+            timeout: var("LOC_API_TIMEOUT")
+                .and_then(|value| value.parse::<f64>().ok())
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok()),
+            user_agent: var("LOC_API_USER_AGENT"),
+            rate_limit: var("LOC_API_RATE_LIMIT").and_then(|value| value.parse().ok()),
+            cache_dir: var("LOC_API_CACHE_DIR").map(PathBuf::from),
+            cache_ttl: var("LOC_API_CACHE_TTL").and_then(|value| value.parse().ok()).map(Duration::from_secs),
+        }
+    }
+}
+
+/// Builder for [`ApiClient`], created with [`ApiClient::builder`].
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::loc_client::ApiClient;
+///
+/// let client = ApiClient::builder()
+///     .base_url("https://www.loc.gov/")
+///     .on_request(|url| println!("GET {}", url))
+///     .on_response(|meta| println!("{} {} ({} bytes)", meta.status, meta.final_url, meta.body_size))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct ApiClientBuilder {
+    base_url: Option<String>,
+    on_request: Option<RequestHook>,
+    on_response: Option<ResponseHook>,
+    metrics: Option<Arc<dyn Metrics>>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    default_attributes: Option<AttributesSelect>,
+    default_item_attributes: Option<ItemAttributes>,
+    default_per_page: Option<u32>,
+    default_page: Option<u32>,
+    default_sort: Option<SortOrder>,
+    throttle: Option<ThrottleConfig>,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxy: ProxyConfig,
+    pool: PoolConfig,
+    resolve: Vec<(String, IpAddr)>,
+    cache_dir: Option<PathBuf>,
+    cache_ttl: Option<Duration>,
+    transport: Option<Box<dyn HttpTransport>>,
+    default_headers: Vec<(String, String)>,
+    download_timeout: Option<Duration>,
+    retries: u32,
+    retry_backoff: Option<Duration>,
+    #[cfg(feature = "record")]
+    record: Option<PathBuf>,
+    #[cfg(feature = "record")]
+    replay: Option<PathBuf>,
+}
+
+impl ApiClientBuilder {
+    /// Creates a new [`ApiClientBuilder`] with default settings.
     pub fn new() -> Self {
-        let base_url = env::var("LOC_API_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
-        let client = Client::new();
-        ApiClient { base_url, client }
+        Self::default()
     }
 
-    /// Performs a search query using the `/search/` endpoint.
-    ///
-    /// # Parameters
-    ///
-    /// - `query`: The search query string.
-    /// - `include_collections`: Whether to include collections in the search results.
-    /// - `attributes`: Attributes to include or exclude in the response.
-    /// - `filters`: Facet filters to apply.
-    /// - `per_page`: Number of results per page.
-    /// - `page`: Page number to retrieve.
-    /// - `sort`: Sorting field.
-    ///
-    /// # Returns
+    /// Sets the base URL, taking precedence over the `LOC_API_BASE_URL` environment variable.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets a callback invoked with the URL of every request before it is sent.
+    pub fn on_request<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_request = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a callback invoked with the [`ResponseMeta`] (URL, status, body size, ...) of every
+    /// response that was received, including error statuses.
+    pub fn on_response<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&ResponseMeta) + Send + Sync + 'static,
+    {
+        self.on_response = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets the [`Metrics`] implementation notified of request events. Defaults to [`NoopMetrics`].
+    pub fn metrics(mut self, metrics: impl Metrics + 'static) -> Self {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    /// Appends a [`Middleware`] to the chain. Middlewares run in the order they are added.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the [`AttributesSelect`] used by the search, format and collection methods when
+    /// they are called with `attributes: None`.
     ///
-    /// Returns a [`SearchResultResponse`] on success.
+    /// Passing `Some(..)` to a call overrides the default; `Some(AttributesSelect::default())`
+    /// requests the API's full default payload.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use loc_api::loc_client::ApiClient;
-    /// use loc_api::param_models::{FacetReq, SearchParams, Facet};
-    /// use loc_api::attribute_models::{AttributesSelect, SortField};
-    /// use loc_api::format_models::Format;
+    /// use loc_api::attribute_models::AttributesSelect;
     ///
-    /// let client = ApiClient::new();
-    /// let response = client.search(
-    ///     "baseball",
-    ///     false,
-    ///     Some(AttributesSelect {
+    /// let client = ApiClient::builder()
+    ///     .default_attributes(AttributesSelect {
     ///         include: vec!["pagination".to_string(), "results".to_string()],
     ///         exclude: vec![],
-    ///     }),
-    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "sports".to_string() }] }),
-    ///     Some(25),
-    ///     Some(1),
-    ///     Some(SortField::DateDesc),
-    /// ).unwrap();
+    ///     })
+    ///     .build();
     /// ```
-    pub fn search(
-        &self,
-        query: &str,
-        include_collections: bool,
-        attributes: Option<AttributesSelect>,
-        filters: Option<FacetReq>,
-        per_page: Option<u32>,
-        page: Option<u32>,
-        sort: Option<SortField>,
-    ) -> Result<(SearchResultResponse, String), Box<dyn Error>> {
-        let common_params = CommonParams {
-            format: Format::default().into(),
-            attributes,
-            query: query.to_string().replace(" ", "+").into(),
-            filter: filters,
-            per_page,
-            page,
-            sort,
-        };
-
-        let search_params = SearchParams {
-            common: common_params,
-            include_collections,
-        };
+    pub fn default_attributes(mut self, attributes: AttributesSelect) -> Self {
+        self.default_attributes = Some(attributes);
+        self
+    }
 
-        let endpoint = Endpoints::Search(search_params);
-        let url = endpoint.to_url()?;
+    /// Sets the [`ItemAttributes`] used by [`ApiClient::get_item`] when it is called with
+    /// `attributes: None`.
+    pub fn default_item_attributes(mut self, attributes: ItemAttributes) -> Self {
+        self.default_item_attributes = Some(attributes);
+        self
+    }
 
-        // Replace the default base URL with the client's base_url
-        let final_url = self.replace_base_url(&url)?;
+    /// Sets the number of results per page used when a call passes `per_page: None`.
+    pub fn default_per_page(mut self, per_page: u32) -> Self {
+        self.default_per_page = Some(per_page);
+        self
+    }
 
-        let response = self.client.get(&final_url).send()?.error_for_status()?;
-        let json = response.json::<SearchResultResponse>()?;
-        Ok((json, final_url))
+    /// Sets the page number used when a call passes `page: None`.
+    pub fn default_page(mut self, page: u32) -> Self {
+        self.default_page = Some(page);
+        self
     }
 
-    /// Retrieves detailed information about a specific item using the `/item/{item_id}/` endpoint.
+    /// Sets the sort order used when a call passes `sort: None`. Accepts a single [`SortField`]
+    /// or a multi-field [`SortOrder`].
     ///
-    /// # Parameters
-    ///
-    /// - `item_id`: The unique identifier of the item.
-    /// - `attributes`: Attributes to include in the response.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::attribute_models::SortField;
     ///
-    /// Returns an [`ItemResponse`] on success.
+    /// let client = ApiClient::builder()
+    ///     .default_per_page(100)
+    ///     .default_sort(SortField::DateDesc)
+    ///     .build();
+    /// ```
+    pub fn default_sort(mut self, sort: impl Into<SortOrder>) -> Self {
+        self.default_sort = Some(sort.into());
+        self
+    }
+
+    /// Rate-limits outgoing requests according to `config`; requests that would exceed it wait
+    /// until they are allowed. Requests are not throttled unless this is set.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use loc_api::loc_client::ApiClient;
-    /// use loc_api::param_models::ItemParams;
-    /// use loc_api::attribute_models::ItemAttributes;
-    /// use loc_api::format_models::Format;
+    /// use loc_api::throttle::ThrottleConfig;
     ///
-    /// let client = ApiClient::new();
-    /// let response = client.get_item(
-    ///     "2014717546",
-    ///     Some(ItemAttributes {
-    ///         cite_this: Some(true),
-    ///         item: Some(true),
-    ///         resources: Some(true),
-    ///     }),
-    /// ).unwrap();
+    /// let client = ApiClient::builder()
+    ///     .throttle(ThrottleConfig::default())
+    ///     .build();
     /// ```
-    pub fn get_item(
-        &self,
-        item_id: &str,
-        attributes: Option<ItemAttributes>,
-    ) -> Result<(ItemResponse, String), Box<dyn Error>> {
-        let item_params = ItemParams {
-            format: Some(Format::default()),
-            attributes,
-        };
+    pub fn throttle(mut self, config: ThrottleConfig) -> Self {
+        self.throttle = Some(config);
+        self
+    }
 
-        let endpoint = Endpoints::Item {
-            item_id: item_id.to_string(),
-            params: item_params,
-        };
-        let url = endpoint.to_url()?;
+    /// Adds a header sent with every request, including file downloads, e.g. a `From:` contact
+    /// address or the credentials of a caching proxy. Setting the same header twice
+    /// (case-insensitive) keeps the last value; middlewares can still override it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::builder()
+    ///     .default_header("From", "archivist@example.edu")
+    ///     .default_header("X-Institution", "example-university")
+    ///     .build();
+    /// ```
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        self.default_headers.retain(|(key, _)| !key.eq_ignore_ascii_case(&name));
+        self.default_headers.push((name, value.into()));
+        self
+    }
 
-        // Replace the default base URL with the client's base_url
-        let final_url = self.replace_base_url(&url)?;
+    /// Sets the [`HttpTransport`] that sends the requests, e.g. a
+    /// [`UreqTransport`](crate::transport::UreqTransport) when both backends are compiled in.
+    ///
+    /// The [`ApiClientBuilder::timeout`], [`ApiClientBuilder::user_agent`], proxy, pool and
+    /// [`ApiClientBuilder::resolve`] settings only apply to the default transport.
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
 
-        let response = self.client.get(&final_url).send()?.error_for_status()?;
-        let json = response.json::<ItemResponse>()?;
-        Ok((json, final_url))
+    /// Sets the timeout of a single HTTP request. Defaults to 30 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
-    /// Retrieves items of a specific format using the `/{format}/` endpoint.
+    /// Sets the timeout of file downloads ([`ApiClient::download_all_pages`] and the
+    /// [`BulkDownloader`](crate::download::BulkDownloader)), overriding
+    /// [`ApiClientBuilder::timeout`] so that metadata requests can fail fast while large files
+    /// get more time.
     ///
-    /// # Parameters
-    ///
-    /// - `format_type`: The specific format type (e.g., `MediaType::Maps`).
-    /// - `query`: The search query string.
-    /// - `attributes`: Attributes to include or exclude in the response.
-    /// - `filters`: Facet filters to apply.
-    /// - `per_page`: Number of results per page.
-    /// - `page`: Page number to retrieve.
-    /// - `sort`: Sorting field.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use std::time::Duration;
     ///
-    /// Returns a [`FormatResponse`] on success.
+    /// let client = ApiClient::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .download_timeout(Duration::from_secs(600))
+    ///     .build();
+    /// ```
+    pub fn download_timeout(mut self, timeout: Duration) -> Self {
+        self.download_timeout = Some(timeout);
+        self
+    }
+
+    /// Retries requests that fail transiently (transport errors, HTTP 429 and 5xx) up to
+    /// `max_retries` times, for [`ApiClient::fetch`] and every typed method built on it.
+    /// Defaults to 0: failures are returned right away.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use loc_api::loc_client::ApiClient;
-    /// use loc_api::param_models::{FacetReq, Facet};
-    /// use loc_api::attribute_models::{AttributesSelect, SortField};
-    /// use loc_api::format_models::{Format, MediaType};
+    /// use std::time::Duration;
     ///
-    /// let client = ApiClient::new();
-    /// let response = client.get_format(
-    ///     MediaType::Maps,
-    ///     Some("mountain"),
-    ///     Some(AttributesSelect {
-    ///         include: vec!["pagination".to_string(), "results".to_string()],
-    ///         exclude: vec![],
-    ///     }),
-    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
-    ///     Some(10),
-    ///     Some(1),
-    ///     Some(SortField::TitleS),
-    /// ).unwrap();
+    /// let client = ApiClient::builder()
+    ///     .retries(3)
+    ///     .retry_backoff(Duration::from_millis(500))
+    ///     .build();
     /// ```
-    pub fn get_format(
-        &self,
-        format_type: MediaType,
-        query: Option<&str>,
-        attributes: Option<AttributesSelect>,
-        filters: Option<FacetReq>,
-        per_page: Option<u32>,
-        page: Option<u32>,
-        sort: Option<SortField>,
-    ) -> Result<(FormatResponse, String), Box<dyn Error>> {
-        let query = if let Some(q) = query { Some(q.replace(" ", "+")) } else { None };
-        let common_params = CommonParams {
-            format: Some(Format::default()),
-            attributes,
-            query,
-            filter: filters,
-            per_page,
-            page,
-            sort,
-        };
-
-        let endpoint = Endpoints::Format {
-            format: format_type,
-            params: common_params,
-        };
-        let url = endpoint.to_url()?;
+    pub fn retries(mut self, max_retries: u32) -> Self {
+        self.retries = max_retries;
+        self
+    }
 
-        // Replace the default base URL with the client's base_url
-        let final_url = self.replace_base_url(&url)?;
+    /// Sets the wait before the first retry; it doubles with every further retry. Defaults to
+    /// 1 second.
+    pub fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = Some(backoff);
+        self
+    }
 
-        let response = self.client.get(&final_url).send()?.error_for_status()?;
-        let json = response.json::<FormatResponse>()?;
-        Ok((json, final_url))
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
     }
 
-    /// Retrieves detailed information about a specific collection using `/collections/{name_of_collection}/`.
-    ///
-    /// # Parameters
-    ///
-    /// - `collection_name`: The name of the collection in kebab-case, auto-conversion will
-    /// replace spaces and '_' with hyphens.
-    /// - `query`: The search query string.
-    /// - `attributes`: Attributes to include or exclude in the response.
-    /// - `filters`: Facet filters to apply.
-    /// - `per_page`: Number of results per page.
-    /// - `page`: Page number to retrieve.
-    /// - `sort`: Sorting field.
-    ///
-    /// # Returns
-    ///
-    /// Returns a [`CollectionResponse`] on success.
+    /// Sends every request through the proxy at `url`, unless a scheme-specific proxy applies
+    /// or the host is listed in [`ApiClientBuilder::no_proxy`]. See [`ProxyConfig`] for the
+    /// supported URLs.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use loc_api::loc_client::ApiClient;
-    /// use loc_api::param_models::{FacetReq, Facet};
-    /// use loc_api::attribute_models::{AttributesSelect, SortField};
-    /// use loc_api::format_models::Format;
-    /// use loc_api::response_models::CollectionResponse;
     ///
-    /// let client = ApiClient::new();
-    /// let response = match client.get_collection(
-    ///     "maps",
-    ///     Some("mountain"),
-    ///     Some(AttributesSelect {
-    ///         include: vec!["pagination".to_string(), "results".to_string()],
-    ///         exclude: vec![],
-    ///     }),
-    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
-    ///     Some(10),
-    ///     Some(1),
-    ///     Some(SortField::TitleS),
-    /// ) {
-    ///     Ok(response) => {
-    ///         println!("URL: {}", response.1);
-    ///         response.0
-    ///     },
-    ///     Err(e) => {
-    ///         eprintln!("Error: {}", e);   
-    ///         CollectionResponse::default()
-    ///     }
-    /// };
+    /// let client = ApiClient::builder()
+    ///     .proxy("http://proxy.example.edu:3128")
+    ///     .no_proxy(["localhost", ".example.edu"])
+    ///     .try_build()
+    ///     .unwrap();
     /// ```
-    pub fn get_collection(
-        &self,
-        collection_name: &str,
-        query: Option<&str>,
-        attributes: Option<AttributesSelect>,
-        filters: Option<FacetReq>,
-        per_page: Option<u32>,
-        page: Option<u32>,
-        sort: Option<SortField>,
-    ) -> Result<(CollectionResponse, String), Box<dyn Error>> {
-        let query = if let Some(q) = query { Some(q.replace(" ", "+")) } else { None };
-
-        let common_params = CommonParams {
-            format: Some(Format::default()),
-            attributes,
-            query,
-            filter: filters,
-            per_page,
-            page,
-            sort,
-        };
-
-        let endpoint = Endpoints::Collection {
-            name: collection_name.to_string().replace(" ", "-").replace("_", "-"),
-            params: common_params,
-        };
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy.all = Some(url.into());
+        self
+    }
 
-        let url = endpoint.to_url()?;
+    /// Sends `http://` requests through the proxy at `url`.
+    pub fn http_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy.http = Some(url.into());
+        self
+    }
 
-        // Replace the default base URL with the client's base_url
-        let final_url = self.replace_base_url(&url)?;
+    /// Sends `https://` requests through the proxy at `url`.
+    pub fn https_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy.https = Some(url.into());
+        self
+    }
 
-        let response = self.client.get(&final_url).send()?.error_for_status()?;
-        let json = response.json::<CollectionResponse>()?;
-        Ok((json, final_url))
+    /// Reaches `hosts` (and their subdomains) directly, bypassing the configured proxies.
+    pub fn no_proxy<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.proxy.no_proxy.extend(hosts.into_iter().map(Into::into));
+        self
     }
 
-    /// Retrieves all collections using the `/collections/` endpoint.
+    /// Speaks HTTP/2 from the start, so concurrent requests (e.g. from the
+    /// [`BulkDownloader`](crate::download::BulkDownloader)) are multiplexed over a single
+    /// connection per host instead of opening one connection each.
     ///
-    /// # Parameters
+    /// Only the reqwest transport supports HTTP/2; with the `ureq` transport
+    /// [`ApiClientBuilder::try_build`] fails.
     ///
-    /// - `query`: The search query string.
-    /// - `attributes`: Attributes to include or exclude in the response.
-    /// - `filters`: Facet filters to apply.
-    /// - `per_page`: Number of results per page.
-    /// - `page`: Page number to retrieve.
-    /// - `sort`: Sorting field.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
     ///
-    /// Returns a [`CollectionsResponse`] on success.
+    /// # #[cfg(feature = "blocking")] {
+    /// let client = ApiClient::builder()
+    ///     .http2_prior_knowledge()
+    ///     .pool_max_idle_per_host(2)
+    ///     .try_build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.pool.http2_prior_knowledge = true;
+        self
+    }
+
+    /// Sets how many idle connections per host are kept open for reuse.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool.max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how many idle connections are kept open in total. Only the `ureq` transport
+    /// supports an overall limit; the reqwest transport ignores it.
+    pub fn pool_max_idle(mut self, max: usize) -> Self {
+        self.pool.max_idle = Some(max);
+        self
+    }
+
+    /// Closes connections that stayed idle for `timeout`. A timeout below the server's own idle
+    /// timeout avoids reusing connections the server already dropped, which otherwise show up as
+    /// sporadic connection-reset errors in long-running harvests. Ignored by the `ureq` transport.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use loc_api::loc_client::ApiClient;
-    /// use loc_api::param_models::{FacetReq, Facet};
+    /// use std::time::Duration;
+    ///
+    /// let client = ApiClient::builder()
+    ///     .pool_idle_timeout(Duration::from_secs(30))
+    ///     .tcp_keepalive(Duration::from_secs(60))
+    ///     .build();
+    /// ```
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends TCP keep-alive probes every `interval` on open connections. Ignored by the `ureq`
+    /// transport.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.pool.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Connects to `ip` for requests to `host` instead of resolving the host name, keeping the
+    /// port of the request URL. Useful for testing against a mirror or in split-horizon DNS
+    /// environments without editing `/etc/hosts`. Calling it again for the same host adds
+    /// another address.
+    ///
+    /// Requests keep the original host name in the `Host` header and for TLS, so HTTPS mirrors
+    /// must serve a certificate for it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let client = ApiClient::builder()
+    ///     .resolve("www.loc.gov", Ipv4Addr::new(10, 0, 0, 12).into())
+    ///     .build();
+    /// ```
+    pub fn resolve(mut self, host: impl Into<String>, ip: IpAddr) -> Self {
+        self.resolve.push((host.into(), ip));
+        self
+    }
+
+    /// Caches successful JSON API responses (URLs with `fo=json`) as files in `dir`, keyed by
+    /// URL. Images and other downloads are never cached.
+    ///
+    /// The status and headers are stored next to the body, so a cached response replays as it
+    /// was received. Cached responses are served without a network request and reported through
+    /// [`Metrics::cache_hit`] until they are older than [`ApiClientBuilder::cache_ttl`].
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets how long cached responses are served before going back to the network, one day by
+    /// default. Only used together with [`ApiClientBuilder::cache_dir`].
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Records every response received from the network to a cassette file at `path`, see
+    /// [`cassette::RecordingTransport`](crate::cassette::RecordingTransport).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// // Record once against loc.gov...
+    /// let client = ApiClient::builder().record("tests/cassettes/baseball.json").build();
+    /// client.search("baseball", false, None, None, Some(5), None, None).unwrap();
+    ///
+    /// // ...then replay without network access.
+    /// let client = ApiClient::builder().replay("tests/cassettes/baseball.json").build();
+    /// client.search("baseball", false, None, None, Some(5), None, None).unwrap();
+    /// ```
+    #[cfg(feature = "record")]
+    pub fn record(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record = Some(path.into());
+        self
+    }
+
+    /// Answers every request from the cassette file at `path` instead of the network, see
+    /// [`cassette::ReplayTransport`](crate::cassette::ReplayTransport). Takes precedence over
+    /// [`ApiClientBuilder::record`] and [`ApiClientBuilder::transport`].
+    ///
+    /// [`ApiClientBuilder::try_build`] fails if the cassette cannot be read.
+    #[cfg(feature = "record")]
+    pub fn replay(mut self, path: impl Into<PathBuf>) -> Self {
+        self.replay = Some(path.into());
+        self
+    }
+
+    /// Applies every setting present in `config`, overriding earlier calls.
+    pub fn config(mut self, config: ClientConfig) -> Self {
+        if let Some(base_url) = config.base_url {
+            self.base_url = Some(base_url);
+        }
+        if let Some(timeout) = config.timeout {
+            self.timeout = Some(timeout);
+        }
+        if let Some(user_agent) = config.user_agent {
+            self.user_agent = Some(user_agent);
+        }
+        if let Some(rate_limit) = config.rate_limit {
+            self.throttle = Some(ThrottleConfig::uniform(rate_limit));
+        }
+        if let Some(cache_dir) = config.cache_dir {
+            self.cache_dir = Some(cache_dir);
+        }
+        if let Some(cache_ttl) = config.cache_ttl {
+            self.cache_ttl = Some(cache_ttl);
+        }
+        self
+    }
+
+    /// Builds the [`ApiClient`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be built, e.g. because a proxy URL is invalid. Use
+    /// [`ApiClientBuilder::try_build`] to handle this case.
+    pub fn build(self) -> ApiClient {
+        self.try_build().expect("failed to build the HTTP client")
+    }
+
+    /// Builds the [`ApiClient`], returning an error if the HTTP client cannot be built.
+    pub fn try_build(self) -> Result<ApiClient, Box<dyn Error>> {
+        let base_url = self
+            .base_url
+            .unwrap_or_else(|| env::var("LOC_API_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()));
+
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => transport::default_transport(&TransportOptions {
+                timeout: self.timeout,
+                user_agent: self.user_agent,
+                proxy: self.proxy,
+                pool: self.pool,
+                resolve: self.resolve,
+            })
+            .map_err(|e| e as Box<dyn Error>)?,
+        };
+        #[cfg(feature = "record")]
+        let transport: Box<dyn HttpTransport> = match (self.replay, self.record) {
+            (Some(path), _) => Box::new(ReplayTransport::load(path)?),
+            (None, Some(path)) => Box::new(RecordingTransport::boxed(transport, path)),
+            (None, None) => transport,
+        };
+
+        Ok(ApiClient {
+            inner: Arc::new(ClientInner {
+                base_url,
+                transport,
+                last_meta: Mutex::new(None),
+                on_request: self.on_request,
+                on_response: self.on_response,
+                metrics: self.metrics.unwrap_or_else(|| Arc::new(NoopMetrics)),
+                middlewares: self.middlewares,
+                default_attributes: self.default_attributes,
+                default_item_attributes: self.default_item_attributes,
+                default_per_page: self.default_per_page,
+                default_page: self.default_page,
+                default_sort: self.default_sort,
+                throttle: self.throttle.map(Throttle::new),
+                cache_dir: self.cache_dir,
+                cache_ttl: self.cache_ttl.unwrap_or(DEFAULT_CACHE_TTL),
+                default_headers: self.default_headers,
+                download_timeout: self.download_timeout,
+                retries: self.retries,
+                retry_backoff: self.retry_backoff.unwrap_or(Duration::from_secs(1)),
+            }),
+        })
+    }
+}
+
+/// Transport-level details of a single API response.
+///
+/// Available for every request through [`ApiClient::last_response_meta`], or together with the
+/// deserialized body through [`ApiClient::fetch_with_meta`].
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// HTTP status code of the response.
+    pub status: u16,
+    /// Response headers as `(name, value)` pairs, names in lowercase.
+    pub headers: Vec<(String, String)>,
+    /// Time between sending the request and receiving the full body.
+    pub elapsed: Duration,
+    /// The URL that was requested, after applying the client's base URL.
+    pub final_url: String,
+    /// The URL the response was served from, after following any redirects.
+    pub resolved_url: String,
+    /// The URLs that answered with a redirect, in the order they were visited, starting with
+    /// [`ResponseMeta::final_url`]. Empty if the response was not redirected.
+    pub redirects: Vec<String>,
+    /// Size of the response body in bytes, after decompression. May be `0` for
+    /// [`ApiClient::stream_results`], whose body is read after the metadata is reported.
+    pub body_size: usize,
+    /// Size of the response body in bytes as handed over by the transport, before the client
+    /// decompressed it (see the `gzip` and `brotli` features). Equals [`ResponseMeta::body_size`] for
+    /// uncompressed responses.
+    pub wire_size: usize,
+    /// Number of times the request was retried before this response was received.
+    pub retries: u32,
+    /// Requested attribute names the API does not document, usually typos, as returned by
+    /// [`Endpoints::validate`]. The request is sent regardless.
+    pub warnings: Vec<String>,
+}
+
+impl ResponseMeta {
+    /// Returns the first value of the header `name` (case-insensitive), if present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ResponseMeta;
+    /// use std::time::Duration;
+    ///
+    /// let meta = ResponseMeta {
+    ///     status: 200,
+    ///     headers: vec![("x-cache".to_string(), "HIT".to_string())],
+    ///     elapsed: Duration::from_millis(120),
+    ///     final_url: "https://www.loc.gov/search/?fo=json&q=baseball".to_string(),
+    ///     resolved_url: "https://www.loc.gov/search/?fo=json&q=baseball".to_string(),
+    ///     redirects: vec![],
+    ///     body_size: 2048,
+    ///     wire_size: 512,
+    ///     retries: 0,
+    ///     warnings: vec![],
+    /// };
+    /// assert_eq!(meta.header("X-Cache"), Some("HIT"));
+    /// ```
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Error returned when the API answers with a non-success HTTP status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpStatusError {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The URL that was requested.
+    pub url: String,
+}
+
+impl std::fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP status {} for {}", self.status, self.url)
+    }
+}
+
+impl Error for HttpStatusError {}
+
+impl Default for ApiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApiClient {
+    /// Creates a new [`ApiClient`] instance configured from the environment with
+    /// [`ClientConfig::from_env`].
+    ///
+    /// The following environment variables are read:
+    ///
+    /// - `LOC_API_BASE_URL`: overrides [`DEFAULT_BASE_URL`].
+    /// - `LOC_API_TIMEOUT`: request timeout in seconds.
+    /// - `LOC_API_USER_AGENT`: the `User-Agent` header.
+    /// - `LOC_API_RATE_LIMIT`: maximum requests per minute.
+    /// - `LOC_API_CACHE_DIR`: directory in which responses are cached.
+    /// - `LOC_API_CACHE_TTL`: seconds a cached response stays fresh.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// ```
+    pub fn new() -> Self {
+        ApiClientBuilder::new().config(ClientConfig::from_env()).build()
+    }
+
+    /// Creates a client answering the documented example requests (the `"baseball"` search,
+    /// item `"2014717546"`, ...) with responses bundled in [`fixtures`](crate::fixtures),
+    /// without network access. See [`OfflineTransport`] for the requests served.
+    ///
+    /// Useful to try the API, and to run examples and doc tests offline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::offline();
+    /// let (item, _) = client.get_item("2014717546", None).unwrap();
+    /// assert!(item.item.is_some());
+    /// assert!(client.get_item("2007683735", None).is_err());
+    /// ```
+    pub fn offline() -> Self {
+        ApiClientBuilder::new().base_url(DEFAULT_BASE_URL).transport(OfflineTransport).build()
+    }
+
+    /// Returns an [`ApiClientBuilder`] for configuring a client, e.g. with logging hooks.
+    pub fn builder() -> ApiClientBuilder {
+        ApiClientBuilder::new()
+    }
+
+    /// Performs a search query using the `/search/` endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// - `query`: The search query string.
+    /// - `include_collections`: Whether to include collections in the search results.
+    /// - `attributes`: Attributes to include or exclude in the response.
+    /// - `filters`: Facet filters to apply.
+    /// - `per_page`: Number of results per page.
+    /// - `page`: Page number to retrieve.
+    /// - `sort`: Sort order, a single field (`SortField::TitleS.into()`) or several (`SortField::Date.then(SortField::TitleS)`).
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`SearchResultResponse`] on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::param_models::{FacetReq, SearchParams, Facet};
     /// use loc_api::attribute_models::{AttributesSelect, SortField};
     /// use loc_api::format_models::Format;
     ///
-    /// let client = ApiClient::new();
-    /// let response = client.get_collections(
-    ///     Some("mountain"),
+    /// // Answered from bundled fixtures; use `ApiClient::new()` to query loc.gov.
+    /// let client = ApiClient::offline();
+    /// let response = client.search(
+    ///     "baseball",
+    ///     false,
     ///     Some(AttributesSelect {
     ///         include: vec!["pagination".to_string(), "results".to_string()],
     ///         exclude: vec![],
     ///     }),
-    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
-    ///     Some(10),
+    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "sports".to_string() }] }),
+    ///     Some(25),
     ///     Some(1),
-    ///     Some(SortField::TitleS),
+    ///     Some(SortField::DateDesc.then(SortField::TitleS)),
     /// ).unwrap();
     /// ```
-    pub fn get_collections(
+    #[allow(clippy::too_many_arguments)]
+    pub fn search(
         &self,
-        query: Option<&str>,
+        query: &str,
+        include_collections: bool,
         attributes: Option<AttributesSelect>,
         filters: Option<FacetReq>,
         per_page: Option<u32>,
         page: Option<u32>,
-        sort: Option<SortField>,
-    ) -> Result<(CollectionsResponse, String), Box<dyn Error>> {
-        let query = if let Some(q) = query { Some(q.replace(" ", "+")) } else { None };
-        let common_params = CommonParams {
-            format: Some(Format::default()),
-            attributes,
-            query,
-            filter: filters,
-            per_page,
-            page,
-            sort,
-        };
+        sort: Option<SortOrder>,
+    ) -> Result<(SearchResultResponse, String), Box<dyn Error>> {
+        let endpoint = self.search_endpoint(query, include_collections, attributes, filters, per_page, page, sort);
+        self.fetch(&endpoint)
+    }
 
-        let endpoint = Endpoints::Collections(common_params);
-        let url = endpoint.to_url()?;
+    /// Same as [`ApiClient::search`], but also returns the untouched JSON body.
+    ///
+    /// Useful for reading fields the response models don't cover without issuing a second request.
+    ///
+    /// # Returns
+    ///
+    /// Returns the typed [`SearchResultResponse`], the raw JSON [`Value`] and the final URL on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (response, raw, url) = client.search_with_raw(
+    ///     "baseball", false, None, None, Some(25), Some(1), None,
+    /// ).unwrap();
+    /// println!("{} results from {}, raw keys: {:?}",
+    ///     response.results.map_or(0, |r| r.len()), url, raw.as_object().map(|o| o.keys().count()));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn search_with_raw(
+        &self,
+        query: &str,
+        include_collections: bool,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(SearchResultResponse, Value, String), Box<dyn Error>> {
+        let endpoint = self.search_endpoint(query, include_collections, attributes, filters, per_page, page, sort);
+        self.request_with_raw(&endpoint)
+    }
 
-        // Replace the default base URL with the client's base_url
-        let final_url = self.replace_base_url(&url)?;
+    /// Streams the pages of a search, starting at the first page and following each page's
+    /// `pagination.next` link. Pages are requested lazily as the stream is consumed.
+    ///
+    /// # Parameters
+    ///
+    /// Same as [`ApiClient::search`], without `page`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`SearchStream`] yielding one [`SearchResultResponse`] per page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let total: usize = client
+    ///     .search_stream("baseball", false, None, None, Some(100), None)
+    ///     .max_pages(3)
+    ///     .map(|page| page.unwrap().results.map_or(0, |r| r.len()))
+    ///     .sum();
+    /// println!("{} results", total);
+    /// ```
+    pub fn search_stream(
+        &self,
+        query: &str,
+        include_collections: bool,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> SearchStream<'_> {
+        let endpoint = self.search_endpoint(query, include_collections, attributes, filters, per_page, None, sort);
+        SearchStream::new(self, endpoint)
+    }
 
-        let response = self.client.get(&final_url).send()?.error_for_status()?;
-        let json = response.json::<CollectionsResponse>()?;
-        Ok((json, final_url))
+    /// Requests a results page and yields its [`ResultItem`]s while the body is still being
+    /// downloaded, instead of buffering and parsing the whole page first.
+    ///
+    /// Useful for pages with a large `per_page`, where it cuts peak memory and the time to the
+    /// first result. The request goes through the same pipeline as any other: request
+    /// middleware, the response cache, the throttle, hooks, metrics, redirects and
+    /// [`retries`](ApiClientBuilder::retries) of failures that happen before the body is read.
+    ///
+    /// Since the body is handed over unread, the streaming path skips:
+    ///
+    /// - [`Middleware::handle_response`], which needs the whole body;
+    /// - storing the response in the cache, although fresh cached responses are served;
+    /// - the exact sizes in [`ResponseMeta`]: [`ResponseMeta::wire_size`] comes from the
+    ///   `Content-Length` header and [`ResponseMeta::body_size`] is `0` when that header is
+    ///   missing or the body is compressed.
+    ///
+    /// # Parameters
+    ///
+    /// - `endpoint`: A search, format, collection or collections endpoint.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`ResultStream`] once the response headers arrived, or an error if the request
+    /// failed or the status is not `2xx`.
+    ///
+    /// See [`ResultStream`] for an example.
+    pub fn stream_results(&self, endpoint: &Endpoints) -> Result<ResultStream, Box<dyn Error>> {
+        let warnings = endpoint.validate()?;
+        let url = self.url_for(endpoint);
+        self.with_retry(&url, self.inner.retries, self.inner.retry_backoff, |retries| {
+            self.stream_url_attempt(url.clone(), retries, &warnings)
+        })
+        .map_err(|(e, _)| e)
     }
 
-    /// Helper method to replace the default base URL in the endpoint URL with the client's base_url.
+    /// Retrieves only the facets of a search, such as subject and location counts for a filter
+    /// sidebar.
     ///
-    /// This is necessary because the [`Endpoints::to_url()`] method includes a hardcoded base URL.
+    /// Requests `at=facets&c=0`, so no results are transferred. The client's default attributes,
+    /// paging and sort are not applied.
     ///
     /// # Parameters
     ///
-    /// - `url`: The URL generated by the `to_url` method.
+    /// - `query`: The search query string.
+    /// - `filters`: Facet filters already applied.
     ///
     /// # Returns
     ///
-    /// Returns the modified URL with the client's base URL.
-    fn replace_base_url(&self, url: &str) -> Result<String, Box<dyn Error>> {
-        let default_base = "https://www.loc.gov";
-        if url.starts_with(default_base) {
-            let suffix = &url[default_base.len()..];
-            Ok(format!("{}{}", self.base_url, suffix))
-        } else {
-            Err(format!("URL does not start with the expected base URL: {}", default_base).into())
-        }
+    /// Returns the facets of the search and the final URL on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (facets, _url) = client.get_facets("baseball", None).unwrap();
+    /// for facet in facets {
+    ///     println!("{:?}: {} filters", facet.name(), facet.filters.len());
+    /// }
+    /// ```
+    pub fn get_facets(&self, query: &str, filters: Option<FacetReq>) -> Result<(Vec<FacetRes>, String), Box<dyn Error>> {
+        let endpoint = Endpoints::Search(SearchParams {
+            common: CommonParams {
+                format: Some(Format::default()),
+                attributes: Some(AttributesSelect {
+                    include: vec!["facets".to_string()],
+                    exclude: vec![],
+                }),
+                query: Some(query.replace(" ", "+")),
+                filter: filters,
+                per_page: Some(0),
+                page: None,
+                sort: None,
+            },
+            include_collections: false,
+        });
+        let (response, url) = self.fetch::<SearchResultResponse>(&endpoint)?;
+        Ok((response.facets.unwrap_or_default(), url))
+    }
+
+    /// Narrows a search to the results matching a facet filter by following its `on` URL.
+    ///
+    /// The URL is parsed with [`Endpoints::parse`], so it is sent to the client's base URL and
+    /// always requests JSON.
+    ///
+    /// # Parameters
+    ///
+    /// - `filter`: A filter of one of the response's [`FacetRes`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the filtered [`SearchResultResponse`] and the final URL on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    /// let sports = &response.facet("subject").unwrap().filters[0];
+    /// let (narrowed, url) = client.apply_filter(sports).unwrap();
+    /// let (_widened, _) = client.remove_filter(&narrowed.facet("subject").unwrap().filters[0]).unwrap();
+    /// println!("narrowed to {}", url);
+    /// ```
+    pub fn apply_filter(&self, filter: &FilterItem) -> Result<(SearchResultResponse, String), Box<dyn Error>> {
+        self.follow_filter(&filter.on, "on")
+    }
+
+    /// Removes a facet filter from a search by following its `off` URL.
+    ///
+    /// See [`ApiClient::apply_filter`].
+    pub fn remove_filter(&self, filter: &FilterItem) -> Result<(SearchResultResponse, String), Box<dyn Error>> {
+        self.follow_filter(&filter.off, "off")
+    }
+
+    /// Excludes the results matching a facet filter by following its `not` URL.
+    ///
+    /// See [`ApiClient::apply_filter`].
+    pub fn exclude_filter(&self, filter: &FilterItem) -> Result<(SearchResultResponse, String), Box<dyn Error>> {
+        self.follow_filter(&filter.not, "not")
+    }
+
+    /// Retrieves detailed information about a specific item using the `/item/{item_id}/` endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// - `item_id`: The unique identifier of the item. Item URLs (such as a search result's `id`),
+    ///   relative `/item/{id}/` paths and LCCN permalinks are accepted too, see [`ItemId::parse`]
+    ///   and [`ResultItem::item_id`].
+    /// - `attributes`: Attributes to include in the response.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`ItemResponse`] on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::param_models::ItemParams;
+    /// use loc_api::attribute_models::ItemAttributes;
+    /// use loc_api::format_models::Format;
+    ///
+    /// // Answered from bundled fixtures; use `ApiClient::new()` to query loc.gov.
+    /// let client = ApiClient::offline();
+    /// let response = client.get_item(
+    ///     "2014717546",
+    ///     Some(ItemAttributes {
+    ///         cite_this: Some(true),
+    ///         item: Some(true),
+    ///         resources: Some(true),
+    ///         ..Default::default()
+    ///     }),
+    /// ).unwrap();
+    /// ```
+    pub fn get_item(
+        &self,
+        item_id: impl Into<ItemId>,
+        attributes: Option<ItemAttributes>,
+    ) -> Result<(ItemResponse, String), Box<dyn Error>> {
+        let endpoint = self.item_endpoint(item_id.into(), attributes)?;
+        self.fetch(&endpoint)
+    }
+
+    /// Fetches many items by ID on `concurrency` threads, retrying transient failures (transport
+    /// errors, HTTP 429 and 5xx) up to 3 times with exponential backoff.
+    ///
+    /// Requests respect the client's [`throttle`](ApiClientBuilder::throttle), so a rate limit
+    /// set there applies to the batch as a whole. A failed ID does not stop the others.
+    ///
+    /// # Parameters
+    ///
+    /// - `ids`: The item IDs, e.g. read from a CSV file or a previous harvest.
+    /// - `concurrency`: The number of requests in flight.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`BulkItems`] report with one result per ID, in input order, and aggregate
+    /// [`BulkStats`](crate::pool::BulkStats).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::identifiers::ItemId;
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::offline();
+    /// let ids: Vec<ItemId> = ["2014717546", "missing"].iter().map(|id| ItemId::from(*id)).collect();
+    ///
+    /// let report = client.get_items_bulk(&ids, 2);
+    /// assert_eq!(report.stats.succeeded, 1);
+    /// assert_eq!(report.stats.not_found, 1);
+    /// assert_eq!(report.failures().next().unwrap().0.as_str(), "missing");
+    /// ```
+    pub fn get_items_bulk(&self, ids: &[ItemId], concurrency: usize) -> BulkItems {
+        ClientPool::new(self).workers(concurrency).retries(3).fetch_items(ids)
+    }
+
+    /// Same as [`ApiClient::get_item`], but also returns the untouched JSON body.
+    ///
+    /// # Returns
+    ///
+    /// Returns the typed [`ItemResponse`], the raw JSON [`Value`] and the final URL on success.
+    pub fn get_item_with_raw(
+        &self,
+        item_id: impl Into<ItemId>,
+        attributes: Option<ItemAttributes>,
+    ) -> Result<(ItemResponse, Value, String), Box<dyn Error>> {
+        let endpoint = self.item_endpoint(item_id.into(), attributes)?;
+        self.request_with_raw(&endpoint)
+    }
+
+    /// Retrieves information about a specific resource using the `/resource/{resource_id}/` endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// - `resource_id`: The unique identifier of the resource (e.g. `"g3701e.ct000003"`).
+    /// - `attributes`: Attributes to include in the response.
+    /// - `page`: The 1-based page of a multi-image resource, or segment of a segmented resource.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`ResourceResponse`] on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::attribute_models::ResourceAttributes;
+    ///
+    /// let client = ApiClient::new();
+    /// let (resource, url) = client.get_resource(
+    ///     "mal.0440500",
+    ///     Some(ResourceAttributes {
+    ///         page: Some(true),
+    ///         ..Default::default()
+    ///     }),
+    ///     Some(3),
+    /// ).unwrap();
+    /// ```
+    pub fn get_resource(
+        &self,
+        resource_id: &str,
+        attributes: Option<ResourceAttributes>,
+        page: Option<u32>,
+    ) -> Result<(ResourceResponse, String), Box<dyn Error>> {
+        let endpoint = self.resource_endpoint(resource_id, attributes, page)?;
+        self.fetch(&endpoint)
+    }
+
+    /// Same as [`ApiClient::get_resource`], but also returns the untouched JSON body.
+    ///
+    /// # Returns
+    ///
+    /// Returns the typed [`ResourceResponse`], the raw JSON [`Value`] and the final URL on success.
+    pub fn get_resource_with_raw(
+        &self,
+        resource_id: &str,
+        attributes: Option<ResourceAttributes>,
+        page: Option<u32>,
+    ) -> Result<(ResourceResponse, Value, String), Box<dyn Error>> {
+        let endpoint = self.resource_endpoint(resource_id, attributes, page)?;
+        self.request_with_raw(&endpoint)
+    }
+
+    /// Retrieves the word coordinates of a resource page from its `word_coordinates` URL, e.g. to
+    /// highlight search hits on a page image.
+    ///
+    /// # Parameters
+    ///
+    /// - `resource`: A resource page, such as an entry of [`ItemResourceResponse::resource`].
+    /// - `term`: Only keep words matching this term (case-insensitive). Pass `""` to keep every word.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`WordCoordinates`] and the final URL on success. Returns an error if the
+    /// resource has no `word_coordinates` URL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::response_models::ItemOrArray;
+    ///
+    /// let client = ApiClient::new();
+    /// let (resource, _) = client.get_resource("mal.0440500", None, Some(1)).unwrap();
+    /// if let Some(ItemOrArray::Item(detail)) = resource.resource {
+    ///     let (coordinates, _) = client.get_word_coordinates(&detail, "lincoln").unwrap();
+    ///     for word in coordinates.words {
+    ///         println!("{}: {:?}", word.text, word.boxes);
+    ///     }
+    /// }
+    /// ```
+    pub fn get_word_coordinates(&self, resource: &ResourceDetail, term: &str) -> Result<(WordCoordinates, String), Box<dyn Error>> {
+        let url = first_link(&resource.word_coordinates).ok_or("The resource has no word coordinates")?;
+
+        let (mut body, meta) = self.execute_url(url)?;
+        let coordinates: WordCoordinates = from_slice(&mut body)?;
+        let coordinates = if term.trim().is_empty() { coordinates } else { coordinates.matching(term) };
+        Ok((coordinates, meta.final_url))
+    }
+
+    /// Retrieves the IIIF `info.json` of an image file from its `info` URL, e.g. to build a tile
+    /// viewer or to pick a download size.
+    ///
+    /// # Parameters
+    ///
+    /// - `file`: An image file, such as one of [`ResourceObject::pages`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`ImageInfo`] and the final URL on success. Returns an error if the file has
+    /// no `info` URL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::response_models::ItemOrArray;
+    ///
+    /// let client = ApiClient::new();
+    /// let (item, _) = client.get_item("2014717546", None).unwrap();
+    /// if let Some(ItemOrArray::Array(resources)) = item.resources {
+    ///     for file in resources[0].all_files() {
+    ///         if file.info.is_some() {
+    ///             let (info, _) = client.get_iiif_info(file).unwrap();
+    ///             println!("{}x{}: {:?}", info.width, info.height, info.size_url(1024));
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn get_iiif_info(&self, file: &File) -> Result<(ImageInfo, String), Box<dyn Error>> {
+        let url = first_link(&file.info).ok_or("The file has no IIIF info URL")?;
+
+        let (mut body, meta) = self.execute_url(url)?;
+        let info: ImageInfo = from_slice(&mut body)?;
+        Ok((info, meta.final_url))
+    }
+
+    /// Retrieves the OCR text of a single page of a resource, such as a newspaper page.
+    ///
+    /// The page's plain-text rendition (`djvu_text_file`) is preferred; if only an ALTO XML
+    /// `fulltext_file` is available, the text is extracted from it.
+    ///
+    /// # Parameters
+    ///
+    /// - `resource_id`: The resource, either at item level (e.g. `"mal.0440500"`) or at segment
+    ///   level (e.g. `"sn83045462/1914-07-28/ed-1"`). Full `https://www.loc.gov/resource/.../`
+    ///   URLs are accepted too.
+    /// - `page`: The 1-based page number.
+    ///
+    /// # Returns
+    ///
+    /// Returns the OCR text and the URL of the text file on success. Returns an error if the page
+    /// has no full text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (text, url) = client.get_page_text("sn83045462/1914-07-28/ed-1", 1).unwrap();
+    /// println!("{}: {} characters", url, text.len());
+    /// ```
+    pub fn get_page_text(&self, resource_id: &str, page: u32) -> Result<(String, String), Box<dyn Error>> {
+        let (resource, _) = self.get_resource(resource_id, None, Some(page))?;
+        let detail = match resource.resource {
+            Some(ItemOrArray::Item(detail)) => Some(detail),
+            Some(ItemOrArray::Array(details)) => details.into_iter().next(),
+            None => None,
+        }
+        .ok_or_else(|| format!("No resource details for page {} of {}", page, resource_id))?;
+
+        if let Some(url) = first_link(&detail.djvu_text_file) {
+            let (body, meta) = self.execute_url(url)?;
+            return Ok((String::from_utf8(body)?, meta.final_url));
+        }
+
+        let url = first_link(&detail.fulltext_file)
+            .ok_or_else(|| format!("No full text for page {} of {}", page, resource_id))?;
+        let (body, meta) = self.execute_url(url)?;
+        let body = String::from_utf8(body)?;
+        let text = if body.trim_start().starts_with('<') { alto_text(&body) } else { body };
+        Ok((text, meta.final_url))
+    }
+
+    /// Downloads the image of every page of a multi-page item, such as a book or manuscript.
+    ///
+    /// Pages are numbered across all resources of the item and saved as `0001.jpg`,
+    /// `0002.jpg`, ..., zero-padded to at least four digits.
+    ///
+    /// # Parameters
+    ///
+    /// - `item_id`: The item to download, see [`ApiClient::get_item`].
+    /// - `dest`: The directory to save the images in. It is created if missing.
+    /// - `size`: The image size to download for each page.
+    ///
+    /// # Returns
+    ///
+    /// Returns a manifest of the saved pages, in page order, on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::response_models::ImageSize;
+    ///
+    /// let client = ApiClient::new();
+    /// let manifest = client.download_all_pages("2014717546", "pages", ImageSize::Large).unwrap();
+    /// for page in manifest {
+    ///     println!("{} -> {}", page.url, page.path.display());
+    /// }
+    /// ```
+    pub fn download_all_pages(
+        &self,
+        item_id: impl Into<ItemId>,
+        dest: impl AsRef<Path>,
+        size: ImageSize,
+    ) -> Result<Vec<SavedPage>, Box<dyn Error>> {
+        let (item, _) = self.get_item(item_id, None)?;
+        let resources: Vec<&ResourceObject> = item.resources.iter().flatten().collect();
+        let images: Vec<&File> = resources
+            .iter()
+            .flat_map(|resource| resource.pages())
+            .filter_map(|files| download::pick_image(&files, size))
+            .collect();
+
+        let dest = dest.as_ref();
+        fs::create_dir_all(dest)?;
+        let digits = images.len().to_string().len().max(4);
+
+        let mut manifest = Vec::with_capacity(images.len());
+        for (index, file) in images.into_iter().enumerate() {
+            let url = first_link(&file.url).ok_or("Page image without a URL")?;
+            let (body, meta) = self.execute_url_attempt(url, 0, self.inner.download_timeout, &[])?;
+            let path = dest.join(format!("{:0width$}.{}", index + 1, download::extension(file), width = digits));
+            download::write_atomic(&path, &body)?;
+            manifest.push(SavedPage { page: index + 1, url: meta.final_url, path, bytes: body.len() as u64 });
+        }
+
+        Ok(manifest)
+    }
+
+    /// Retrieves items of a specific format using the `/{format}/` endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// - `format_type`: The specific format type (e.g., `MediaType::Maps`).
+    /// - `query`: The search query string.
+    /// - `attributes`: Attributes to include or exclude in the response.
+    /// - `filters`: Facet filters to apply.
+    /// - `per_page`: Number of results per page.
+    /// - `page`: Page number to retrieve.
+    /// - `sort`: Sort order, a single field (`SortField::TitleS.into()`) or several (`SortField::Date.then(SortField::TitleS)`).
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`FormatResponse`] on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::param_models::{FacetReq, Facet};
+    /// use loc_api::attribute_models::{AttributesSelect, SortField};
+    /// use loc_api::format_models::{Format, MediaType};
+    ///
+    /// // Answered from bundled fixtures; use `ApiClient::new()` to query loc.gov.
+    /// let client = ApiClient::offline();
+    /// let response = client.get_format(
+    ///     MediaType::Maps,
+    ///     Some("baseball"),
+    ///     Some(AttributesSelect {
+    ///         include: vec!["pagination".to_string(), "results".to_string()],
+    ///         exclude: vec![],
+    ///     }),
+    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
+    ///     Some(10),
+    ///     Some(1),
+    ///     SortField::TitleS.into(),
+    /// ).unwrap();
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_format(
+        &self,
+        format_type: MediaType,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(FormatResponse, String), Box<dyn Error>> {
+        let endpoint = self.format_endpoint(format_type, query, attributes, filters, per_page, page, sort);
+        self.fetch(&endpoint)
+    }
+
+    /// Same as [`ApiClient::get_format`], but also returns the untouched JSON body.
+    ///
+    /// # Returns
+    ///
+    /// Returns the typed [`FormatResponse`], the raw JSON [`Value`] and the final URL on success.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_format_with_raw(
+        &self,
+        format_type: MediaType,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(FormatResponse, Value, String), Box<dyn Error>> {
+        let endpoint = self.format_endpoint(format_type, query, attributes, filters, per_page, page, sort);
+        self.request_with_raw(&endpoint)
+    }
+
+    /// Same as [`ApiClient::get_format`], but deserializes the results into a format-specific
+    /// type such as [`NewspaperResult`].
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`FormatResponse`] of `T` results and the final URL on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::format_models::MediaType;
+    /// use loc_api::response_models::NewspaperResult;
+    ///
+    /// let client = ApiClient::new();
+    /// let (response, _) = client
+    ///     .get_format_typed::<NewspaperResult>(MediaType::Newspapers, Some("baseball"), None, None, Some(10), None, None)
+    ///     .unwrap();
+    /// for result in response.results.unwrap_or_default() {
+    ///     println!("{:?} {:?}", result.partof_title, result.number_edition);
+    /// }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_format_typed<T: DeserializeOwned + 'static>(
+        &self,
+        format_type: MediaType,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(FormatResponse<T>, String), Box<dyn Error>> {
+        let endpoint = self.format_endpoint(format_type, query, attributes, filters, per_page, page, sort);
+        self.fetch(&endpoint)
+    }
+
+    /// Retrieves detailed information about a specific collection using `/collections/{name_of_collection}/`.
+    ///
+    /// # Parameters
+    ///
+    /// - `collection_name`: The name of the collection in kebab-case, auto-conversion will
+    /// replace spaces and '_' with hyphens.
+    /// - `query`: The search query string.
+    /// - `attributes`: Attributes to include or exclude in the response.
+    /// - `filters`: Facet filters to apply.
+    /// - `per_page`: Number of results per page.
+    /// - `page`: Page number to retrieve.
+    /// - `sort`: Sort order, a single field (`SortField::TitleS.into()`) or several (`SortField::Date.then(SortField::TitleS)`).
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`CollectionResponse`] on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::param_models::{FacetReq, Facet};
+    /// use loc_api::attribute_models::{AttributesSelect, SortField};
+    /// use loc_api::format_models::Format;
+    /// use loc_api::response_models::CollectionResponse;
+    ///
+    /// let client = ApiClient::new();
+    /// let response = match client.get_collection(
+    ///     "maps",
+    ///     Some("mountain"),
+    ///     Some(AttributesSelect {
+    ///         include: vec!["pagination".to_string(), "results".to_string()],
+    ///         exclude: vec![],
+    ///     }),
+    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
+    ///     Some(10),
+    ///     Some(1),
+    ///     SortField::TitleS.into(),
+    /// ) {
+    ///     Ok(response) => {
+    ///         println!("URL: {}", response.1);
+    ///         response.0
+    ///     },
+    ///     Err(e) => {
+    ///         eprintln!("Error: {}", e);   
+    ///         CollectionResponse::default()
+    ///     }
+    /// };
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_collection(
+        &self,
+        collection_name: &str,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(CollectionResponse, String), Box<dyn Error>> {
+        let endpoint = self.collection_endpoint(collection_name, query, attributes, filters, per_page, page, sort);
+        self.fetch(&endpoint)
+    }
+
+    /// Same as [`ApiClient::get_collection`], but also returns the untouched JSON body.
+    ///
+    /// # Returns
+    ///
+    /// Returns the typed [`CollectionResponse`], the raw JSON [`Value`] and the final URL on success.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_collection_with_raw(
+        &self,
+        collection_name: &str,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(CollectionResponse, Value, String), Box<dyn Error>> {
+        let endpoint = self.collection_endpoint(collection_name, query, attributes, filters, per_page, page, sort);
+        self.request_with_raw(&endpoint)
+    }
+
+    /// Retrieves all collections using the `/collections/` endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// - `query`: The search query string.
+    /// - `attributes`: Attributes to include or exclude in the response.
+    /// - `filters`: Facet filters to apply.
+    /// - `per_page`: Number of results per page.
+    /// - `page`: Page number to retrieve.
+    /// - `sort`: Sort order, a single field (`SortField::TitleS.into()`) or several (`SortField::Date.then(SortField::TitleS)`).
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`CollectionsResponse`] on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::param_models::{FacetReq, Facet};
+    /// use loc_api::attribute_models::{AttributesSelect, SortField};
+    /// use loc_api::format_models::Format;
+    ///
+    /// // Answered from bundled fixtures; use `ApiClient::new()` to query loc.gov.
+    /// let client = ApiClient::offline();
+    /// let response = client.get_collections(
+    ///     Some("baseball"),
+    ///     Some(AttributesSelect {
+    ///         include: vec!["pagination".to_string(), "results".to_string()],
+    ///         exclude: vec![],
+    ///     }),
+    ///     Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
+    ///     Some(10),
+    ///     Some(1),
+    ///     SortField::TitleS.into(),
+    /// ).unwrap();
+    /// ```
+    pub fn get_collections(
+        &self,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(CollectionsResponse, String), Box<dyn Error>> {
+        let endpoint = self.collections_endpoint(query, attributes, filters, per_page, page, sort);
+        self.fetch(&endpoint)
+    }
+
+    /// Same as [`ApiClient::get_collections`], but also returns the untouched JSON body.
+    ///
+    /// # Returns
+    ///
+    /// Returns the typed [`CollectionsResponse`], the raw JSON [`Value`] and the final URL on success.
+    pub fn get_collections_with_raw(
+        &self,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Result<(CollectionsResponse, Value, String), Box<dyn Error>> {
+        let endpoint = self.collections_endpoint(query, attributes, filters, per_page, page, sort);
+        self.request_with_raw(&endpoint)
+    }
+
+    /// Retrieves the MARCXML record of an item.
+    ///
+    /// The record is requested from the `marcxml` link listed in the item's resources, or from the
+    /// LCCN permalink service (`https://lccn.loc.gov/{item_id}/marcxml`) if the item lists none.
+    /// Only items whose identifier is an LCCN (e.g. `"2014717546"`) have catalog records;
+    /// other identifiers result in an HTTP error.
+    ///
+    /// # Parameters
+    ///
+    /// - `item_id`: The LCCN of the item, its `lccn.loc.gov` permalink or its loc.gov item URL.
+    ///
+    /// # Returns
+    ///
+    /// Returns the raw MARCXML document and its URL on success. The document is not parsed, so
+    /// the crate needs no XML parser; read it with the MARCXML tooling of your choice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (marcxml, url) = client.get_marcxml("https://www.loc.gov/item/2014717546/").unwrap();
+    /// println!("{}: {} bytes", url, marcxml.len());
+    /// ```
+    pub fn get_marcxml(&self, item_id: impl Into<ItemId>) -> Result<(String, String), Box<dyn Error>> {
+        self.get_record_rendition(item_id.into(), "marcxml")
+    }
+
+    /// Retrieves the MODS record of an item.
+    ///
+    /// The record is requested from the `mods` link listed in the item's resources, or from the
+    /// LCCN permalink service (`https://lccn.loc.gov/{item_id}/mods`) if the item lists none.
+    /// Only items whose identifier is an LCCN (e.g. `"2014717546"`) have catalog records;
+    /// other identifiers result in an HTTP error.
+    ///
+    /// # Parameters
+    ///
+    /// - `item_id`: The LCCN of the item, its `lccn.loc.gov` permalink or its loc.gov item URL.
+    ///
+    /// # Returns
+    ///
+    /// Returns the raw MODS document and its URL on success. The document is not parsed, so
+    /// the crate needs no XML parser; read it with the MODS tooling of your choice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (mods, url) = client.get_mods("2014717546").unwrap();
+    /// println!("{}: {} bytes", url, mods.len());
+    /// ```
+    pub fn get_mods(&self, item_id: impl Into<ItemId>) -> Result<(String, String), Box<dyn Error>> {
+        self.get_record_rendition(item_id.into(), "mods")
+    }
+
+    /// Resolves an LCCN to its loc.gov item by following the `https://lccn.loc.gov/{lccn}`
+    /// permalink redirect.
+    ///
+    /// # Parameters
+    ///
+    /// - `lccn`: The LCCN (e.g. `"sn86069873"`) or its `lccn.loc.gov` permalink.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`ItemId`] usable with [`ApiClient::get_item`] and the canonical item URL on
+    /// success. Returns an error if the permalink does not redirect to a loc.gov item page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (item_id, url) = client.resolve_lccn("sn86069873").unwrap();
+    /// println!("{} -> {}", item_id, url);
+    /// let (item, _) = client.get_item(item_id, None).unwrap();
+    /// ```
+    pub fn resolve_lccn(&self, lccn: &str) -> Result<(ItemId, String), Box<dyn Error>> {
+        let lccn = ItemId::parse(lccn)?;
+        let url = format!("{}{}", LCCN_BASE_URL, lccn);
+
+        let (_, meta) = self.execute_url(url)?;
+        let resolved = meta.resolved_url;
+        let is_item_url = resolved
+            .split_once("://")
+            .is_some_and(|(_, rest)| rest.starts_with("www.loc.gov/item/") || rest.starts_with("loc.gov/item/"));
+        if !is_item_url {
+            return Err(format!("LCCN {} did not resolve to a loc.gov item, got {}", lccn, resolved).into());
+        }
+
+        Ok((ItemId::parse(&resolved)?, resolved))
+    }
+
+    /// Downloads and parses a sitemap or sitemap index, such as `https://www.loc.gov/sitemap.xml`.
+    ///
+    /// Gzip-compressed sitemaps (`.xml.gz`) are decompressed when the `gzip` feature is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `url`: The absolute URL of the sitemap.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed [`Sitemap`] and the final URL on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let (index, _) = client.get_sitemap("https://www.loc.gov/sitemap.xml").unwrap();
+    /// for entry in index.entries() {
+    ///     println!("{} ({:?})", entry.url, entry.lastmod);
+    /// }
+    /// ```
+    pub fn get_sitemap(&self, url: &str) -> Result<(Sitemap, String), Box<dyn Error>> {
+        let (body, meta) = self.execute_url(url.to_string())?;
+        #[cfg(feature = "gzip")]
+        let body = transport::gunzip_file(body)?;
+        Ok((Sitemap::parse(&String::from_utf8(body)?)?, meta.final_url))
+    }
+
+    /// Downloads the sitemap at `url` and, if it is an index, every sitemap it lists, down to
+    /// `max_depth` levels of nested indexes.
+    ///
+    /// # Returns
+    ///
+    /// Returns the listed pages of all sitemaps, in document order. Pass them to
+    /// [`sitemaps::item_ids`](crate::sitemaps::item_ids) to fetch the items in bulk.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::sitemaps::item_ids;
+    ///
+    /// let client = ApiClient::new();
+    /// let pages = client.sitemap_urls("https://www.loc.gov/collections/baseball-cards/sitemap.xml", 2).unwrap();
+    /// println!("{} items", item_ids(&pages).len());
+    /// ```
+    pub fn sitemap_urls(&self, url: &str, max_depth: usize) -> Result<Vec<SitemapEntry>, Box<dyn Error>> {
+        match self.get_sitemap(url)? {
+            (Sitemap::UrlSet(entries), _) => Ok(entries),
+            (Sitemap::Index(_), _) if max_depth == 0 => Err(format!("sitemap index {} nested too deeply", url).into()),
+            (Sitemap::Index(sitemaps), _) => {
+                let mut entries = Vec::new();
+                for sitemap in sitemaps {
+                    entries.extend(self.sitemap_urls(&sitemap.url, max_depth - 1)?);
+                }
+                Ok(entries)
+            }
+        }
+    }
+
+    /// Helper method to fetch a bibliographic rendition (`marcxml`, `mods`, ...) of a catalog record.
+    ///
+    /// Looks the rendition up in the item's resources first. If the item cannot be fetched or
+    /// lists no such link, falls back to the LCCN permalink service.
+    fn get_record_rendition(&self, item_id: ItemId, rendition: &str) -> Result<(String, String), Box<dyn Error>> {
+        if item_id.as_str().is_empty() {
+            return Err("An item id is required to retrieve a catalog record".into());
+        }
+
+        let attributes = ItemAttributes { resources: Some(true), ..Default::default() };
+        let url = self
+            .get_item(item_id.clone(), Some(attributes))
+            .ok()
+            .and_then(|(item, _)| rendition_link(&item, rendition))
+            .unwrap_or_else(|| format!("{}{}/{}", LCCN_BASE_URL, item_id, rendition));
+
+        let (body, meta) = self.execute_url(url)?;
+        Ok((String::from_utf8(body)?, meta.final_url))
+    }
+
+    /// Closes the pooled connections of the transport, so the following requests open fresh
+    /// ones. Calling it between large batches of a long-running harvest keeps stale connections
+    /// from failing the next batch.
+    ///
+    /// Has no effect on custom transports that don't implement
+    /// [`HttpTransport::recycle_connections`], or on transports wrapping a client built elsewhere.
+    pub fn recycle_connections(&self) {
+        self.inner.transport.recycle_connections();
+    }
+
+    /// Returns the [`ResponseMeta`] of the most recent request made by this client, if any.
+    ///
+    /// When the client is shared between threads this is the metadata of whichever request
+    /// finished last; use [`ApiClient::fetch_with_meta`] to tie metadata to a specific call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// client.get_item("2014717546", None).unwrap();
+    ///
+    /// if let Some(meta) = client.last_response_meta() {
+    ///     println!("{} in {:?} (cache: {:?})", meta.status, meta.elapsed, meta.header("x-cache"));
+    /// }
+    /// ```
+    pub fn last_response_meta(&self) -> Option<ResponseMeta> {
+        self.inner.last_meta.lock().ok().and_then(|meta| meta.clone())
+    }
+
+    /// Same as [`ApiClient::fetch`], but returns the [`ResponseMeta`] instead of just the final URL.
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized `T` and the response metadata on success.
+    pub fn fetch_with_meta<T: DeserializeOwned + 'static>(&self, endpoint: &Endpoints) -> Result<(T, ResponseMeta), Box<dyn Error>> {
+        let (mut body, meta) = self.execute(endpoint)?;
+        let json = from_slice::<T>(&mut body)?;
+        Ok((json, meta))
+    }
+
+    /// Helper method to request the endpoint behind one of the `on`/`off`/`not` URLs of a [`FilterItem`].
+    fn follow_filter(&self, link: &Option<StringOrArray>, name: &str) -> Result<(SearchResultResponse, String), Box<dyn Error>> {
+        let url = first_link(link).ok_or_else(|| format!("The filter has no `{}` URL", name))?;
+        let endpoint = Endpoints::parse(&url)?;
+        self.fetch(&endpoint)
+    }
+
+    /// Builds the [`Endpoints::Search`] endpoint used by [`ApiClient::search`].
+    #[allow(clippy::too_many_arguments)]
+    fn search_endpoint(
+        &self,
+        query: &str,
+        include_collections: bool,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Endpoints {
+        Endpoints::Search(SearchParams {
+            common: self.list_params(Some(query), attributes, filters, per_page, page, sort),
+            include_collections,
+        })
+    }
+
+    /// Builds the [`Endpoints::Item`] endpoint used by [`ApiClient::get_item`].
+    pub(crate) fn item_endpoint(&self, item_id: ItemId, attributes: Option<ItemAttributes>) -> Result<Endpoints, Box<dyn Error>> {
+        if item_id.as_str().is_empty() {
+            return Err("An item id is required to retrieve an item".into());
+        }
+
+        Ok(Endpoints::Item {
+            item_id: item_id.to_string(),
+            params: ItemParams {
+                format: Some(Format::default()),
+                attributes: attributes.or_else(|| self.inner.default_item_attributes.clone()),
+            },
+        })
+    }
+
+    /// Builds the [`Endpoints::Resource`] endpoint used by [`ApiClient::get_resource`].
+    fn resource_endpoint(
+        &self,
+        resource_id: &str,
+        attributes: Option<ResourceAttributes>,
+        page: Option<u32>,
+    ) -> Result<Endpoints, Box<dyn Error>> {
+        let resource_id = resource_path(resource_id);
+        if resource_id.is_empty() {
+            return Err("A resource id is required to retrieve a resource".into());
+        }
+
+        Ok(Endpoints::Resource {
+            resource_id: resource_id.to_string(),
+            params: ResourceParams {
+                format: Some(Format::default()),
+                attributes,
+                page,
+            },
+        })
+    }
+
+    /// Builds the [`Endpoints::Format`] endpoint used by [`ApiClient::get_format`].
+    #[allow(clippy::too_many_arguments)]
+    fn format_endpoint(
+        &self,
+        format_type: MediaType,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Endpoints {
+        Endpoints::Format {
+            format: format_type,
+            params: self.list_params(query, attributes, filters, per_page, page, sort),
+        }
+    }
+
+    /// Builds the [`Endpoints::Collection`] endpoint used by [`ApiClient::get_collection`].
+    #[allow(clippy::too_many_arguments)]
+    fn collection_endpoint(
+        &self,
+        collection_name: &str,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Endpoints {
+        Endpoints::Collection {
+            name: collection_name.to_string().replace(" ", "-").replace("_", "-"),
+            params: self.list_params(query, attributes, filters, per_page, page, sort),
+        }
+    }
+
+    /// Builds the [`Endpoints::Collections`] endpoint used by [`ApiClient::get_collections`].
+    fn collections_endpoint(
+        &self,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> Endpoints {
+        Endpoints::Collections(self.list_params(query, attributes, filters, per_page, page, sort))
+    }
+
+    /// Builds the [`CommonParams`] shared by the list endpoints, falling back to the client's
+    /// defaults for every parameter passed as `None`.
+    fn list_params(
+        &self,
+        query: Option<&str>,
+        attributes: Option<AttributesSelect>,
+        filters: Option<FacetReq>,
+        per_page: Option<u32>,
+        page: Option<u32>,
+        sort: Option<SortOrder>,
+    ) -> CommonParams {
+        CommonParams {
+            format: Some(Format::default()),
+            attributes: attributes.or_else(|| self.inner.default_attributes.clone()),
+            query: query.map(|q| q.replace(" ", "+")),
+            filter: filters,
+            per_page: per_page.or(self.inner.default_per_page),
+            page: page.or(self.inner.default_page),
+            sort: sort.or_else(|| self.inner.default_sort.clone()),
+        }
+    }
+
+    /// Helper method to send a GET request for an endpoint and return the body with the final URL.
+    fn request_text(&self, endpoint: &Endpoints) -> Result<(Vec<u8>, String), Box<dyn Error>> {
+        let (body, meta) = self.execute(endpoint)?;
+        Ok((body, meta.final_url))
+    }
+
+    /// Helper method to send a GET request for an endpoint, recording its [`ResponseMeta`].
+    fn execute(&self, endpoint: &Endpoints) -> Result<(Vec<u8>, ResponseMeta), Box<dyn Error>> {
+        self.execute_with_retry(endpoint, self.inner.retries, self.inner.retry_backoff).map_err(|(e, _)| e)
+    }
+
+    /// Same as [`ApiClient::execute`], retrying transient failures like
+    /// [`ApiClient::execute_url_with_retry`].
+    pub(crate) fn execute_with_retry(&self, endpoint: &Endpoints, max_retries: u32, backoff: Duration) -> RetryResult {
+        let warnings = endpoint.validate().map_err(|e| (e.into(), 1))?;
+        self.execute_url_with_retry(&self.url_for(endpoint), max_retries, backoff, None, &warnings)
+    }
+
+    /// Helper method to send a GET request for a URL through the middleware chain, hooks and
+    /// metrics, retrying as configured with [`ApiClientBuilder::retries`].
+    fn execute_url(&self, url: String) -> Result<(Vec<u8>, ResponseMeta), Box<dyn Error>> {
+        self.execute_url_with_retry(&url, self.inner.retries, self.inner.retry_backoff, None, &[]).map_err(|(e, _)| e)
+    }
+
+    /// Same as [`ApiClient::execute_url`], retrying transient failures (transport errors, HTTP 429
+    /// and 5xx) up to `max_retries` times with exponential backoff starting at `backoff`.
+    /// `timeout` overrides the client's timeout for each attempt, and `warnings` are reported in
+    /// the [`ResponseMeta`].
+    ///
+    /// On failure, returns the last error together with the number of attempts made.
+    pub(crate) fn execute_url_with_retry(
+        &self,
+        url: &str,
+        max_retries: u32,
+        backoff: Duration,
+        timeout: Option<Duration>,
+        warnings: &[String],
+    ) -> RetryResult {
+        self.with_retry(url, max_retries, backoff, |retries| {
+            self.execute_url_attempt(url.to_string(), retries, timeout, warnings)
+        })
+    }
+
+    /// Helper method to call `attempt_once` with the number of retries so far until it succeeds, fails
+    /// permanently or `max_retries` is reached, see [`ApiClient::execute_url_with_retry`].
+    fn with_retry<T>(
+        &self,
+        url: &str,
+        max_retries: u32,
+        backoff: Duration,
+        mut attempt_once: impl FnMut(u32) -> Result<T, Box<dyn Error>>,
+    ) -> Result<T, (Box<dyn Error>, u32)> {
+        let mut attempt = 0;
+        loop {
+            match attempt_once(attempt) {
+                Ok(response) => return Ok(response),
+                Err(e) if attempt < max_retries && is_transient(e.as_ref()) => {
+                    attempt += 1;
+                    self.inner.metrics.retry(url, attempt);
+                    thread::sleep(backoff * 2u32.saturating_pow(attempt - 1));
+                }
+                Err(e) => return Err((e, attempt + 1)),
+            }
+        }
+    }
+
+    /// Requests an absolute URL, such as a `pagination.next` link, and deserializes the JSON body into `T`.
+    pub(crate) fn fetch_url<T: DeserializeOwned + 'static>(&self, url: String) -> Result<(T, String), Box<dyn Error>> {
+        let (mut body, meta) = self.execute_url(url)?;
+        let json = from_slice::<T>(&mut body)?;
+        Ok((json, meta.final_url))
+    }
+
+    /// Returns the URL of `endpoint` on the client's base URL.
+    pub(crate) fn url_for(&self, endpoint: &Endpoints) -> String {
+        endpoint.url_with_base(&self.inner.base_url)
+    }
+
+    /// Returns the timeout of file downloads set with [`ApiClientBuilder::download_timeout`].
+    pub(crate) fn download_timeout(&self) -> Option<Duration> {
+        self.inner.download_timeout
+    }
+
+    /// Returns the retry policy set with [`ApiClientBuilder::retries`] and
+    /// [`ApiClientBuilder::retry_backoff`].
+    pub(crate) fn retry_policy(&self) -> (u32, Duration) {
+        (self.inner.retries, self.inner.retry_backoff)
+    }
+
+    /// Returns the [`Metrics`] sink of this client.
+    pub(crate) fn metrics(&self) -> &dyn Metrics {
+        self.inner.metrics.as_ref()
+    }
+
+    /// Helper method behind [`ApiClient::execute_url`], recording `retries` and `warnings` in the
+    /// [`ResponseMeta`] and overriding the client's timeout with `timeout` if set.
+    fn execute_url_attempt(
+        &self,
+        url: String,
+        retries: u32,
+        timeout: Option<Duration>,
+        warnings: &[String],
+    ) -> Result<(Vec<u8>, ResponseMeta), Box<dyn Error>> {
+        let opened = self.open::<middleware::Response>(url, timeout)?;
+        let Opened { request, mut response, resolved_url, redirects, started, cache_path, .. } = opened;
+        let wire_size = response.body.len();
+        #[cfg(any(feature = "gzip", feature = "brotli"))]
+        if let Err(e) = transport::decompress(&mut response) {
+            self.inner.metrics.request_completed(&request.url, None, started.elapsed());
+            return Err(TransportError::new(&request.url, e).into());
+        }
+
+        for middleware in &self.inner.middlewares {
+            middleware.handle_response(&request, &mut response);
+        }
+
+        let meta = self.complete(ResponseMeta {
+            status: response.status,
+            headers: response.headers,
+            elapsed: started.elapsed(),
+            final_url: request.url,
+            resolved_url,
+            redirects,
+            body_size: response.body.len(),
+            wire_size,
+            retries,
+            warnings: warnings.to_vec(),
+        })?;
+        if let Some(path) = &cache_path {
+            write_cache(path, meta.status, &meta.headers, &response.body);
+        }
+
+        Ok((response.body, meta))
+    }
+
+    /// Helper method behind [`ApiClient::stream_results`], the streaming counterpart of
+    /// [`ApiClient::execute_url_attempt`].
+    fn stream_url_attempt(&self, url: String, retries: u32, warnings: &[String]) -> Result<ResultStream, Box<dyn Error>> {
+        let Opened { request, response, resolved_url, redirects, started, size, .. } =
+            self.open::<StreamingResponse>(url, None)?;
+
+        // The body is not read yet, so its sizes are only known from a buffered response or the
+        // `Content-Length` header, and the decompressed size not at all for an encoded body.
+        let wire_size = size.unwrap_or(0);
+        let encoded = response.headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("content-encoding"));
+        #[cfg(any(feature = "gzip", feature = "brotli"))]
+        let response = match transport::decompress_streaming(response) {
+            Ok(response) => response,
+            Err(e) => {
+                self.inner.metrics.request_completed(&request.url, None, started.elapsed());
+                return Err(TransportError::new(&request.url, e).into());
+            }
+        };
+
+        self.complete(ResponseMeta {
+            status: response.status,
+            headers: response.headers.clone(),
+            elapsed: started.elapsed(),
+            final_url: request.url,
+            resolved_url,
+            redirects,
+            body_size: if encoded { 0 } else { wire_size },
+            wire_size,
+            retries,
+            warnings: warnings.to_vec(),
+        })?;
+        Ok(ResultStream::new(response.body))
+    }
+
+    /// Helper method shared by every request: applies the default headers and the request
+    /// middleware, serves fresh responses from the cache, calls the `on_request` hook, waits for
+    /// the throttle and sends the request, following redirects.
+    ///
+    /// [`Metrics::request_completed`] is called here if no response is received; otherwise the
+    /// caller reports the response through [`ApiClient::complete`].
+    fn open<R: PipelineResponse>(&self, url: String, timeout: Option<Duration>) -> Result<Opened<R>, Box<dyn Error>> {
+        let mut request = middleware::Request::new(url);
+        request.headers = self.inner.default_headers.clone();
+        request.timeout = timeout;
+        #[cfg(any(feature = "gzip", feature = "brotli"))]
+        transport::accept_compressed(&mut request);
+        let mut short_circuit = self
+            .inner
+            .middlewares
+            .iter()
+            .find_map(|middleware| middleware.handle_request(&mut request));
+        let cache_path = self
+            .inner
+            .cache_dir
+            .as_ref()
+            .filter(|_| is_cacheable(&request.url))
+            .map(|dir| cache_path(dir, &request.url));
+        if let (None, Some(path)) = (&short_circuit, &cache_path) {
+            if let Some(response) = read_cache(path, self.inner.cache_ttl) {
+                self.inner.metrics.cache_hit(&request.url);
+                short_circuit = Some(response);
+            }
+        }
+        let from_network = short_circuit.is_none();
+
+        if let Some(hook) = &self.inner.on_request {
+            hook(&request.url);
+        }
+
+        if from_network {
+            self.wait_for_throttle(&request.url);
+        }
+
+        self.inner.metrics.request_started(&request.url);
+        let started = Instant::now();
+        let (response, resolved_url, redirects, size) = match short_circuit {
+            Some(response) => {
+                let size = response.body.len();
+                (R::buffered(response), request.url.clone(), Vec::new(), Some(size))
+            }
+            None => match self.send::<R>(&request) {
+                Ok((response, resolved_url, redirects)) => {
+                    let size = response.size();
+                    (response, resolved_url, redirects, size)
+                }
+                Err(e) => {
+                    self.inner.metrics.request_completed(&request.url, None, started.elapsed());
+                    return Err(e);
+                }
+            },
+        };
+
+        Ok(Opened {
+            request,
+            response,
+            resolved_url,
+            redirects,
+            started,
+            size,
+            // Only network responses are stored; a cached or short-circuited one is not written back.
+            cache_path: cache_path.filter(|_| from_network),
+        })
+    }
+
+    /// Helper method to report a received response through the metrics, the `on_response` hook
+    /// and [`ApiClient::last_response_meta`], then fail for a status other than `2xx`.
+    fn complete(&self, meta: ResponseMeta) -> Result<ResponseMeta, Box<dyn Error>> {
+        self.inner.metrics.request_completed(&meta.final_url, Some(meta.status), meta.elapsed);
+        if let Some(hook) = &self.inner.on_response {
+            hook(&meta);
+        }
+        if let Ok(mut last) = self.inner.last_meta.lock() {
+            *last = Some(meta.clone());
+        }
+
+        if !(200..300).contains(&meta.status) {
+            return Err(HttpStatusError { status: meta.status, url: meta.final_url }.into());
+        }
+        Ok(meta)
+    }
+
+    /// Helper method to block until the throttle, if any, allows a request to `url`.
+    fn wait_for_throttle(&self, url: &str) {
+        if let Some(throttle) = &self.inner.throttle {
+            let wait = throttle.acquire(url);
+            if !wait.is_zero() {
+                self.inner.metrics.rate_limited(url, wait);
+                thread::sleep(wait);
+            }
+        }
+    }
+
+    /// Helper method to perform the HTTP request described by a middleware [`middleware::Request`].
+    ///
+    /// Redirects are followed here rather than by `reqwest` so the chain can be recorded.
+    /// Returns the response, the URL it was served from and the URLs that redirected.
+    fn send<R: PipelineResponse>(&self, request: &middleware::Request) -> Result<(R, String, Vec<String>), Box<dyn Error>> {
+        let mut current = request.clone();
+        let mut redirects = Vec::new();
+
+        loop {
+            let response = R::get(self.inner.transport.as_ref(), &current)?;
+            if let Some(location) = redirect_location(response.status(), response.headers()) {
+                if redirects.len() >= MAX_REDIRECTS {
+                    return Err(format!("Too many redirects while requesting {}", request.url).into());
+                }
+                let next = join_url(&current.url, location);
+                redirects.push(std::mem::replace(&mut current.url, next));
+                continue;
+            }
+
+            return Ok((response, current.url, redirects));
+        }
+    }
+
+    /// Requests any [`Endpoints`] value and deserializes the JSON body into a caller-supplied type.
+    ///
+    /// This is the building block behind the typed methods above. It lets you supply your own
+    /// response structs, e.g. a slim struct that only keeps the fields you need, while still
+    /// going through the client's base URL handling, rate limiting and
+    /// [`retries`](ApiClientBuilder::retries).
+    ///
+    /// # Parameters
+    ///
+    /// - `endpoint`: The endpoint and parameters to request.
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized `T` and the final URL on success.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::endpoints::Endpoints;
+    /// use loc_api::param_models::{CommonParams, SearchParams};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct SlimResult {
+    ///     id: String,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct SlimPage {
+    ///     results: Vec<SlimResult>,
+    /// }
+    ///
+    /// let client = ApiClient::new();
+    /// let endpoint = Endpoints::Search(SearchParams {
+    ///     common: CommonParams {
+    ///         query: Some("baseball".to_string()),
+    ///         ..Default::default()
+    ///     },
+    ///     include_collections: false,
+    /// });
+    ///
+    /// let (page, url) = client.fetch::<SlimPage>(&endpoint).unwrap();
+    /// for result in page.results {
+    ///     println!("{}", result.id);
+    /// }
+    /// println!("fetched {}", url);
+    /// ```
+    pub fn fetch<T: DeserializeOwned + 'static>(&self, endpoint: &Endpoints) -> Result<(T, String), Box<dyn Error>> {
+        let (mut body, final_url) = self.request_text(endpoint)?;
+        let json = from_slice::<T>(&mut body)?;
+        Ok((json, final_url))
+    }
+
+    /// Helper method to request an endpoint and return both the typed and the raw JSON body.
+    fn request_with_raw<T: DeserializeOwned + 'static>(&self, endpoint: &Endpoints) -> Result<(T, Value, String), Box<dyn Error>> {
+        let (mut body, final_url) = self.request_text(endpoint)?;
+        let raw = from_slice::<Value>(&mut body)?;
+        let json = T::deserialize(&raw)?;
+        Ok((json, raw, final_url))
+    }
+}
+
+/// A request sent by [`ApiClient::open`], with the response received so far.
+struct Opened<R> {
+    request: middleware::Request,
+    response: R,
+    resolved_url: String,
+    redirects: Vec<String>,
+    started: Instant,
+    /// Size of the body as received, if known before reading it.
+    size: Option<usize>,
+    /// The cache file to store the response in, `None` if it is not cached.
+    cache_path: Option<PathBuf>,
+}
+
+/// A response the request pipeline can produce, read in full or streamed while it arrives.
+trait PipelineResponse: Sized {
+    /// Sends `request` through `transport`, without following redirects.
+    fn get(transport: &dyn HttpTransport, request: &middleware::Request) -> Result<Self, TransportError>;
+    /// Wraps a response from the middleware or the cache.
+    fn buffered(response: middleware::Response) -> Self;
+    fn status(&self) -> u16;
+    fn headers(&self) -> &[(String, String)];
+    /// Size of the body as received, if known before reading it.
+    fn size(&self) -> Option<usize>;
+}
+
+impl PipelineResponse for middleware::Response {
+    fn get(transport: &dyn HttpTransport, request: &middleware::Request) -> Result<Self, TransportError> {
+        transport.get(request)
+    }
+
+    fn buffered(response: middleware::Response) -> Self {
+        response
+    }
+
+    fn status(&self) -> u16 {
+        self.status
+    }
+
+    fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    fn size(&self) -> Option<usize> {
+        Some(self.body.len())
+    }
+}
+
+impl PipelineResponse for StreamingResponse {
+    fn get(transport: &dyn HttpTransport, request: &middleware::Request) -> Result<Self, TransportError> {
+        transport.get_streaming(request)
+    }
+
+    fn buffered(response: middleware::Response) -> Self {
+        StreamingResponse {
+            status: response.status,
+            headers: response.headers,
+            body: Box::new(io::Cursor::new(response.body)),
+        }
+    }
+
+    fn status(&self) -> u16 {
+        self.status
+    }
+
+    fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    fn size(&self) -> Option<usize> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse().ok())
+    }
+}
+
+/// Returns the first link among the resources of `item`, or their files, whose path ends with
+/// `/{rendition}`, e.g. `https://lccn.loc.gov/2014717546/marcxml`.
+fn rendition_link(item: &ItemResponse, rendition: &str) -> Option<String> {
+    let suffix = format!("/{}", rendition);
+    item.resources
+        .iter()
+        .flatten()
+        .flat_map(|resource| {
+            let files = resource.all_files().into_iter().filter_map(|file| first_link(&file.url));
+            resource.url.iter().flatten().cloned().chain(files).collect::<Vec<_>>()
+        })
+        .find(|url| {
+            let path = url.split(['?', '#']).next().unwrap_or_default();
+            path.trim_end_matches('/').ends_with(&suffix)
+        })
+}
+
+/// Returns the `Location` of a `3xx` response, `None` for other responses.
+fn redirect_location(status: u16, headers: &[(String, String)]) -> Option<&str> {
+    if !(300..400).contains(&status) {
+        return None;
+    }
+    headers.iter().find(|(name, _)| name.eq_ignore_ascii_case("location")).map(|(_, value)| value.as_str())
+}
+
+/// Extracts the words of an ALTO XML document, one text line per output line.
+///
+/// Words are separated by a space, whether or not the document has `SP` elements between them.
+/// A word hyphenated across two lines is written whole on the first line when its first part
+/// carries the full word in `SUBS_CONTENT`; otherwise the `HYP` hyphen is kept.
+fn alto_text(xml: &str) -> String {
+    let mut text = String::new();
+    let mut line = String::new();
+    // Set when a `HypPart1` was written as the whole word, so its `HypPart2` and `HYP` are skipped.
+    let mut joined = false;
+
+    let flush = |line: &mut String, text: &mut String| {
+        let trimmed = line.trim_end();
+        if !trimmed.is_empty() {
+            text.push_str(trimmed);
+            text.push('\n');
+        }
+        line.clear();
+    };
+
+    for tag in xml.split('<').skip(1) {
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .next()
+            .unwrap_or_default();
+        // Namespaced documents use prefixed names such as `alto:String`.
+        let name = name.rsplit(':').next().unwrap_or_default();
+        match (name, is_closing) {
+            ("String", false) => {
+                let content = match xml_attribute(tag, "SUBS_TYPE").as_deref() {
+                    Some("HypPart1") => {
+                        joined = xml_attribute(tag, "SUBS_CONTENT").is_some();
+                        xml_attribute(tag, "SUBS_CONTENT").or_else(|| xml_attribute(tag, "CONTENT"))
+                    }
+                    Some("HypPart2") if joined => {
+                        joined = false;
+                        None
+                    }
+                    _ => xml_attribute(tag, "CONTENT"),
+                };
+                if let Some(content) = content.filter(|content| !content.is_empty()) {
+                    if !line.is_empty() && !line.ends_with(' ') {
+                        line.push(' ');
+                    }
+                    line.push_str(&content);
+                }
+            }
+            ("SP", false) if !line.is_empty() && !line.ends_with(' ') => line.push(' '),
+            ("HYP", false) if !joined => line.push_str(&xml_attribute(tag, "CONTENT").unwrap_or_else(|| "-".to_string())),
+            ("TextLine", true) => flush(&mut line, &mut text),
+            _ => {}
+        }
+    }
+    flush(&mut line, &mut text);
+
+    text
+}
+
+/// Returns the unescaped value of the attribute `name` of an XML start tag, given the text
+/// after its `<`. Both single- and double-quoted values are read.
+fn xml_attribute(tag: &str, name: &str) -> Option<String> {
+    let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+    let mut rest = tag;
+    while let Some(index) = rest.find(name) {
+        let preceded = rest[..index].ends_with(|c: char| c.is_whitespace());
+        let after = rest[index + name.len()..].trim_start();
+        rest = &rest[index + name.len()..];
+        let Some(value) = after.strip_prefix('=').map(str::trim_start).filter(|_| preceded) else {
+            continue;
+        };
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        return Some(unescape_xml(&value[..value.find(quote)?]));
+    }
+    None
+}
+
+/// Replaces the predefined XML entities and numeric character references in `text`. Unknown
+/// entities are kept as they are.
+fn unescape_xml(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find('&') {
+        unescaped.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let decoded = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        });
+        match (entity, decoded) {
+            (Some(entity), Some(c)) => {
+                unescaped.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// A response stored in the cache directory, see [`ApiClientBuilder::cache_dir`].
+#[derive(Serialize, Deserialize)]
+struct CachedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    /// Seconds since the Unix epoch at which the response was stored.
+    stored_at: u64,
+    body: Box<RawValue>,
+}
+
+/// Returns `true` if the response to `url` may be cached, i.e. it is a JSON API response.
+fn is_cacheable(url: &str) -> bool {
+    url.split_once('?')
+        .is_some_and(|(_, query)| query.split('&').any(|pair| pair == "fo=json"))
+}
+
+/// Returns the file caching the response to `url` in `dir`, named by the hash of the URL.
+fn cache_path(dir: &Path, url: &str) -> PathBuf {
+    dir.join(format!("{:016x}.json", crate::diff::hash_bytes(url.as_bytes())))
+}
+
+/// Returns the seconds elapsed since the Unix epoch.
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Reads the cached response at `path`, `None` if there is none or it is older than `ttl`.
+fn read_cache(path: &Path, ttl: Duration) -> Option<middleware::Response> {
+    let cached: CachedResponse = serde_json::from_slice(&fs::read(path).ok()?).ok()?;
+    if unix_now().saturating_sub(cached.stored_at) >= ttl.as_secs() {
+        return None;
+    }
+    Some(middleware::Response {
+        status: cached.status,
+        headers: cached.headers,
+        body: cached.body.get().as_bytes().to_vec(),
+    })
+}
+
+/// Stores a response at `path`, skipping bodies that are not valid JSON. The body is stored
+/// decompressed, so the `content-encoding` and `content-length` headers are dropped.
+///
+/// Caching is best effort; a failed write only means the next request goes to the network.
+fn write_cache(path: &Path, status: u16, headers: &[(String, String)], body: &[u8]) {
+    let Ok(body) = std::str::from_utf8(body).map(str::to_string) else {
+        return;
+    };
+    let Ok(body) = RawValue::from_string(body) else {
+        return;
+    };
+    let headers = headers
+        .iter()
+        .filter(|(name, _)| !name.eq_ignore_ascii_case("content-encoding") && !name.eq_ignore_ascii_case("content-length"))
+        .cloned()
+        .collect();
+    let cached = CachedResponse { status, headers, stored_at: unix_now(), body };
+    if let Ok(json) = serde_json::to_vec(&cached) {
+        let _ = path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| download::write_atomic(path, &json));
+    }
+}
+
+/// Resolves the `Location` of a redirect against the URL that answered with it.
+fn join_url(base: &str, location: &str) -> String {
+    if location.contains("://") {
+        return location.to_string();
+    }
+    let (scheme, rest) = base.split_once("://").unwrap_or(("https", base));
+    if let Some(location) = location.strip_prefix("//") {
+        return format!("{}://{}", scheme, location);
+    }
+    let path_start = rest.find('/').unwrap_or(rest.len());
+    let origin = &base[..scheme.len() + 3 + path_start];
+    if location.starts_with('/') {
+        return format!("{}{}", origin, location);
+    }
+    let path = rest[path_start..].split(['?', '#']).next().unwrap_or_default();
+    let directory = path.rfind('/').map_or("/", |end| &path[..=end]);
+    format!("{}{}{}", origin, directory, location)
+}
+
+/// Returns whether a request error is worth retrying: transport errors, HTTP 429 and 5xx.
+fn is_transient(error: &(dyn Error + 'static)) -> bool {
+    match error.downcast_ref::<HttpStatusError>() {
+        Some(status) => status.status == 429 || status.status >= 500,
+        None => error.is::<TransportError>(),
     }
 }
@@ -0,0 +1,244 @@
+//! # Mapping Module
+//!
+//! Conversions from the LOC response models into canonical metadata schemas used by
+//! aggregation pipelines, starting with simple Dublin Core.
+//!
+//! [`NormalizedItem`] flattens search results and items into one record shape with the same
+//! fields either way, for tabular analysis.
+
+use serde::{Deserialize, Serialize};
+
+use crate::identifiers::ItemId;
+use crate::response_models::{
+    all_strings, first_string, strings, unique, BoolOrString, ItemAttribute, ItemOrArray, ItemSummary, ResultItem,
+};
+
+/// A ten-element subset of simple Dublin Core, as used by OAI-PMH style pipelines.
+///
+/// Every element is repeatable, so each field is a list; empty lists are omitted when serialized.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::mapping::DublinCore;
+/// use loc_api::response_models::ItemAttribute;
+///
+/// let item: ItemAttribute = serde_json::from_str(r#"{
+///     "title": "Baseball cards",
+///     "contributor_names": ["American Tobacco Company"],
+///     "subject_headings": ["Baseball", "Baseball players"],
+///     "date": "1887",
+///     "language": ["english"]
+/// }"#).unwrap();
+///
+/// let dc = DublinCore::from(&item);
+/// assert_eq!(dc.title, vec!["Baseball cards"]);
+/// assert_eq!(dc.creator, vec!["American Tobacco Company"]);
+/// assert_eq!(dc.subject.len(), 2);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct DublinCore {
+    /// `dc:title`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub title: Vec<String>,
+    /// `dc:creator`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub creator: Vec<String>,
+    /// `dc:subject`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub subject: Vec<String>,
+    /// `dc:description`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub description: Vec<String>,
+    /// `dc:date`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub date: Vec<String>,
+    /// `dc:type`
+    #[serde(rename = "type", skip_serializing_if = "Vec::is_empty", default)]
+    pub type_field: Vec<String>,
+    /// `dc:format`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub format: Vec<String>,
+    /// `dc:identifier`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub identifier: Vec<String>,
+    /// `dc:language`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub language: Vec<String>,
+    /// `dc:rights`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub rights: Vec<String>,
+}
+
+impl From<&ItemAttribute> for DublinCore {
+    fn from(item: &ItemAttribute) -> Self {
+        let mut creator = strings(&item.contributor_names);
+        if creator.is_empty() {
+            creator = strings(&item.contributors);
+        }
+
+        DublinCore {
+            title: owned(all_strings(&item.title)),
+            creator: owned(creator),
+            subject: owned(unique([
+                strings(&item.subject_headings),
+                strings(&item.subjects),
+                strings(&item.subject),
+            ])),
+            description: owned(unique([all_strings(&item.description), all_strings(&item.summary)])),
+            date: owned(all_strings(&item.date)),
+            type_field: owned(strings(&item.original_format)),
+            format: owned(strings(&item.online_format)),
+            identifier: owned(unique([all_strings(&item.id), all_strings(&item.url), strings(&item.digital_id)])),
+            language: owned(strings(&item.language)),
+            rights: owned(strings(&item.rights)),
+        }
+    }
+}
+
+impl From<&ItemSummary> for DublinCore {
+    fn from(item: &ItemSummary) -> Self {
+        DublinCore {
+            title: owned(all_strings(&item.title)),
+            creator: owned(strings(&item.contributor_names)),
+            subject: owned(unique([strings(&item.subject_headings), strings(&item.subjects)])),
+            description: owned(all_strings(&item.summary)),
+            date: owned(all_strings(&item.date_issued)),
+            type_field: owned(strings(&item.genre)),
+            format: owned(all_strings(&item.medium)),
+            identifier: owned(strings(&item.call_number)),
+            language: owned(strings(&item.language)),
+            rights: Vec::new(),
+        }
+    }
+}
+
+/// An item flattened into the fields shared by search results and item responses.
+///
+/// Single-valued fields keep their first value; multi-valued fields are lists without empty
+/// values or duplicates.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::mapping::NormalizedItem;
+/// use loc_api::response_models::ResultItem;
+///
+/// let result: ResultItem = serde_json::from_str(r#"{
+///     "id": "http://www.loc.gov/item/2014717546/",
+///     "title": "Baseball players practicing",
+///     "date": "1887-05",
+///     "subject": ["baseball", "sports"],
+///     "digitized": true
+/// }"#).unwrap();
+///
+/// let item = NormalizedItem::from(&result);
+/// assert_eq!(item.id.as_deref(), Some("2014717546"));
+/// assert_eq!(item.year, Some(1887));
+/// assert_eq!(item.subjects, vec!["baseball", "sports"]);
+/// assert_eq!(item.digitized, Some(true));
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct NormalizedItem {
+    /// The item ID, or `None` for results that are not items, such as collections.
+    pub id: Option<String>,
+    /// The title.
+    pub title: Option<String>,
+    /// The date as written by loc.gov, e.g. `1887` or `1887-05-01`.
+    pub date: Option<String>,
+    /// The first four-digit year of [`NormalizedItem::date`].
+    pub year: Option<i32>,
+    /// The item's page on loc.gov.
+    pub url: Option<String>,
+    /// The descriptions and summaries.
+    #[serde(default)]
+    pub description: Vec<String>,
+    /// The subjects and subject headings.
+    #[serde(default)]
+    pub subjects: Vec<String>,
+    /// The contributor names.
+    #[serde(default)]
+    pub contributors: Vec<String>,
+    /// The original formats, e.g. `photo, print, drawing`.
+    #[serde(default)]
+    pub original_format: Vec<String>,
+    /// The online formats, e.g. `image`.
+    #[serde(default)]
+    pub online_format: Vec<String>,
+    /// The languages.
+    #[serde(default)]
+    pub language: Vec<String>,
+    /// The named places the item is about.
+    #[serde(default)]
+    pub location: Vec<String>,
+    /// Whether the item is digitized, if known.
+    pub digitized: Option<bool>,
+}
+
+impl From<&ResultItem> for NormalizedItem {
+    fn from(result: &ResultItem) -> Self {
+        let summary = result.item.as_ref().and_then(ItemOrArray::first);
+        let mut contributors = summary.map(|s| strings(&s.contributor_names)).unwrap_or_default();
+        if contributors.is_empty() {
+            contributors = strings(&result.contributor);
+        }
+        let date = first_string(&result.date);
+
+        NormalizedItem {
+            id: result.item_id().map(|id| id.as_str().to_string()),
+            title: first_string(&result.title).map(str::to_string),
+            year: date.and_then(year),
+            date: date.map(str::to_string),
+            url: first_string(&result.url).or_else(|| first_string(&result.id)).map(str::to_string),
+            description: owned(all_strings(&result.description)),
+            subjects: owned(strings(&result.subject)),
+            contributors: owned(contributors),
+            original_format: owned(strings(&result.original_format)),
+            online_format: owned(strings(&result.online_format)),
+            language: owned(strings(&result.language)),
+            location: owned(strings(&result.location)),
+            digitized: result.digitized.as_ref().and_then(BoolOrString::as_bool),
+        }
+    }
+}
+
+impl From<&ItemAttribute> for NormalizedItem {
+    fn from(item: &ItemAttribute) -> Self {
+        let mut contributors = strings(&item.contributor_names);
+        if contributors.is_empty() {
+            contributors = strings(&item.contributors);
+        }
+        let url = first_string(&item.url);
+        let id = first_string(&item.id).or(url);
+        let date = first_string(&item.date);
+
+        NormalizedItem {
+            id: id.and_then(|id| ItemId::parse(id).ok()).map(|id| id.as_str().to_string()),
+            title: first_string(&item.title).map(str::to_string),
+            year: date.and_then(year),
+            date: date.map(str::to_string),
+            url: url.map(str::to_string),
+            description: owned(unique([all_strings(&item.description), all_strings(&item.summary)])),
+            subjects: owned(unique([strings(&item.subject_headings), strings(&item.subjects), strings(&item.subject)])),
+            contributors: owned(contributors),
+            original_format: owned(strings(&item.original_format)),
+            online_format: owned(strings(&item.online_format)),
+            language: owned(strings(&item.language)),
+            location: owned(strings(&item.locations)),
+            digitized: item.digitized.as_ref().and_then(BoolOrString::as_bool),
+        }
+    }
+}
+
+/// Returns the first four-digit year in `date`.
+fn year(date: &str) -> Option<i32> {
+    let bytes = date.as_bytes();
+    (0..bytes.len().saturating_sub(3))
+        .find(|&i| bytes[i..i + 4].iter().all(u8::is_ascii_digit))
+        .and_then(|i| date[i..i + 4].parse().ok())
+}
+
+/// Copies borrowed values into owned strings.
+fn owned(values: Vec<&str>) -> Vec<String> {
+    values.into_iter().map(str::to_string).collect()
+}
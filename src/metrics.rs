@@ -0,0 +1,150 @@
+//! # Metrics Module
+//!
+//! The [`Metrics`] trait is called by [`ApiClient`](crate::loc_client::ApiClient) at each stage
+//! of a request so harvest dashboards can be built on top of the client. All methods have no-op
+//! default implementations, so implementors only override the events they care about.
+//!
+//! With the `prometheus` feature enabled, [`PrometheusMetrics`] records the events into a
+//! [`prometheus::Registry`].
+
+use std::time::Duration;
+
+/// Receives events from the client's request path.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::loc_client::ApiClient;
+/// use loc_api::metrics::Metrics;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// #[derive(Default)]
+/// struct RequestCounter(AtomicUsize);
+///
+/// impl Metrics for RequestCounter {
+///     fn request_started(&self, _url: &str) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let client = ApiClient::builder().metrics(RequestCounter::default()).build();
+/// ```
+pub trait Metrics: Send + Sync {
+    /// Called right before a request is sent.
+    fn request_started(&self, _url: &str) {}
+
+    /// Called once a request finished. `status` is `None` when no response was received
+    /// (connection errors, timeouts, ...).
+    fn request_completed(&self, _url: &str, _status: Option<u16>, _elapsed: Duration) {}
+
+    /// Called before a failed request is retried; `attempt` starts at 1 for the first retry.
+    fn retry(&self, _url: &str, _attempt: u32) {}
+
+    /// Called when a response is served from a cache instead of the network.
+    fn cache_hit(&self, _url: &str) {}
+
+    /// Called when a request is delayed by rate limiting, with the time spent waiting.
+    fn rate_limited(&self, _url: &str, _wait: Duration) {}
+}
+
+/// A [`Metrics`] implementation that ignores every event. This is the client default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+/// A [`Metrics`] implementation backed by the [`prometheus`] crate.
+///
+/// Registers the following collectors:
+///
+/// - `loc_api_requests_total{status}`: completed requests by HTTP status (`"error"` when no response).
+/// - `loc_api_request_duration_seconds`: request latency histogram.
+/// - `loc_api_requests_in_flight`: requests currently in progress.
+/// - `loc_api_retries_total`, `loc_api_cache_hits_total`, `loc_api_rate_limited_total`: event counters.
+/// - `loc_api_rate_limited_seconds_total`: total time spent waiting on rate limits.
+#[cfg(feature = "prometheus")]
+#[derive(Clone)]
+pub struct PrometheusMetrics {
+    requests: prometheus::IntCounterVec,
+    duration: prometheus::Histogram,
+    in_flight: prometheus::IntGauge,
+    retries: prometheus::IntCounter,
+    cache_hits: prometheus::IntCounter,
+    rate_limited: prometheus::IntCounter,
+    rate_limited_seconds: prometheus::Counter,
+}
+
+#[cfg(feature = "prometheus")]
+impl PrometheusMetrics {
+    /// Creates the collectors and registers them with `registry`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::loc_client::ApiClient;
+    /// use loc_api::metrics::PrometheusMetrics;
+    ///
+    /// let registry = prometheus::Registry::new();
+    /// let metrics = PrometheusMetrics::new(&registry).unwrap();
+    /// let client = ApiClient::builder().metrics(metrics).build();
+    /// ```
+    pub fn new(registry: &prometheus::Registry) -> Result<Self, prometheus::Error> {
+        use prometheus::{Counter, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts};
+
+        let metrics = PrometheusMetrics {
+            requests: IntCounterVec::new(
+                Opts::new("loc_api_requests_total", "Completed loc.gov API requests by HTTP status."),
+                &["status"],
+            )?,
+            duration: Histogram::with_opts(HistogramOpts::new(
+                "loc_api_request_duration_seconds",
+                "Latency of loc.gov API requests.",
+            ))?,
+            in_flight: IntGauge::new("loc_api_requests_in_flight", "loc.gov API requests in progress.")?,
+            retries: IntCounter::new("loc_api_retries_total", "Retried loc.gov API requests.")?,
+            cache_hits: IntCounter::new("loc_api_cache_hits_total", "Responses served from the cache.")?,
+            rate_limited: IntCounter::new("loc_api_rate_limited_total", "Requests delayed by rate limiting.")?,
+            rate_limited_seconds: Counter::new(
+                "loc_api_rate_limited_seconds_total",
+                "Time spent waiting on rate limits.",
+            )?,
+        };
+
+        registry.register(Box::new(metrics.requests.clone()))?;
+        registry.register(Box::new(metrics.duration.clone()))?;
+        registry.register(Box::new(metrics.in_flight.clone()))?;
+        registry.register(Box::new(metrics.retries.clone()))?;
+        registry.register(Box::new(metrics.cache_hits.clone()))?;
+        registry.register(Box::new(metrics.rate_limited.clone()))?;
+        registry.register(Box::new(metrics.rate_limited_seconds.clone()))?;
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(feature = "prometheus")]
+impl Metrics for PrometheusMetrics {
+    fn request_started(&self, _url: &str) {
+        self.in_flight.inc();
+    }
+
+    fn request_completed(&self, _url: &str, status: Option<u16>, elapsed: Duration) {
+        self.in_flight.dec();
+        let status = status.map_or_else(|| "error".to_string(), |s| s.to_string());
+        self.requests.with_label_values(&[status.as_str()]).inc();
+        self.duration.observe(elapsed.as_secs_f64());
+    }
+
+    fn retry(&self, _url: &str, _attempt: u32) {
+        self.retries.inc();
+    }
+
+    fn cache_hit(&self, _url: &str) {
+        self.cache_hits.inc();
+    }
+
+    fn rate_limited(&self, _url: &str, wait: Duration) {
+        self.rate_limited.inc();
+        self.rate_limited_seconds.inc_by(wait.as_secs_f64());
+    }
+}
@@ -0,0 +1,94 @@
+//! # Middleware Module
+//!
+//! Middlewares are registered on the [`ApiClientBuilder`](crate::loc_client::ApiClientBuilder)
+//! and run, in registration order, inside the client's request path. They can rewrite the
+//! outgoing URL and headers, answer a request themselves (short-circuiting the network), and
+//! observe or modify the response before it is deserialized.
+
+use std::time::Duration;
+
+/// An outgoing request as seen by a [`Middleware`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// The URL that will be requested.
+    pub url: String,
+    /// Extra headers sent with the request as `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+    /// Timeout of this request, overriding the client's default timeout. Set by the client for
+    /// file downloads (see [`ApiClientBuilder::download_timeout`](crate::loc_client::ApiClientBuilder::download_timeout));
+    /// middlewares may change it.
+    pub timeout: Option<Duration>,
+}
+
+impl Request {
+    /// Creates a new [`Request`] for `url` without extra headers or timeout override.
+    pub fn new(url: impl Into<String>) -> Self {
+        Request { url: url.into(), headers: Vec::new(), timeout: None }
+    }
+
+    /// Sets the header `name`, replacing any existing value (case-insensitive).
+    pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.headers.retain(|(key, _)| !key.eq_ignore_ascii_case(&name));
+        self.headers.push((name, value.into()));
+    }
+}
+
+/// A response as seen by a [`Middleware`], either received from the network or
+/// produced by a middleware that short-circuited the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers as `(name, value)` pairs, names in lowercase.
+    pub headers: Vec<(String, String)>,
+    /// The raw response body.
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Creates a `200 OK` [`Response`] with the given body and no headers.
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        Response { status: 200, headers: Vec::new(), body: body.into() }
+    }
+}
+
+/// A request/response interceptor.
+///
+/// Both methods have default implementations that do nothing, so a middleware only
+/// implements the side it needs.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::loc_client::ApiClient;
+/// use loc_api::middleware::{Middleware, Request, Response};
+///
+/// /// Sends every request through a caching proxy that requires a token.
+/// struct ProxyAuth {
+///     token: String,
+/// }
+///
+/// impl Middleware for ProxyAuth {
+///     fn handle_request(&self, request: &mut Request) -> Option<Response> {
+///         request.url = request.url.replace("https://www.loc.gov", "https://loc-cache.example.org");
+///         request.set_header("Authorization", format!("Bearer {}", self.token));
+///         None
+///     }
+/// }
+///
+/// let client = ApiClient::builder()
+///     .middleware(ProxyAuth { token: "secret".to_string() })
+///     .build();
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent. Returning `Some(response)` skips the network and
+    /// the remaining middlewares' `handle_request`, using `response` instead.
+    fn handle_request(&self, _request: &mut Request) -> Option<Response> {
+        None
+    }
+
+    /// Called with every response, including short-circuited ones, before it is returned
+    /// to the caller.
+    fn handle_response(&self, _request: &Request, _response: &mut Response) {}
+}
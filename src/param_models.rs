@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 /// Represents common query parameters applicable to multiple endpoints.
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CommonParams {
     /// Specifies the format of the returned results (`fo=json` or `fo=yaml`).
     pub format: Option<Format>,
@@ -17,11 +18,92 @@ pub struct CommonParams {
     /// Specifies the page number to retrieve (`sp` parameter). The first page is 1.
     pub page: Option<u32>,
     /// Defines the sorting order of the results (`sb` parameter).
-    pub sort: Option<SortField>,
+    ///
+    /// A single [`SortField`] converts into a [`SortOrder`] with `.into()`.
+    pub sort: Option<SortOrder>,
+}
+
+impl CommonParams {
+    /// Returns the query pairs of the parameters that are set, in the canonical order `fo`,
+    /// `at`, `at!`, `q`, `fa`, `c`, `sp`, `sb`. The format defaults to JSON.
+    ///
+    /// Attribute lists and facets are sorted and deduplicated, and empty values are omitted,
+    /// so that equivalent parameters always produce the same URL.
+    ///
+    /// Values are returned as the API expects them: the query and facet values keep their
+    /// `+`-encoded spaces. [`Endpoints::to_url`](crate::endpoints::Endpoints::to_url) encodes
+    /// the remaining reserved characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::param_models::CommonParams;
+    ///
+    /// let params = CommonParams {
+    ///     query: Some("civil+war".to_string()),
+    ///     per_page: Some(50),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     params.query_pairs(),
+    ///     vec![("fo", "json".to_string()), ("q", "civil+war".to_string()), ("c", "50".to_string())]
+    /// );
+    /// ```
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![("fo", self.format.unwrap_or_default().slug().to_string())];
+        if let Some(attributes) = &self.attributes {
+            pairs.extend(attributes.query_pairs());
+        }
+        if let Some(query) = self.query.as_ref().filter(|q| !q.trim().is_empty()) {
+            pairs.push(("q", query.trim().to_string()));
+        }
+        if let Some(filter) = &self.filter {
+            let mut facets: Vec<String> = filter.filters.iter().map(|f| f.to_string()).collect();
+            facets.sort_unstable();
+            facets.dedup();
+            if !facets.is_empty() {
+                pairs.push(("fa", facets.join("|")));
+            }
+        }
+        if let Some(per_page) = self.per_page {
+            pairs.push(("c", per_page.to_string()));
+        }
+        if let Some(page) = self.page {
+            pairs.push(("sp", page.to_string()));
+        }
+        if let Some(sort) = self.sort.as_ref().filter(|s| !s.fields.is_empty()) {
+            pairs.push(("sb", sort.slug()));
+        }
+        pairs
+    }
+
+    /// Excludes web pages (`original-format:web page`) from the results.
+    ///
+    /// See [`FacetReq::exclude_websites`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::param_models::CommonParams;
+    ///
+    /// let params = CommonParams::default().exclude_websites();
+    /// assert_eq!(params.filter.unwrap().to_query_param(), "original-format!:web+page");
+    /// ```
+    pub fn exclude_websites(mut self) -> Self {
+        self.filter = Some(self.filter.take().unwrap_or_default().exclude_websites());
+        self
+    }
+
+    /// Restricts results to freely accessible materials. See [`FacetReq::freely_accessible`].
+    pub fn freely_accessible(mut self) -> Self {
+        self.filter = Some(self.filter.take().unwrap_or_default().freely_accessible());
+        self
+    }
 }
 
 /// Parameters specific to the `/search/` endpoint.
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SearchParams {
     /// Common query parameters.
     pub common: CommonParams,
@@ -31,8 +113,23 @@ pub struct SearchParams {
     pub include_collections: bool,
 }
 
+impl SearchParams {
+    /// Excludes web pages from the search results. See [`FacetReq::exclude_websites`].
+    pub fn exclude_websites(mut self) -> Self {
+        self.common = self.common.exclude_websites();
+        self
+    }
+
+    /// Restricts results to freely accessible materials. See [`FacetReq::freely_accessible`].
+    pub fn freely_accessible(mut self) -> Self {
+        self.common = self.common.freely_accessible();
+        self
+    }
+}
+
 /// Parameters specific to the `/item/{item_id}/` endpoint.
 #[derive(Debug, Serialize, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ItemParams {
     /// Specifies the format of the returned results (`fo=json` or `fo=yaml`).
     pub format: Option<Format>,
@@ -40,19 +137,51 @@ pub struct ItemParams {
     pub attributes: Option<ItemAttributes>,
 }
 
+impl ItemParams {
+    /// Returns the query pairs of the parameters that are set: `fo` followed by the `at` pairs.
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![("fo", self.format.unwrap_or_default().slug().to_string())];
+        if let Some(attributes) = &self.attributes {
+            pairs.extend(attributes.query_pairs());
+        }
+        pairs
+    }
+}
+
 /// Parameters specific to the `/resource/{resource_id}/` endpoint.
 #[derive(Debug, Serialize, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ResourceParams {
     /// Specifies the format of the returned results (`fo=json` or `fo=yaml`).
     pub format: Option<Format>,
     /// Selects specific attributes to include in the resource response.
     pub attributes: Option<ResourceAttributes>,
+    /// Selects a page of a multi-image resource, or a segment of a segmented resource, by its
+    /// 1-based sequence number (`sp` parameter).
+    pub page: Option<u32>,
+}
+
+impl ResourceParams {
+    /// Returns the query pairs of the parameters that are set: `fo`, the `at` pairs and `sp`.
+    pub fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![("fo", self.format.unwrap_or_default().slug().to_string())];
+        if let Some(attributes) = &self.attributes {
+            pairs.extend(attributes.query_pairs());
+        }
+        if let Some(page) = self.page {
+            pairs.push(("sp", page.to_string()));
+        }
+        pairs
+    }
 }
 
 /// Represents the filter/facet parameter (`fa`).
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FacetReq {
     /// A list of facet filters (e.g., `"location:ohio"`, `"subject:wildlife"`).
+    ///
+    /// Wrap a filter with [`Facet::exclude`] to exclude matching results instead.
     pub filters: Vec<Facet>,
 }
 
@@ -74,9 +203,53 @@ impl FacetReq {
     pub fn to_query_param(&self) -> String {
         self.filters.iter().map(|f| f.to_string()).collect::<Vec<String>>().join("|")
     }
+
+    /// Adds a negated facet, excluding results that match `facet`. See [`Facet::exclude`].
+    pub fn exclude(mut self, facet: Facet) -> Self {
+        self.filters.push(Facet::exclude(facet));
+        self
+    }
+
+    /// Adds a negative `original-format` facet (`original-format!:web+page`) excluding web pages,
+    /// which otherwise dominate many keyword searches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::param_models::{FacetReq, Facet};
+    ///
+    /// let filter = FacetReq {
+    ///     filters: vec![Facet::Subject { value: "baseball".to_string() }],
+    /// }.exclude_websites();
+    /// assert_eq!(filter.to_query_param(), "subject:baseball|original-format!:web+page");
+    /// ```
+    pub fn exclude_websites(self) -> Self {
+        self.exclude(Facet::Other {
+            key: "original-format".to_string(),
+            value: "web page".to_string(),
+        })
+    }
+
+    /// Restricts results to freely accessible materials (`access-restricted:false`), replacing
+    /// any access-restricted facet already present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::param_models::FacetReq;
+    ///
+    /// let filter = FacetReq::default().freely_accessible();
+    /// assert_eq!(filter.to_query_param(), "access-restricted:false");
+    /// ```
+    pub fn freely_accessible(mut self) -> Self {
+        self.filters.retain(|f| !matches!(f, Facet::AccessRestricted { .. }));
+        self.filters.push(Facet::access_restricted(false));
+        self
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Facet {
     AccessRestricted {
         value: bool,
@@ -100,19 +273,512 @@ pub enum Facet {
         key: String,
         value: String,
     },
+    /// Excludes results matching the wrapped facet. Build it with [`Facet::exclude`].
+    Not {
+        facet: Box<Facet>,
+    },
 }
 
 impl Facet {
-    fn to_string(&self) -> String {
+    /// Creates an `access-restricted` facet. `false` keeps only freely accessible materials.
+    pub fn access_restricted(value: bool) -> Self {
+        Facet::AccessRestricted { value }
+    }
+
+    /// Negates `facet`, so that results matching it are excluded.
+    ///
+    /// Serializes with the API's `!:` separator, e.g. `partof!:web+archives`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::param_models::{Facet, FacetReq};
+    ///
+    /// let filter = FacetReq {
+    ///     filters: vec![
+    ///         Facet::Subject { value: "maps".to_string() },
+    ///         Facet::exclude(Facet::Other { key: "partof".to_string(), value: "web archives".to_string() }),
+    ///     ],
+    /// };
+    /// assert_eq!(filter.to_query_param(), "subject:maps|partof!:web+archives");
+    /// ```
+    pub fn exclude(facet: Facet) -> Self {
+        match facet {
+            Facet::Not { facet } => *facet,
+            facet => Facet::Not { facet: Box::new(facet) },
+        }
+    }
+
+    /// Parses a single facet as it appears in the `fa` parameter (`key:value` or `key!:value`),
+    /// turning `+` in the value back into spaces. Returns `None` if there is no `:` separator.
+    pub(crate) fn from_query_value(facet: &str) -> Option<Facet> {
+        let (key, value) = facet.split_once(':')?;
+        let (key, negated) = match key.strip_suffix('!') {
+            Some(key) => (key, true),
+            None => (key, false),
+        };
+        let value = value.replace('+', " ");
+
+        let facet = match key {
+            "access-restricted" => match value.parse() {
+                Ok(value) => Facet::AccessRestricted { value },
+                Err(_) => Facet::Other { key: key.to_string(), value },
+            },
+            "contributor" => Facet::Contributor { value },
+            "language" => Facet::Language { value },
+            "subject" => Facet::Subject { value },
+            "location" => Facet::Location { value },
+            "online-format" => Facet::OnlineFormat { value },
+            _ => Facet::Other { key: key.to_string(), value },
+        };
+        Some(if negated { Facet::exclude(facet) } else { facet })
+    }
+
+    /// Returns the facet key and value, with spaces in the value replaced by `+`.
+    fn key_value(&self) -> (&str, String) {
+        match self {
+            Facet::AccessRestricted { value } => ("access-restricted", value.to_string()),
+            Facet::Contributor { value } => ("contributor", value.replace(" ", "+")),
+            Facet::Language { value } => ("language", value.replace(" ", "+")),
+            Facet::Subject { value } => ("subject", value.replace(" ", "+")),
+            Facet::Location { value } => ("location", value.replace(" ", "+")),
+            Facet::OnlineFormat { value } => ("online-format", value.replace(" ", "+")),
+            Facet::Other { key, value } => (key.as_str(), value.replace(" ", "+")),
+            Facet::Not { facet } => facet.key_value(),
+        }
+    }
+}
+
+impl std::fmt::Display for Facet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (key, value) = self.key_value();
+        match self {
+            Facet::Not { .. } => write!(f, "{}!:{}", key, value),
+            _ => write!(f, "{}:{}", key, value),
+        }
+    }
+}
+
+impl std::str::FromStr for Facet {
+    type Err = ParseFacetError;
+
+    /// Parses a facet written as in the `fa` parameter, e.g. `subject:baseball` or
+    /// `partof!:web+archives`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::param_models::Facet;
+    ///
+    /// let facet: Facet = "location:new york".parse().unwrap();
+    /// assert_eq!(facet.to_string(), "location:new+york");
+    /// assert!("baseball".parse::<Facet>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Facet::from_query_value(s.trim()).ok_or_else(|| ParseFacetError { input: s.to_string() })
+    }
+}
+
+/// The error returned when parsing a [`Facet`] from a string without a `:` separator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFacetError {
+    /// The input that could not be parsed.
+    pub input: String,
+}
+
+impl std::fmt::Display for ParseFacetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not a facet, expected `key:value` or `key!:value`: {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ParseFacetError {}
+
+/// Languages accepted by the `language` facet, mapped from ISO 639 codes to the
+/// vocabulary loc.gov uses in its facet values.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::param_models::{Facet, FacetReq, Language};
+///
+/// let spanish = Language::from_iso("es").unwrap();
+/// assert_eq!(spanish, Language::Spanish);
+/// assert_eq!(Language::from_iso("ger"), Some(Language::German));
+///
+/// let filter = FacetReq { filters: vec![Facet::from(spanish)] };
+/// assert_eq!(filter.to_query_param(), "language:spanish");
+///
+/// // Languages without a dedicated variant can still be expressed.
+/// let welsh: Language = "welsh".parse().unwrap();
+/// assert_eq!(welsh.facet_value(), "welsh");
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Language {
+    /// Arabic (`ar, ara`).
+    Arabic,
+    /// Chinese (`zh, chi, zho`).
+    Chinese,
+    /// Czech (`cs, cze, ces`).
+    Czech,
+    /// Danish (`da, dan`).
+    Danish,
+    /// Dutch (`nl, dut, nld`).
+    Dutch,
+    /// English (`en, eng`).
+    English,
+    /// French (`fr, fre, fra`).
+    French,
+    /// German (`de, ger, deu`).
+    German,
+    /// Greek (`el, gre, ell`).
+    Greek,
+    /// Hebrew (`he, heb`).
+    Hebrew,
+    /// Hungarian (`hu, hun`).
+    Hungarian,
+    /// Italian (`it, ita`).
+    Italian,
+    /// Japanese (`ja, jpn`).
+    Japanese,
+    /// Korean (`ko, kor`).
+    Korean,
+    /// Latin (`la, lat`).
+    Latin,
+    /// Norwegian (`no, nor`).
+    Norwegian,
+    /// Polish (`pl, pol`).
+    Polish,
+    /// Portuguese (`pt, por`).
+    Portuguese,
+    /// Russian (`ru, rus`).
+    Russian,
+    /// Spanish (`es, spa`).
+    Spanish,
+    /// Swedish (`sv, swe`).
+    Swedish,
+    /// Ukrainian (`uk, ukr`).
+    Ukrainian,
+    /// Yiddish (`yi, yid`).
+    Yiddish,
+    /// Any other language, given as the facet value loc.gov uses (e.g. `"welsh"`).
+    Other(String),
+}
+
+impl Language {
+    /// Looks up a language by its ISO 639-1 (`"en"`) or ISO 639-2 (`"eng"`) code, ignoring case.
+    pub fn from_iso(code: &str) -> Option<Self> {
+        let language = match code.trim().to_ascii_lowercase().as_str() {
+            "ar" | "ara" => Language::Arabic,
+            "zh" | "chi" | "zho" => Language::Chinese,
+            "cs" | "cze" | "ces" => Language::Czech,
+            "da" | "dan" => Language::Danish,
+            "nl" | "dut" | "nld" => Language::Dutch,
+            "en" | "eng" => Language::English,
+            "fr" | "fre" | "fra" => Language::French,
+            "de" | "ger" | "deu" => Language::German,
+            "el" | "gre" | "ell" => Language::Greek,
+            "he" | "heb" => Language::Hebrew,
+            "hu" | "hun" => Language::Hungarian,
+            "it" | "ita" => Language::Italian,
+            "ja" | "jpn" => Language::Japanese,
+            "ko" | "kor" => Language::Korean,
+            "la" | "lat" => Language::Latin,
+            "no" | "nor" => Language::Norwegian,
+            "pl" | "pol" => Language::Polish,
+            "pt" | "por" => Language::Portuguese,
+            "ru" | "rus" => Language::Russian,
+            "es" | "spa" => Language::Spanish,
+            "sv" | "swe" => Language::Swedish,
+            "uk" | "ukr" => Language::Ukrainian,
+            "yi" | "yid" => Language::Yiddish,
+            _ => return None,
+        };
+        Some(language)
+    }
+
+    /// Returns the value loc.gov expects in the `language` facet (e.g. `"english"`).
+    pub fn facet_value(&self) -> &str {
         match self {
-            Facet::AccessRestricted { value } => format!("access_restricted:{}", value),
-            Facet::Contributor { value } => format!("contributor:{}", value.replace(" ", "+")),
-            Facet::Language { value } => format!("language:{}", value.replace(" ", "+")),
-            Facet::Subject { value } => format!("subject:{}", value.replace(" ", "+")),
-            Facet::Location { value } => format!("location:{}", value.replace(" ", "+")),
-            Facet::OnlineFormat { value } => format!("online-format:{}", value.replace(" ", "+")),
-            Facet::Other { key, value } => format!("{}:{}", key, value.replace(" ", "+")),
+            Language::Arabic => "arabic",
+            Language::Chinese => "chinese",
+            Language::Czech => "czech",
+            Language::Danish => "danish",
+            Language::Dutch => "dutch",
+            Language::English => "english",
+            Language::French => "french",
+            Language::German => "german",
+            Language::Greek => "greek",
+            Language::Hebrew => "hebrew",
+            Language::Hungarian => "hungarian",
+            Language::Italian => "italian",
+            Language::Japanese => "japanese",
+            Language::Korean => "korean",
+            Language::Latin => "latin",
+            Language::Norwegian => "norwegian",
+            Language::Polish => "polish",
+            Language::Portuguese => "portuguese",
+            Language::Russian => "russian",
+            Language::Spanish => "spanish",
+            Language::Swedish => "swedish",
+            Language::Ukrainian => "ukrainian",
+            Language::Yiddish => "yiddish",
+            Language::Other(value) => value,
+        }
+    }
+
+    /// Returns the ISO 639-1 code of the language, if it has a dedicated variant.
+    pub fn iso_639_1(&self) -> Option<&'static str> {
+        let code = match self {
+            Language::Arabic => "ar",
+            Language::Chinese => "zh",
+            Language::Czech => "cs",
+            Language::Danish => "da",
+            Language::Dutch => "nl",
+            Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Greek => "el",
+            Language::Hebrew => "he",
+            Language::Hungarian => "hu",
+            Language::Italian => "it",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::Latin => "la",
+            Language::Norwegian => "no",
+            Language::Polish => "pl",
+            Language::Portuguese => "pt",
+            Language::Russian => "ru",
+            Language::Spanish => "es",
+            Language::Swedish => "sv",
+            Language::Ukrainian => "uk",
+            Language::Yiddish => "yi",
+            Language::Other(_) => return None,
+        };
+        Some(code)
+    }
+}
+
+impl std::str::FromStr for Language {
+    type Err = std::convert::Infallible;
+
+    /// Parses an ISO 639 code or a loc.gov facet value; unknown values become [`Language::Other`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(language) = Language::from_iso(s) {
+            return Ok(language);
         }
+
+        let value = s.trim().to_lowercase();
+        let language = [
+            Language::Arabic,
+            Language::Chinese,
+            Language::Czech,
+            Language::Danish,
+            Language::Dutch,
+            Language::English,
+            Language::French,
+            Language::German,
+            Language::Greek,
+            Language::Hebrew,
+            Language::Hungarian,
+            Language::Italian,
+            Language::Japanese,
+            Language::Korean,
+            Language::Latin,
+            Language::Norwegian,
+            Language::Polish,
+            Language::Portuguese,
+            Language::Russian,
+            Language::Spanish,
+            Language::Swedish,
+            Language::Ukrainian,
+            Language::Yiddish,
+        ]
+        .into_iter()
+        .find(|language| language.facet_value() == value)
+        .unwrap_or(Language::Other(value));
+        Ok(language)
+    }
+}
+
+impl From<Language> for Facet {
+    fn from(language: Language) -> Self {
+        Facet::Language { value: language.facet_value().to_string() }
+    }
+}
+
+/// A keyword query for the `q` parameter, built from terms, quoted phrases and boolean groups.
+///
+/// [`Display`](std::fmt::Display) renders the query as typed into the loc.gov search box, while
+/// [`Query::to_query_param`] renders it URL-encoded for use in [`CommonParams::query`].
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::param_models::Query;
+///
+/// let query = Query::all_of([
+///     Query::phrase("declaration of independence"),
+///     Query::any_of(["jefferson", "adams"]),
+/// ]);
+///
+/// assert_eq!(query.to_string(), r#""declaration of independence" AND (jefferson OR adams)"#);
+/// assert_eq!(
+///     query.to_query_param(),
+///     "%22declaration+of+independence%22+AND+%28jefferson+OR+adams%29"
+/// );
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Query {
+    /// One or more bare keywords, matched anywhere in the metadata or full text.
+    Term(String),
+    /// An exact phrase, rendered in double quotes.
+    Phrase(String),
+    /// All of the sub-queries must match (`AND`).
+    AllOf(Vec<Query>),
+    /// Any of the sub-queries may match (`OR`).
+    AnyOf(Vec<Query>),
+    /// The sub-query must not match (`NOT`).
+    Not(Box<Query>),
+}
+
+/// Alias of [`Query`] for building searches with the combinator DSL.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::param_models::SearchQuery;
+///
+/// let query = SearchQuery::term("lincoln")
+///     .and(SearchQuery::phrase("civil war"))
+///     .not(SearchQuery::term("reenactment"));
+///
+/// assert_eq!(query.to_string(), r#"lincoln AND "civil war" AND NOT reenactment"#);
+///
+/// // The same query can be passed to any endpoint taking a `q` parameter.
+/// let q = query.to_query_param();
+/// assert_eq!(q, "lincoln+AND+%22civil+war%22+AND+NOT+reenactment");
+/// ```
+pub type SearchQuery = Query;
+
+impl Query {
+    /// Creates a bare keyword query.
+    pub fn term(term: impl Into<String>) -> Self {
+        Query::Term(term.into())
+    }
+
+    /// Creates an exact phrase query. Double quotes inside `phrase` are dropped.
+    pub fn phrase(phrase: impl Into<String>) -> Self {
+        Query::Phrase(phrase.into().replace('"', ""))
+    }
+
+    /// Creates a query matching only results that match every sub-query.
+    pub fn all_of<I, Q>(queries: I) -> Self
+    where
+        I: IntoIterator<Item = Q>,
+        Q: Into<Query>,
+    {
+        Query::AllOf(queries.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a query matching results that match at least one sub-query.
+    pub fn any_of<I, Q>(queries: I) -> Self
+    where
+        I: IntoIterator<Item = Q>,
+        Q: Into<Query>,
+    {
+        Query::AnyOf(queries.into_iter().map(Into::into).collect())
+    }
+
+    /// Combines `self` and `other` so that both must match.
+    ///
+    /// Chained calls extend the same `AND` group instead of nesting.
+    pub fn and(self, other: impl Into<Query>) -> Self {
+        match self {
+            Query::AllOf(mut queries) => {
+                queries.push(other.into());
+                Query::AllOf(queries)
+            }
+            query => Query::AllOf(vec![query, other.into()]),
+        }
+    }
+
+    /// Combines `self` and `other` so that either may match.
+    ///
+    /// Chained calls extend the same `OR` group instead of nesting.
+    pub fn or(self, other: impl Into<Query>) -> Self {
+        match self {
+            Query::AnyOf(mut queries) => {
+                queries.push(other.into());
+                Query::AnyOf(queries)
+            }
+            query => Query::AnyOf(vec![query, other.into()]),
+        }
+    }
+
+    /// Requires `self` to match and `other` not to match (`self AND NOT other`).
+    pub fn not(self, other: impl Into<Query>) -> Self {
+        self.and(Query::Not(Box::new(other.into())))
+    }
+
+    /// Converts the [`Query`] into a URL-encoded value for the `q` parameter.
+    ///
+    /// Spaces become `+` and every character outside the unreserved set is percent-encoded.
+    pub fn to_query_param(&self) -> String {
+        let mut encoded = String::new();
+        for byte in self.to_string().bytes() {
+            match byte {
+                b' ' => encoded.push('+'),
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+                _ => encoded.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+        encoded
+    }
+
+    /// Renders the query, wrapping boolean groups of more than one sub-query in parentheses.
+    fn fmt_nested(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Query::AllOf(queries) | Query::AnyOf(queries) if queries.len() > 1 => write!(f, "({})", self),
+            _ => write!(f, "{}", self),
+        }
+    }
+}
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (queries, operator) = match self {
+            Query::Term(term) => return write!(f, "{}", term.trim()),
+            Query::Phrase(phrase) => return write!(f, "\"{}\"", phrase.trim()),
+            Query::Not(query) => {
+                f.write_str("NOT ")?;
+                return query.fmt_nested(f);
+            }
+            Query::AllOf(queries) => (queries, " AND "),
+            Query::AnyOf(queries) => (queries, " OR "),
+        };
+
+        for (i, query) in queries.iter().enumerate() {
+            if i > 0 {
+                f.write_str(operator)?;
+            }
+            query.fmt_nested(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for Query {
+    fn from(term: &str) -> Self {
+        Query::Term(term.to_string())
+    }
+}
+
+impl From<String> for Query {
+    fn from(term: String) -> Self {
+        Query::Term(term)
     }
 }
 
@@ -120,6 +786,7 @@ impl Facet {
 ///
 /// **Note**: This enum can be expanded to include more variants as needed.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum QueryParam {
     /// Common query parameters.
     Common(CommonParams),
@@ -0,0 +1,263 @@
+//! # Pool Module
+//!
+//! Multi-threaded use of the blocking client: a [`ClientPool`] spreads a batch of endpoint
+//! requests over a fixed number of worker threads and returns the results in input order.
+//!
+//! The workers share clones of one [`ApiClient`], and with it the client's connection pool,
+//! cache and [`throttle`](crate::throttle). A pool can also carry its own rate limit, shared by
+//! all workers, when the client was built without one.
+//!
+//! Transient failures can be retried with [`ClientPool::retries`]. For the common case of
+//! fetching a list of items, [`ClientPool::fetch_items`] (and [`ApiClient::get_items_bulk`], built
+//! on it) returns a [`BulkItems`] report with per-ID results and aggregate [`BulkStats`].
+
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+
+use crate::endpoints::Endpoints;
+use crate::identifiers::ItemId;
+use crate::loc_client::{ApiClient, HttpStatusError};
+use crate::response_models::{from_slice, ItemResponse};
+use crate::throttle::{Throttle, ThrottleConfig};
+
+/// Result of one request of [`ClientPool::fetch_all`]: the deserialized body and the final URL.
+pub type PoolResult<T> = Result<(T, String), PoolError>;
+
+/// Result of one request of [`ClientPool::fetch_items`], with the number of retries made.
+type ItemFetch = (Result<ItemResponse, PoolError>, u32);
+
+/// Sends batches of endpoint requests through an [`ApiClient`] on several threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::endpoints::Endpoints;
+/// use loc_api::loc_client::ApiClient;
+/// use loc_api::param_models::ItemParams;
+/// use loc_api::pool::ClientPool;
+/// use loc_api::response_models::ItemResponse;
+///
+/// let client = ApiClient::offline();
+/// let endpoints: Vec<Endpoints> = ["2014717546", "2007683735"]
+///     .iter()
+///     .map(|id| Endpoints::Item { item_id: id.to_string(), params: ItemParams::default() })
+///     .collect();
+///
+/// let results = ClientPool::new(&client).workers(2).fetch_all::<ItemResponse>(&endpoints);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub struct ClientPool {
+    client: ApiClient,
+    workers: usize,
+    throttle: Option<Throttle>,
+    retries: Option<u32>,
+}
+
+impl ClientPool {
+    /// Creates a [`ClientPool`] sending its requests through clones of `client`.
+    ///
+    /// Defaults to 4 workers, the client's [`retries`](crate::loc_client::ApiClientBuilder::retries)
+    /// and no rate limit besides the client's own.
+    pub fn new(client: &ApiClient) -> Self {
+        ClientPool { client: client.clone(), workers: 4, throttle: None, retries: None }
+    }
+
+    /// Sets the number of worker threads.
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Rate limits the requests of all workers together, in addition to the client's
+    /// [`throttle`](crate::loc_client::ApiClientBuilder::throttle).
+    pub fn throttle(mut self, config: ThrottleConfig) -> Self {
+        self.throttle = Some(Throttle::new(config));
+        self
+    }
+
+    /// Retries transient failures (transport errors, HTTP 429 and 5xx) up to `retries` times
+    /// per request, overriding the client's setting, with the client's
+    /// [`retry_backoff`](crate::loc_client::ApiClientBuilder::retry_backoff).
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Requests every endpoint and deserializes the JSON bodies into `T`, like
+    /// [`ApiClient::fetch`].
+    ///
+    /// # Returns
+    ///
+    /// Returns one result per endpoint, in the order of `endpoints`: the deserialized body and
+    /// the final URL, or a [`PoolError`] if the request failed.
+    pub fn fetch_all<T>(&self, endpoints: &[Endpoints]) -> Vec<PoolResult<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.run(endpoints, |endpoint| self.fetch(endpoint).0)
+    }
+
+    /// Fetches the items with the given IDs.
+    ///
+    /// # Returns
+    ///
+    /// Returns a [`BulkItems`] report with one result per ID, in input order, and aggregate
+    /// [`BulkStats`], including the number of [`retries`](ClientPool::retries) made.
+    pub fn fetch_items(&self, ids: &[ItemId]) -> BulkItems {
+        let started = Instant::now();
+        let results = self.run(ids, |id| self.fetch_item(id));
+
+        let mut stats = BulkStats { requested: ids.len(), ..BulkStats::default() };
+        let results = ids
+            .iter()
+            .cloned()
+            .zip(results)
+            .map(|(id, (result, retries))| {
+                stats.retries += retries;
+                match &result {
+                    Ok(_) => stats.succeeded += 1,
+                    Err(e) => {
+                        stats.failed += 1;
+                        stats.not_found += usize::from(e.status == Some(404));
+                    }
+                }
+                (id, result)
+            })
+            .collect();
+        stats.elapsed = started.elapsed();
+        BulkItems { results, stats }
+    }
+
+    /// Runs `task` on every input on the worker threads and returns the results in input order.
+    fn run<I, R>(&self, inputs: &[I], task: impl Fn(&I) -> R + Sync) -> Vec<R>
+    where
+        I: Sync,
+        R: Send,
+    {
+        let next = AtomicUsize::new(0);
+        let results: Mutex<Vec<Option<R>>> = Mutex::new(inputs.iter().map(|_| None).collect());
+
+        thread::scope(|scope| {
+            for _ in 0..self.workers.min(inputs.len()) {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(input) = inputs.get(index) else { break };
+                    let result = task(input);
+                    results.lock().unwrap()[index] = Some(result);
+                });
+            }
+        });
+
+        results.into_inner().unwrap().into_iter().flatten().collect()
+    }
+
+    /// Helper method behind [`ClientPool::fetch_items`]: fetches one item and returns the result
+    /// with the number of retries made.
+    fn fetch_item(&self, id: &ItemId) -> ItemFetch {
+        match self.client.item_endpoint(id.clone(), None) {
+            Ok(endpoint) => {
+                let (result, retries) = self.fetch(&endpoint);
+                (result.map(|(item, _)| item), retries)
+            }
+            Err(e) => (Err(PoolError::new(id.to_string(), e.as_ref())), 0),
+        }
+    }
+
+    /// Requests one endpoint, after waiting for the pool's rate limit, and returns the result
+    /// with the number of retries made.
+    fn fetch<T: DeserializeOwned + 'static>(&self, endpoint: &Endpoints) -> (PoolResult<T>, u32) {
+        let url = self.client.url_for(endpoint);
+        if let Some(throttle) = &self.throttle {
+            let wait = throttle.acquire(&url);
+            if !wait.is_zero() {
+                self.client.metrics().rate_limited(&url, wait);
+                thread::sleep(wait);
+            }
+        }
+        let (client_retries, backoff) = self.client.retry_policy();
+        match self.client.execute_with_retry(endpoint, self.retries.unwrap_or(client_retries), backoff) {
+            Ok((mut body, meta)) => {
+                let result = match from_slice(&mut body) {
+                    Ok(json) => Ok((json, meta.final_url)),
+                    Err(e) => Err(PoolError::new(meta.final_url, &e)),
+                };
+                (result, meta.retries)
+            }
+            Err((e, attempts)) => (Err(PoolError::new(url, e.as_ref())), attempts - 1),
+        }
+    }
+}
+
+/// Error of a single request sent by a [`ClientPool`].
+///
+/// The client's errors cannot move between threads, so the pool keeps their message and, for
+/// [`HttpStatusError`]s, the status code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolError {
+    /// The URL that was requested.
+    pub url: String,
+    /// The HTTP status code, if the API answered with a non-success status.
+    pub status: Option<u16>,
+    /// The error message.
+    pub message: String,
+}
+
+impl PoolError {
+    pub(crate) fn new(url: String, error: &(dyn Error + 'static)) -> Self {
+        let status = error.downcast_ref::<HttpStatusError>().map(|e| e.status);
+        PoolError { url, status, message: error.to_string() }
+    }
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for PoolError {}
+
+/// The report of [`ClientPool::fetch_items`] and [`ApiClient::get_items_bulk`].
+#[derive(Debug)]
+pub struct BulkItems {
+    /// One result per requested ID, in input order.
+    pub results: Vec<(ItemId, Result<ItemResponse, PoolError>)>,
+    /// Aggregate counts over all requests.
+    pub stats: BulkStats,
+}
+
+impl BulkItems {
+    /// Returns the fetched items, in input order.
+    pub fn items(&self) -> impl Iterator<Item = (&ItemId, &ItemResponse)> {
+        self.results.iter().filter_map(|(id, result)| result.as_ref().ok().map(|item| (id, item)))
+    }
+
+    /// Returns the IDs that could not be fetched with their errors, in input order.
+    pub fn failures(&self) -> impl Iterator<Item = (&ItemId, &PoolError)> {
+        self.results.iter().filter_map(|(id, result)| result.as_ref().err().map(|error| (id, error)))
+    }
+}
+
+/// Aggregate counts of a [`BulkItems`] report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkStats {
+    /// The number of IDs requested.
+    pub requested: usize,
+    /// The number of items fetched.
+    pub succeeded: usize,
+    /// The number of IDs that failed, including those not found.
+    pub failed: usize,
+    /// The number of IDs the API answered `404 Not Found` for.
+    pub not_found: usize,
+    /// The number of retries of transient failures, over all requests.
+    pub retries: u32,
+    /// The wall-clock time of the whole batch.
+    pub elapsed: Duration,
+}
@@ -1,8 +1,14 @@
+use crate::identifiers::ItemId;
+use std::fmt;
 use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
 use serde_json::Value;
 
 /// Represents a value that can be either a single [`String`] or a `Vec<String>`.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum StringOrArray {
     String(String),
@@ -11,6 +17,8 @@ pub enum StringOrArray {
 
 /// Represents a value that can be either a [`u32`] or a [`String`].
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum NumberOrString {
     Number(u32),
@@ -19,30 +27,336 @@ pub enum NumberOrString {
 
 /// Represents a value that can be either a [`bool`] or a [`String`].
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum BoolOrString {
     Bool(bool),
     String(String),
 }
 
+impl StringOrArray {
+    /// Returns the string, or the first string of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::StringOrArray;
+    ///
+    /// let title: StringOrArray = serde_json::from_str(r#"["Baseball cards", "Tobacco cards"]"#).unwrap();
+    /// assert_eq!(title.first_str(), Some("Baseball cards"));
+    /// ```
+    pub fn first_str(&self) -> Option<&str> {
+        match self {
+            StringOrArray::String(s) => Some(s),
+            StringOrArray::Array(values) => values.first().map(String::as_str),
+        }
+    }
+}
+
+impl NumberOrString {
+    /// Returns the number, parsing numeric strings such as `"25"`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::NumberOrString;
+    ///
+    /// let count: NumberOrString = serde_json::from_str(r#"" 25 ""#).unwrap();
+    /// assert_eq!(count.as_u64(), Some(25));
+    /// ```
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            NumberOrString::Number(n) => Some(u64::from(*n)),
+            NumberOrString::String(s) => s.trim().parse().ok(),
+        }
+    }
+}
+
+impl BoolOrString {
+    /// Returns the flag, reading the strings `"true"` and `"false"` in any case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::BoolOrString;
+    ///
+    /// let digitized: BoolOrString = serde_json::from_str(r#""True""#).unwrap();
+    /// assert_eq!(digitized.as_bool(), Some(true));
+    /// ```
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            BoolOrString::Bool(value) => Some(*value),
+            BoolOrString::String(value) => match value.trim() {
+                v if v.eq_ignore_ascii_case("true") => Some(true),
+                v if v.eq_ignore_ascii_case("false") => Some(false),
+                _ => None,
+            },
+        }
+    }
+}
+
 /// Represents a value that can be either a single item or an array of items.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema), schemars(rename = "ItemOrArray_for_{T}"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum ItemOrArray<T> {
     Item(T),
     Array(Vec<T>),
 }
 
-/// Represents a single facet category.
+impl<T> ItemOrArray<T> {
+    /// Returns the number of items: 1 for a single item, the array length otherwise.
+    pub fn len(&self) -> usize {
+        match self {
+            ItemOrArray::Item(_) => 1,
+            ItemOrArray::Array(items) => items.len(),
+        }
+    }
+
+    /// Returns `true` for an empty array.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the first item, if any.
+    pub fn first(&self) -> Option<&T> {
+        self.as_slice().first()
+    }
+
+    /// Returns the items as a slice, of length 1 for a single item.
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            ItemOrArray::Item(item) => std::slice::from_ref(item),
+            ItemOrArray::Array(items) => items,
+        }
+    }
+
+    /// Iterates over the items, whether the API sent a single item or an array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::ItemOrArray;
+    ///
+    /// let one: ItemOrArray<String> = serde_json::from_str(r#""english""#).unwrap();
+    /// let many: ItemOrArray<String> = serde_json::from_str(r#"["english", "german"]"#).unwrap();
+    /// assert_eq!(one.iter().collect::<Vec<_>>(), ["english"]);
+    /// for language in &many {
+    ///     assert!(language.ends_with("ish") || language.ends_with("man"));
+    /// }
+    /// assert_eq!(many.map(|l| l.len()).into_iter().sum::<usize>(), 13);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Applies `f` to every item, keeping the single item or array shape.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> ItemOrArray<U> {
+        match self {
+            ItemOrArray::Item(item) => ItemOrArray::Item(f(item)),
+            ItemOrArray::Array(items) => ItemOrArray::Array(items.into_iter().map(f).collect()),
+        }
+    }
+}
+
+impl<T> IntoIterator for ItemOrArray<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            ItemOrArray::Item(item) => vec![item].into_iter(),
+            ItemOrArray::Array(items) => items.into_iter(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ItemOrArray<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Parses the first link in `value` into a [`url::Url`], see [`first_link`].
+#[cfg(feature = "url")]
+fn parse_link(value: &Option<StringOrArray>) -> Result<Option<url::Url>, ParseLinkError> {
+    let Some(link) = first_link(value) else { return Ok(None) };
+    url::Url::parse(&link).map(Some).map_err(|source| ParseLinkError { link, source })
+}
+
+/// The error returned by the `url::Url` accessors of the response models when a link is
+/// malformed.
+#[cfg(feature = "url")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLinkError {
+    /// The link that could not be parsed, with protocol-relative links resolved against `https:`.
+    pub link: String,
+    /// The underlying error.
+    pub source: url::ParseError,
+}
+
+#[cfg(feature = "url")]
+impl fmt::Display for ParseLinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "malformed link {:?}: {}", self.link, self.source)
+    }
+}
+
+#[cfg(feature = "url")]
+impl std::error::Error for ParseLinkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Parses every MIME type string in `values` into a [`mime::Mime`].
+#[cfg(feature = "mime")]
+fn parse_mimes(values: &Option<ItemOrArray<String>>) -> Result<Vec<mime::Mime>, mime::FromStrError> {
+    match values {
+        Some(ItemOrArray::Item(value)) => Ok(vec![value.parse()?]),
+        Some(ItemOrArray::Array(values)) => values.iter().map(|v| v.parse()).collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Represents a single facet category, one entry of a response's `facets` list.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FacetRes {
     /// The name of the facet field (e.g., "subject", "location").
+    #[serde(rename = "type", alias = "field", skip_serializing_if = "Option::is_none")]
+    pub type_field: Option<String>,
     /// A list of filters within the facet.
-    pub filters: Option<ItemOrArray<FilterItem>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<FilterItem>,
+    /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub additional: Option<Value>,
+}
+
+impl FacetRes {
+    /// Returns the name of the facet field (e.g., "subject", "location"), if present.
+    pub fn name(&self) -> Option<&str> {
+        self.type_field.as_deref()
+    }
+}
+
+/// Typed access to a list of facets, returned by [`SearchResultResponse::facets`].
+///
+/// Also usable on the facets returned by
+/// [`ApiClient::get_facets`](crate::loc_client::ApiClient::get_facets) through [`FacetSummary::new`].
+#[derive(Debug, Clone, Copy)]
+pub struct FacetSummary<'a> {
+    facets: &'a [FacetRes],
+}
+
+impl<'a> FacetSummary<'a> {
+    /// Summarizes `facets`.
+    pub fn new(facets: &'a [FacetRes]) -> Self {
+        FacetSummary { facets }
+    }
+
+    /// Returns the counts of the facet named `name`, empty if the response doesn't include it.
+    pub fn get(&self, name: &str) -> FacetCounts<'a> {
+        let filters = self
+            .facets
+            .iter()
+            .find(|facet| facet.name() == Some(name))
+            .map_or(&[][..], |facet| facet.filters.as_slice());
+        FacetCounts { filters }
+    }
+
+    /// Returns the counts of the `subject` facet.
+    pub fn subject(&self) -> FacetCounts<'a> {
+        self.get("subject")
+    }
+
+    /// Returns the counts of the `location` facet.
+    pub fn location(&self) -> FacetCounts<'a> {
+        self.get("location")
+    }
+
+    /// Returns the counts of the `language` facet.
+    pub fn language(&self) -> FacetCounts<'a> {
+        self.get("language")
+    }
+
+    /// Returns the counts of the `contributor` facet.
+    pub fn contributor(&self) -> FacetCounts<'a> {
+        self.get("contributor")
+    }
+
+    /// Returns the counts of the `partof` facet.
+    pub fn partof(&self) -> FacetCounts<'a> {
+        self.get("partof")
+    }
+
+    /// Returns the counts of the `original-format` facet.
+    pub fn original_format(&self) -> FacetCounts<'a> {
+        self.get("original-format")
+    }
+
+    /// Returns the counts of the `online-format` facet.
+    pub fn online_format(&self) -> FacetCounts<'a> {
+        self.get("online-format")
+    }
+}
+
+/// The filters of one facet read as `(term, count)` pairs, returned by [`FacetSummary`].
+///
+/// The term is the filter's `term`, falling back to its `title`. Counts sent as strings are
+/// parsed; filters without a term are skipped and missing counts read as `0`.
+#[derive(Debug, Clone, Copy)]
+pub struct FacetCounts<'a> {
+    filters: &'a [FilterItem],
+}
+
+impl FacetCounts<'_> {
+    /// Returns every `(term, count)` pair, by descending count and then by term.
+    pub fn all(&self) -> Vec<(String, u64)> {
+        let mut counts: Vec<(String, u64)> = self
+            .filters
+            .iter()
+            .filter_map(|filter| {
+                let term = first_string(&filter.term).or_else(|| first_string(&filter.title))?;
+                Some((term.to_string(), number(&filter.count).unwrap_or(0)))
+            })
+            .collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+
+    /// Returns the `n` pairs with the highest counts.
+    pub fn top(&self, n: usize) -> Vec<(String, u64)> {
+        let mut counts = self.all();
+        counts.truncate(n);
+        counts
+    }
+
+    /// Returns the count of `term`, if the facet lists it.
+    pub fn count(&self, term: &str) -> Option<u64> {
+        self.all().into_iter().find(|(t, _)| t == term).map(|(_, count)| count)
+    }
+
+    /// Returns `true` if the facet has no filters.
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
 }
 
 /// Represents a single filter within a [`FacetRes`].
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct FilterItem {
     /// The number of results matching this filter.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,8 +378,28 @@ pub struct FilterItem {
     pub title: Option<StringOrArray>,
 }
 
+#[cfg(feature = "url")]
+impl FilterItem {
+    /// Parses [`FilterItem::on`] into a [`url::Url`].
+    pub fn on_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.on)
+    }
+
+    /// Parses [`FilterItem::off`] into a [`url::Url`].
+    pub fn off_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.off)
+    }
+
+    /// Parses [`FilterItem::not`] into a [`url::Url`].
+    pub fn not_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.not)
+    }
+}
+
 /// Represents the pagination information in the response.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Pagination {
     /// Index number of the first result item on the current page.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -108,8 +442,46 @@ pub struct Pagination {
     pub first: Option<StringOrArray>,
 }
 
+#[cfg(feature = "url")]
+impl Pagination {
+    /// Parses [`Pagination::next`] into a [`url::Url`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::Pagination;
+    ///
+    /// let pagination: Pagination = serde_json::from_str(
+    ///     r#"{"next": "https://www.loc.gov/search/?q=baseball&sp=2&fo=json"}"#,
+    /// ).unwrap();
+    ///
+    /// let next = pagination.next_url().unwrap().unwrap();
+    /// assert!(next.query_pairs().any(|(k, v)| k == "sp" && v == "2"));
+    /// ```
+    pub fn next_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.next)
+    }
+
+    /// Parses [`Pagination::previous`] into a [`url::Url`].
+    pub fn previous_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.previous)
+    }
+
+    /// Parses [`Pagination::first`] into a [`url::Url`].
+    pub fn first_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.first)
+    }
+
+    /// Parses [`Pagination::last`] into a [`url::Url`].
+    pub fn last_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.last)
+    }
+}
+
 /// Represents a single page in the pagination list.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PageListItem {
     /// URL of the page, if available.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -119,8 +491,63 @@ pub struct PageListItem {
     pub number: Option<NumberOrString>,
 }
 
+#[cfg(feature = "url")]
+impl PageListItem {
+    /// Parses [`PageListItem::url`] into a [`url::Url`].
+    pub fn parsed_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.url)
+    }
+}
+
+/// A result from the `/newspapers/` endpoint, with the issue-specific fields typed.
+///
+/// The fields shared with every other format stay available through
+/// [`NewspaperResult::result`].
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::NewspaperResult;
+///
+/// let json = r#"{"date": "1914-07-28", "number_edition": ["1"], "location_state": ["district of columbia"]}"#;
+/// let result: NewspaperResult = serde_json::from_str(json).unwrap();
+/// assert!(result.location_state.is_some());
+/// assert!(result.result.date.is_some());
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct NewspaperResult {
+    /// Edition of the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_edition: Option<StringOrArray>,
+    /// Page number within the issue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_page: Option<StringOrArray>,
+    /// LCCN of the newspaper title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_lccn: Option<StringOrArray>,
+    /// Title of the newspaper the issue is part of.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partof_title: Option<StringOrArray>,
+    /// URL of the newspaper title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_url: Option<StringOrArray>,
+    /// State the newspaper was published in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_state: Option<StringOrArray>,
+    /// City the newspaper was published in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_city: Option<StringOrArray>,
+    /// The fields shared with every other format, such as the issue `date`.
+    #[serde(flatten)]
+    pub result: ResultItem,
+}
+
 /// Represents a single item in the search results.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ResultItem {
     /// Indicates if access to the item is restricted.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,6 +597,9 @@ pub struct ResultItem {
     /// Languages associated with the item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<ItemOrArray<String>>,
+    /// Latitude and longitude of the item, as decimal degrees separated by a comma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latlong: Option<StringOrArray>,
     /// Locations related to the item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<ItemOrArray<String>>,
@@ -203,17 +633,177 @@ pub struct ResultItem {
     /// Subjects associated with the item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subject: Option<ItemOrArray<String>>,
-    /// Type of the item (e.g., "web page").
+    /// Title of the item.
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<StringOrArray>,
+    /// Type of the item (e.g., "web page").
+    #[serde(rename = "type", alias = "type_field", skip_serializing_if = "Option::is_none")]
     pub type_field: Option<StringOrArray>, // Updated to handle multiple types
+    /// URL of the item's page on loc.gov.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<StringOrArray>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+impl ResultItem {
+    /// Returns the [`ItemId`] of this result, parsed from [`ResultItem::id`], so it can be passed
+    /// straight to [`ApiClient::get_item`](crate::loc_client::ApiClient::get_item).
+    ///
+    /// Returns `None` if the result has no ID or its ID is not an item URL, e.g. for collections
+    /// and web pages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::ResultItem;
+    ///
+    /// let result: ResultItem = serde_json::from_str(r#"{"id": "http://www.loc.gov/item/2014717546/"}"#).unwrap();
+    /// assert_eq!(result.item_id().unwrap().as_str(), "2014717546");
+    /// ```
+    pub fn item_id(&self) -> Option<ItemId> {
+        ItemId::parse(self.id.as_ref()?.first_str()?).ok()
+    }
+
+    /// Returns the smallest [`ResultItem::image_url`] that is at least `min_width` pixels wide,
+    /// or the widest one if none is wide enough.
+    ///
+    /// Widths are read from the `#h=..&w=..` fragment loc.gov appends to image URLs, falling back
+    /// to a `_{width}px` file name suffix. URLs without a known width are only returned if no URL
+    /// has one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::{ImageSize, ResultItem};
+    ///
+    /// let result: ResultItem = serde_json::from_str(r#"{"image_url": [
+    ///     "https://tile.loc.gov/image-services/iiif/service:pnp:bbc:0000:0001f/full/pct:6.25/0/default.jpg#h=150&w=96",
+    ///     "https://tile.loc.gov/image-services/iiif/service:pnp:bbc:0000:0001f/full/pct:50/0/default.jpg#h=1200&w=768"
+    /// ]}"#).unwrap();
+    /// assert!(result.image_url_for(500).unwrap().contains("pct:50"));
+    /// assert!(result.image_url_size(ImageSize::Thumb).unwrap().contains("pct:6.25"));
+    /// ```
+    pub fn image_url_for(&self, min_width: u32) -> Option<&str> {
+        let urls: Vec<&String> = self.image_url.as_ref()?.iter().collect();
+
+        let sized = urls.iter().filter_map(|url| Some((image_width(url)?, url.as_str())));
+        let large_enough = sized.clone().filter(|(width, _)| *width >= min_width).min_by_key(|(width, _)| *width);
+        large_enough
+            .or_else(|| sized.max_by_key(|(width, _)| *width))
+            .map(|(_, url)| url)
+            .or_else(|| urls.first().map(|url| url.as_str()))
+    }
+
+    /// Returns the [`ResultItem::image_url`] best matching `size`, see [`ResultItem::image_url_for`].
+    pub fn image_url_size(&self, size: ImageSize) -> Option<&str> {
+        self.image_url_for(size.min_width())
+    }
+}
+
+/// Common image sizes for [`ResultItem::image_url_size`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSize {
+    /// The narrowest image available, for thumbnails.
+    Thumb,
+    /// A medium image, at least 640 pixels wide.
+    Medium,
+    /// A large image, at least 1024 pixels wide.
+    Large,
+    /// The widest image available.
+    Full,
+}
+
+impl ImageSize {
+    /// Returns the minimum width in pixels for this size.
+    pub fn min_width(&self) -> u32 {
+        match self {
+            ImageSize::Thumb => 0,
+            ImageSize::Medium => 640,
+            ImageSize::Large => 1024,
+            ImageSize::Full => u32::MAX,
+        }
+    }
+}
+
+/// Reads the width of an image URL from its `w=` fragment parameter or `_{width}px` suffix.
+fn image_width(url: &str) -> Option<u32> {
+    let (path, fragment) = url.split_once('#').unwrap_or((url, ""));
+    let from_fragment = fragment
+        .split('&')
+        .find_map(|param| param.strip_prefix("w="))
+        .and_then(|width| width.parse().ok());
+
+    from_fragment.or_else(|| {
+        let stem = path.rsplit('/').next()?.split('.').next()?;
+        stem.rsplit('_').next()?.strip_suffix("px")?.parse().ok()
+    })
+}
+
+#[cfg(feature = "mime")]
+impl ResultItem {
+    /// Parses [`ResultItem::mime_type`] into [`mime::Mime`] values.
+    pub fn mime_types(&self) -> Result<Vec<mime::Mime>, mime::FromStrError> {
+        parse_mimes(&self.mime_type)
+    }
+}
+
+/// The id, title, date and URL of a search result, all a typical result list needs.
+///
+/// Built from a [`ResultItem`] or [`CollectionItem`] with [`From`], or for a whole page with
+/// e.g. [`SearchResultResponse::briefs`]. Fields missing from the result are `None`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ItemBrief {
+    /// Unique identifier of the result, usually its loc.gov URL.
+    pub id: Option<String>,
+    /// Title of the result.
+    pub title: Option<String>,
+    /// Date of the result.
+    pub date: Option<String>,
+    /// URL of the result's page on loc.gov.
+    pub url: Option<String>,
+}
+
+impl From<&ResultItem> for ItemBrief {
+    fn from(item: &ResultItem) -> Self {
+        ItemBrief {
+            id: first_string(&item.id).map(str::to_string),
+            title: first_string(&item.title).map(str::to_string),
+            date: first_string(&item.date).map(str::to_string),
+            url: first_string(&item.url).map(str::to_string),
+        }
+    }
+}
+
+impl From<&CollectionItem> for ItemBrief {
+    fn from(item: &CollectionItem) -> Self {
+        ItemBrief {
+            id: first_string(&item.id).map(str::to_string),
+            title: first_string(&item.title).map(str::to_string),
+            date: first_string(&item.created_at).map(str::to_string),
+            url: first_string(&item.url).map(str::to_string),
+        }
+    }
+}
+
+/// Converts a page of results into [`ItemBrief`]s.
+fn briefs<'a, T: 'a>(results: &'a Option<Vec<T>>) -> Vec<ItemBrief>
+where
+    ItemBrief: From<&'a T>,
+{
+    results.iter().flatten().map(ItemBrief::from).collect()
+}
+
 /// Represents the summary information of an item in the search results.
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ItemSummary {
     /// Call numbers associated with the item.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -265,21 +855,31 @@ pub struct ItemSummary {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<StringOrArray>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
-/// Represents the response from the `/item/{item_id}/` endpoint.
+/// The shared shape of the `/item/{item_id}/` and `/resource/{resource_id}/` responses.
+///
+/// The two endpoints return the same fields and differ only in the type of
+/// [`ItemResourceResponse::resource`], so code that post-processes either response can be
+/// written once, generic over `R`. Use the [`ItemResponse`] and [`ResourceResponse`] aliases
+/// for the concrete responses.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct ItemResponse {
-    /// Various views available for the item.
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ItemResourceResponse<R> {
+    /// Various views available for the item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::values))]
     pub views: Option<ItemOrArray<Value>>,
-    /// Timestamp indicating when the item was indexed.
+    /// Timestamp indicating when the item or resource was indexed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<NumberOrString>,
-    /// Locations associated with the item.
+    /// Locations associated with the item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locations: Option<ItemOrArray<String>>,
     /// URL to the full-text service.
@@ -294,19 +894,19 @@ pub struct ItemResponse {
     /// URL to the title page.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title_url: Option<StringOrArray>,
-    /// Pages associated with the item.
+    /// Pages associated with the item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<ItemOrArray<Page>>,
     /// Pagination details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<ItemOrArray<Pagination>>,
-    /// Resource details associated with the item.
+    /// Resource details: raw JSON for items, [`ResourceDetail`] for resources.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource: Option<ItemOrArray<Value>>,
+    pub resource: Option<ItemOrArray<R>>,
     /// Citation information in various formats.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cite_this: Option<ItemOrArray<CiteThis>>,
-    /// Calendar URL related to the item.
+    /// Calendar URL related to the item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub calendar_url: Option<StringOrArray>,
     /// Information about the previous issue.
@@ -315,19 +915,20 @@ pub struct ItemResponse {
     /// Segments within the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub segments: Option<ItemOrArray<Segment>>,
-    /// Related items to this item.
+    /// Related items to this item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub related_items: Option<ItemOrArray<RelatedItem>>,
     /// Query details for word coordinates.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::values))]
     pub word_coordinates_query: Option<ItemOrArray<Value>>,
     /// "More like this" recommendations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub more_like_this: Option<ItemOrArray<MoreLikeThis>>,
-    /// Articles and essays related to the item.
+    /// Articles and essays related to the item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub articles_and_essays: Option<ItemOrArray<String>>,
-    /// Traditional knowledge labels associated with the item.
+    /// Traditional knowledge labels associated with the item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub traditional_knowledge_labels: Option<ItemOrArray<String>>,
     /// Detailed bibliographic information.
@@ -335,105 +936,399 @@ pub struct ItemResponse {
     pub item: Option<ItemOrArray<ItemAttribute>>,
     /// Word coordinates on pages.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::values))]
     pub word_coordinates_pages: Option<ItemOrArray<Value>>,
-    /// Type of the response (e.g., "Item").
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Type of the response (e.g., "Item" or "Resource").
+    #[serde(rename = "type", alias = "type_field", skip_serializing_if = "Option::is_none")]
     pub type_field: Option<StringOrArray>, // Updated to handle multiple types
-    /// Additional options or metadata.
+    /// The request options the server applied, including its defaults.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub options: Option<ItemOrArray<Value>>,
-    /// Resources associated with the item.
+    pub options: Option<ItemOrArray<ResponseOptions>>,
+    /// Resources associated with the item or resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resources: Option<ItemOrArray<ResourceObject>>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
-/// Represents the response from the `/resource/{resource_id}/` endpoint.
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct ResourceResponse {
-    /// Various views available for the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub views: Option<ItemOrArray<Value>>,
-    /// Timestamp indicating when the resource was indexed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<NumberOrString>,
-    /// Locations associated with the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub locations: Option<ItemOrArray<String>>,
-    /// URL to the full-text service.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fulltext_service: Option<StringOrArray>,
-    /// Information about the next issue.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_issue: Option<StringOrArray>,
-    /// URL to newspaper holdings.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub newspaper_holdings_url: Option<StringOrArray>,
-    /// URL to the title page.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title_url: Option<StringOrArray>,
-    /// Pages associated with the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub page: Option<ItemOrArray<Page>>,
-    /// Pagination details.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pagination: Option<ItemOrArray<Pagination>>,
-    /// Detailed information about the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource: Option<ItemOrArray<ResourceDetail>>,
-    /// Citation information in various formats.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cite_this: Option<ItemOrArray<CiteThis>>,
-    /// Calendar URL related to the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub calendar_url: Option<StringOrArray>,
-    /// Information about the previous issue.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub previous_issue: Option<StringOrArray>,
-    /// Segments within the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub segments: Option<ItemOrArray<Segment>>,
-    /// Related items to this resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub related_items: Option<ItemOrArray<RelatedItem>>,
-    /// Query details for word coordinates.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub word_coordinates_query: Option<ItemOrArray<Value>>,
-    /// "More like this" recommendations.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub more_like_this: Option<ItemOrArray<MoreLikeThis>>,
-    /// Articles and essays related to the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub articles_and_essays: Option<ItemOrArray<String>>,
-    /// Traditional knowledge labels associated with the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub traditional_knowledge_labels: Option<ItemOrArray<String>>,
-    /// Detailed bibliographic information.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub item: Option<ItemOrArray<ItemAttribute>>,
-    /// Word coordinates on pages.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub word_coordinates_pages: Option<ItemOrArray<Value>>,
-    /// Type of the response (e.g., "Resource").
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_field: Option<StringOrArray>, // Updated to handle multiple types
-    /// Additional options or metadata.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub options: Option<ItemOrArray<Value>>,
-    /// Resources associated with the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resources: Option<ItemOrArray<ResourceObject>>,
-    /// Captures any additional fields not explicitly defined.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(flatten)]
-    pub additional: Option<Value>,
+impl<R> ItemResourceResponse<R> {
+    /// Returns the preservation master files (TIFF and JPEG 2000) listed in
+    /// [`ItemResourceResponse::resources`], best quality first.
+    ///
+    /// Files are ranked by pixel count, then by size in bytes, with TIFF ahead of JPEG 2000 on
+    /// ties. If no resource lists a master format, the highest-resolution file of each resource
+    /// is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::ItemResponse;
+    ///
+    /// let json = r#"{"resources": [{"files": [[
+    ///     {"mimetype": "image/jpeg", "url": "https://tile.loc.gov/0001f_150px.jpg", "width": 150, "height": 96},
+    ///     {"mimetype": "image/jp2", "url": "https://tile.loc.gov/0001f.jp2", "width": 4000, "height": 2560},
+    ///     {"mimetype": "image/tiff", "url": "https://tile.loc.gov/0001f.tif", "width": 4000, "height": 2560, "size": 30720000}
+    /// ]]}]}"#;
+    /// let item: ItemResponse = serde_json::from_str(json).unwrap();
+    /// let masters = item.master_files();
+    /// assert_eq!(masters.len(), 2);
+    /// assert_eq!(masters[0].url, "https://tile.loc.gov/0001f.tif");
+    /// assert_eq!(masters[0].width, Some(4000));
+    /// ```
+    pub fn master_files(&self) -> Vec<MasterFile> {
+        let resources: Vec<&ResourceObject> = self.resources.iter().flatten().collect();
+        let files_of = |resource: &ResourceObject| -> Vec<MasterFile> {
+            resource.all_files().into_iter().filter_map(MasterFile::from_file).collect()
+        };
+
+        let mut masters: Vec<MasterFile> = resources
+            .iter()
+            .flat_map(|resource| files_of(resource))
+            .filter(MasterFile::is_master_format)
+            .collect();
+        if masters.is_empty() {
+            masters = resources
+                .iter()
+                .filter_map(|resource| files_of(resource).into_iter().max_by_key(MasterFile::quality))
+                .collect();
+        }
+
+        masters.sort_by_key(|file| std::cmp::Reverse(file.quality()));
+        masters
+    }
+}
+
+/// A high-quality file returned by [`ItemResourceResponse::master_files`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MasterFile {
+    /// URL of the file.
+    pub url: String,
+    /// MIME type of the file (e.g., "image/tiff").
+    pub mimetype: Option<String>,
+    /// Width in pixels.
+    pub width: Option<u64>,
+    /// Height in pixels.
+    pub height: Option<u64>,
+    /// Size in bytes.
+    pub size: Option<u64>,
 }
 
+impl MasterFile {
+    fn from_file(file: &File) -> Option<MasterFile> {
+        Some(MasterFile {
+            url: first_string(&file.url)?.to_string(),
+            mimetype: first_string(&file.mimetype).map(str::to_string),
+            width: number(&file.width),
+            height: number(&file.height),
+            size: number(&file.size),
+        })
+    }
+
+    fn is_master_format(&self) -> bool {
+        matches!(self.mimetype.as_deref(), Some("image/tiff" | "image/tif" | "image/jp2" | "image/jpx"))
+    }
+
+    /// Sort key: pixel count, then size, then TIFF over other formats.
+    fn quality(&self) -> (u64, u64, bool) {
+        let pixels = self.width.unwrap_or(0).saturating_mul(self.height.unwrap_or(0));
+        let is_tiff = self.mimetype.as_deref().is_some_and(|m| m.starts_with("image/tif"));
+        (pixels, self.size.unwrap_or(0), is_tiff)
+    }
+}
+
+/// Returns the first string in `value`, if any.
+pub(crate) fn first_string(value: &Option<StringOrArray>) -> Option<&str> {
+    value.as_ref()?.first_str()
+}
+
+/// Returns the first link in `value`.
+///
+/// Protocol-relative links (`//tile.loc.gov/...`), which loc.gov uses for image and tile
+/// URLs, are resolved against `https:`.
+#[cfg(any(feature = "client", feature = "url"))]
+pub(crate) fn first_link(value: &Option<StringOrArray>) -> Option<String> {
+    first_string(value).map(absolute_link)
+}
+
+/// Resolves a protocol-relative `link` against `https:`, see [`first_link`].
+fn absolute_link(link: &str) -> String {
+    match link.strip_prefix("//") {
+        Some(rest) => format!("https://{}", rest),
+        None => link.to_string(),
+    }
+}
+
+/// Reads a [`NumberOrString`] as a number, parsing numeric strings.
+pub(crate) fn number(value: &Option<NumberOrString>) -> Option<u64> {
+    value.as_ref()?.as_u64()
+}
+
+/// Returns the non-blank strings in `values`, empty if there are none.
+pub(crate) fn strings(values: &Option<ItemOrArray<String>>) -> Vec<&str> {
+    values.iter().flatten().map(String::as_str).filter(|s| !s.trim().is_empty()).collect()
+}
+
+/// Returns the non-blank strings in `value`, empty if there are none.
+pub(crate) fn all_strings(value: &Option<StringOrArray>) -> Vec<&str> {
+    let values = match value {
+        Some(StringOrArray::String(s)) => std::slice::from_ref(s),
+        Some(StringOrArray::Array(values)) => values.as_slice(),
+        None => &[],
+    };
+    values.iter().map(String::as_str).filter(|s| !s.trim().is_empty()).collect()
+}
+
+/// Writes the multi-line summary shared by the [`fmt::Display`] impls: the title and date, then
+/// the formats, subjects and URL, skipping lines without data.
+fn write_summary(
+    f: &mut fmt::Formatter<'_>,
+    title: Option<&str>,
+    date: Option<&str>,
+    formats: &[&str],
+    subjects: &[&str],
+    url: Option<&str>,
+) -> fmt::Result {
+    write!(f, "{}", title.unwrap_or("Untitled"))?;
+    if let Some(date) = date {
+        write!(f, " ({})", date)?;
+    }
+    if !formats.is_empty() {
+        write!(f, "\nFormat: {}", formats.join(", "))?;
+    }
+    if !subjects.is_empty() {
+        write!(f, "\nSubjects: {}", subjects.join(", "))?;
+    }
+    if let Some(url) = url {
+        write!(f, "\n{}", url)?;
+    }
+    Ok(())
+}
+
+/// Prints the title, date, original formats, subjects and URL of the result, one per line.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::ResultItem;
+///
+/// let result: ResultItem = serde_json::from_str(r#"{
+///     "title": "Baseball cards",
+///     "date": "1887",
+///     "original_format": ["photo, print, drawing"],
+///     "subject": ["baseball", "sports"],
+///     "url": "https://www.loc.gov/item/2014717546/"
+/// }"#).unwrap();
+/// assert_eq!(
+///     result.to_string(),
+///     "Baseball cards (1887)\nFormat: photo, print, drawing\nSubjects: baseball, sports\nhttps://www.loc.gov/item/2014717546/",
+/// );
+/// ```
+impl fmt::Display for ResultItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let url = first_string(&self.url).or_else(|| first_string(&self.id));
+        write_summary(
+            f,
+            first_string(&self.title),
+            first_string(&self.date),
+            &strings(&self.original_format),
+            &strings(&self.subject),
+            url,
+        )
+    }
+}
+
+/// Prints the title, date, original formats, subjects and URL of the item, one per line.
+impl fmt::Display for ItemAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let url = first_string(&self.url).or_else(|| first_string(&self.id));
+        write_summary(
+            f,
+            first_string(&self.title),
+            first_string(&self.date),
+            &strings(&self.original_format),
+            &strings(&self.subject),
+            url,
+        )
+    }
+}
+
+/// Prints the summary of the (first) [`ItemAttribute`] in [`ItemResourceResponse::item`], or
+/// nothing if the response has none.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::loc_client::ApiClient;
+///
+/// // Answered from bundled fixtures; use `ApiClient::new()` to query loc.gov.
+/// let client = ApiClient::offline();
+/// let (item, _) = client.get_item("2014717546", None).unwrap();
+/// assert!(item.to_string().starts_with("Baseball players practicing (1887)\nFormat: photo, print, drawing"));
+/// ```
+impl<R> fmt::Display for ItemResourceResponse<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.item.as_ref().and_then(ItemOrArray::first).map_or(Ok(()), |item| item.fmt(f))
+    }
+}
+
+/// The descriptive metadata shared by [`ItemSummary`], [`ItemAttribute`] and [`ResultItem`], so
+/// code that reads it can be written once for all three.
+///
+/// Single-valued accessors return the first value; list accessors return every value once, in
+/// order, merging the fields that hold the same kind of value (e.g. `subject_headings` and
+/// `subjects`).
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::{HasItemMetadata, ItemAttribute, ResultItem};
+///
+/// fn label(item: &impl HasItemMetadata) -> String {
+///     format!("{} ({})", item.title().unwrap_or("Untitled"), item.date().unwrap_or("n.d."))
+/// }
+///
+/// let result: ResultItem = serde_json::from_str(r#"{
+///     "title": "Baseball cards", "date": "1887",
+///     "item": {"title": "Baseball cards", "date_issued": "1887", "subject_headings": ["Baseball"]}
+/// }"#).unwrap();
+/// let item: ItemAttribute = serde_json::from_str(r#"{"title": "Baseball cards", "date": "1887"}"#).unwrap();
+/// assert_eq!(label(&result), label(&item));
+/// assert_eq!(result.subjects(), ["Baseball"]);
+/// ```
+pub trait HasItemMetadata {
+    /// The title.
+    fn title(&self) -> Option<&str>;
+    /// The date, as written by loc.gov.
+    fn date(&self) -> Option<&str>;
+    /// The subjects and subject headings.
+    fn subjects(&self) -> Vec<&str>;
+    /// The contributor names.
+    fn contributors(&self) -> Vec<&str>;
+    /// The languages.
+    fn languages(&self) -> Vec<&str>;
+    /// The summary or description.
+    fn summary(&self) -> Option<&str>;
+}
+
+impl HasItemMetadata for ItemSummary {
+    fn title(&self) -> Option<&str> {
+        first_string(&self.title)
+    }
+
+    fn date(&self) -> Option<&str> {
+        first_string(&self.date_issued)
+    }
+
+    fn subjects(&self) -> Vec<&str> {
+        unique([strings(&self.subject_headings), strings(&self.subjects)])
+    }
+
+    fn contributors(&self) -> Vec<&str> {
+        strings(&self.contributor_names)
+    }
+
+    fn languages(&self) -> Vec<&str> {
+        strings(&self.language)
+    }
+
+    fn summary(&self) -> Option<&str> {
+        first_string(&self.summary)
+    }
+}
+
+impl HasItemMetadata for ItemAttribute {
+    fn title(&self) -> Option<&str> {
+        first_string(&self.title)
+    }
+
+    fn date(&self) -> Option<&str> {
+        first_string(&self.date)
+    }
+
+    fn subjects(&self) -> Vec<&str> {
+        unique([strings(&self.subject_headings), strings(&self.subjects), strings(&self.subject)])
+    }
+
+    fn contributors(&self) -> Vec<&str> {
+        let names = strings(&self.contributor_names);
+        if names.is_empty() { strings(&self.contributors) } else { names }
+    }
+
+    fn languages(&self) -> Vec<&str> {
+        strings(&self.language)
+    }
+
+    fn summary(&self) -> Option<&str> {
+        first_string(&self.summary).or_else(|| first_string(&self.description))
+    }
+}
+
+/// Reads the embedded [`ResultItem::item`] summary, falling back to the result's own fields
+/// where the summary has no value.
+impl HasItemMetadata for ResultItem {
+    fn title(&self) -> Option<&str> {
+        self.summary_item().and_then(HasItemMetadata::title).or_else(|| first_string(&self.title))
+    }
+
+    fn date(&self) -> Option<&str> {
+        self.summary_item().and_then(HasItemMetadata::date).or_else(|| first_string(&self.date))
+    }
+
+    fn subjects(&self) -> Vec<&str> {
+        non_empty(self.summary_item().map(HasItemMetadata::subjects), || strings(&self.subject))
+    }
+
+    fn contributors(&self) -> Vec<&str> {
+        non_empty(self.summary_item().map(HasItemMetadata::contributors), || strings(&self.contributor))
+    }
+
+    fn languages(&self) -> Vec<&str> {
+        non_empty(self.summary_item().map(HasItemMetadata::languages), || strings(&self.language))
+    }
+
+    fn summary(&self) -> Option<&str> {
+        self.summary_item().and_then(HasItemMetadata::summary).or_else(|| first_string(&self.description))
+    }
+}
+
+impl ResultItem {
+    fn summary_item(&self) -> Option<&ItemSummary> {
+        self.item.as_ref().and_then(ItemOrArray::first)
+    }
+}
+
+/// Returns `values` unless missing or empty, else `fallback()`.
+fn non_empty<'a>(values: Option<Vec<&'a str>>, fallback: impl FnOnce() -> Vec<&'a str>) -> Vec<&'a str> {
+    values.filter(|values| !values.is_empty()).unwrap_or_else(fallback)
+}
+
+/// Concatenates `lists`, dropping duplicates while keeping first-seen order.
+pub(crate) fn unique<const N: usize>(lists: [Vec<&str>; N]) -> Vec<&str> {
+    let mut merged: Vec<&str> = Vec::new();
+    for value in lists.into_iter().flatten() {
+        if !merged.contains(&value) {
+            merged.push(value);
+        }
+    }
+    merged
+}
+
+/// Represents the response from the `/item/{item_id}/` endpoint.
+pub type ItemResponse = ItemResourceResponse<Value>;
+
+/// Represents the response from the `/resource/{resource_id}/` endpoint.
+pub type ResourceResponse = ItemResourceResponse<ResourceDetail>;
+
 /// Represents the detailed information about a resource.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ResourceDetail {
     /// Caption for the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -493,7 +1388,7 @@ pub struct ResourceDetail {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub representative_index: Option<NumberOrString>,
     /// Type of the resource (e.g., "audio").
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type", alias = "type_field", skip_serializing_if = "Option::is_none")]
     pub type_field: Option<StringOrArray>,
     /// URL to access the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -517,13 +1412,158 @@ pub struct ResourceDetail {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub word_coordinates: Option<StringOrArray>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+impl ResourceDetail {
+    /// Returns every file of the resource, page after page, see [`ResourceObject::all_files`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::ResourceDetail;
+    ///
+    /// let detail: ResourceDetail = serde_json::from_str(r#"{"files": [
+    ///     [{"url": "https://tile.loc.gov/0001.jpg"}, {"url": "https://tile.loc.gov/0001.tif"}],
+    ///     [{"url": "https://tile.loc.gov/0002.jpg"}]
+    /// ]}"#).unwrap();
+    /// assert_eq!(detail.all_files().len(), 3);
+    /// ```
+    pub fn all_files(&self) -> Vec<&File> {
+        self.files.iter().flatten().flatten().collect()
+    }
+}
+
+impl ResourceResponse {
+    /// Returns every playable audio/video source of the resource, preferred sources first:
+    /// adaptive streams, then video files, then audio files.
+    ///
+    /// Sources are collected from the `video_stream`, `video` and `audio` links of
+    /// [`ItemResourceResponse::resource`] and from the `streams` and audio/video `url`s of its
+    /// files. Protocol-relative links are resolved against `https:`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::ResourceResponse;
+    ///
+    /// let json = r#"{"resource": {
+    ///     "audio": "//tile.loc.gov/storage-services/media/afc/0001.mp3",
+    ///     "video_stream": "https://stream.media.loc.gov/afc/0001/master.m3u8",
+    ///     "duration": 95
+    /// }}"#;
+    /// let resource: ResourceResponse = serde_json::from_str(json).unwrap();
+    /// let sources = resource.playable_sources();
+    /// assert_eq!(sources.len(), 2);
+    /// assert_eq!(sources[1].url, "https://tile.loc.gov/storage-services/media/afc/0001.mp3");
+    /// assert_eq!(resource.best_stream().unwrap().mimetype.as_deref(), Some("application/vnd.apple.mpegurl"));
+    /// ```
+    pub fn playable_sources(&self) -> Vec<MediaSource> {
+        let details: Vec<&ResourceDetail> = self.resource.iter().flatten().collect();
+
+        let mut sources = Vec::new();
+        for detail in details {
+            let duration = number(&detail.duration);
+            for link in [&detail.video_stream, &detail.video, &detail.audio] {
+                if let Some(url) = first_string(link) {
+                    sources.push(MediaSource::new(url, None, duration));
+                }
+            }
+
+            for file in detail.all_files() {
+                let duration = number(&file.duration).or(duration);
+                let streams = match &file.streams {
+                    Some(ItemOrArray::Item(stream)) => std::slice::from_ref(stream),
+                    Some(ItemOrArray::Array(streams)) => streams.as_slice(),
+                    None => &[],
+                };
+                for stream in streams {
+                    sources.push(MediaSource::new(stream, None, duration));
+                }
+
+                let mimetype = first_string(&file.mimetype);
+                let is_media = mimetype.is_some_and(|m| m.starts_with("audio/") || m.starts_with("video/"));
+                if let (true, Some(url)) = (is_media, first_string(&file.url)) {
+                    sources.push(MediaSource::new(url, mimetype, duration));
+                }
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        sources.retain(|source| seen.insert(source.url.clone()));
+        sources.sort_by_key(MediaSource::rank);
+        sources
+    }
+
+    /// Returns the preferred playable source, see [`ResourceResponse::playable_sources`].
+    pub fn best_stream(&self) -> Option<MediaSource> {
+        self.playable_sources().into_iter().next()
+    }
+}
+
+/// A playable audio or video source returned by [`ResourceResponse::playable_sources`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct MediaSource {
+    /// Absolute URL of the source.
+    pub url: String,
+    /// MIME type of the source, as listed or inferred from the file extension.
+    pub mimetype: Option<String>,
+    /// Duration in seconds, if known.
+    pub duration: Option<u64>,
+}
+
+impl MediaSource {
+    fn new(url: &str, mimetype: Option<&str>, duration: Option<u64>) -> MediaSource {
+        let url = absolute_link(url);
+        let mimetype = mimetype.map(str::to_string).or_else(|| {
+            let path = url.split(['?', '#']).next().unwrap_or_default();
+            let extension = path.rsplit_once('.')?.1.to_lowercase();
+            let mimetype = match extension.as_str() {
+                "m3u8" => "application/vnd.apple.mpegurl",
+                "mpd" => "application/dash+xml",
+                "mp4" | "m4v" => "video/mp4",
+                "webm" => "video/webm",
+                "mp3" => "audio/mpeg",
+                "m4a" => "audio/mp4",
+                "wav" => "audio/wav",
+                "ogg" => "audio/ogg",
+                _ => return None,
+            };
+            Some(mimetype.to_string())
+        });
+
+        MediaSource { url, mimetype, duration }
+    }
+
+    /// Sort key: adaptive streams first, then video, then audio, then unknown types.
+    fn rank(&self) -> u8 {
+        match self.mimetype.as_deref() {
+            Some("application/vnd.apple.mpegurl" | "application/x-mpegurl" | "application/dash+xml") => 0,
+            Some(m) if m.starts_with("video/") => 1,
+            Some(m) if m.starts_with("audio/") => 2,
+            _ => 3,
+        }
+    }
+}
+
+#[cfg(feature = "url")]
+impl ResourceDetail {
+    /// Parses [`ResourceDetail::url`] into a [`url::Url`].
+    pub fn parsed_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.url)
+    }
+}
+
 /// Represents a single file associated with a resource.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct File {
     /// Captions associated with the file.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -533,6 +1573,7 @@ pub struct File {
     pub duration: Option<NumberOrString>,
     /// Format information of the file.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::values))]
     pub format: Option<ItemOrArray<Value>>, // Could represent IIIF, audio, or video specifics
     /// Height of the media file in pixels, if applicable.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -565,25 +1606,68 @@ pub struct File {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tiles: Option<ItemOrArray<String>>,
     /// Type of the file (e.g., "audio").
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type", alias = "type_field", skip_serializing_if = "Option::is_none")]
     pub type_field: Option<StringOrArray>,
     /// URL to access the file.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<StringOrArray>,
     /// Usage description of the file (e.g., "newspaper").
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "use", alias = "use_field", skip_serializing_if = "Option::is_none")]
     pub use_field: Option<StringOrArray>, // [`use`] is a reserved keyword in Rust
     /// Width of the media file in pixels, if applicable.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<NumberOrString>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+#[cfg(feature = "mime")]
+impl File {
+    /// Parses [`File::mimetype`] into a [`mime::Mime`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::File;
+    ///
+    /// let file: File = serde_json::from_str(r#"{"mimetype": "image/jp2"}"#).unwrap();
+    /// let mime = file.mime().unwrap().unwrap();
+    /// assert_eq!(mime.type_(), mime::IMAGE);
+    /// assert_eq!(mime.subtype(), "jp2");
+    /// ```
+    pub fn mime(&self) -> Result<Option<mime::Mime>, mime::FromStrError> {
+        self.mimetype.as_ref().and_then(StringOrArray::first_str).map(str::parse).transpose()
+    }
+}
+
+#[cfg(feature = "url")]
+impl File {
+    /// Parses [`File::url`] into a [`url::Url`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::File;
+    ///
+    /// let file: File = serde_json::from_str(r#"{"url": "//tile.loc.gov/image-services/iiif/x/full/pct:25/0/default.jpg"}"#).unwrap();
+    /// assert_eq!(file.parsed_url().unwrap().unwrap().scheme(), "https");
+    ///
+    /// let file: File = serde_json::from_str(r#"{"url": "http://[::1"}"#).unwrap();
+    /// assert_eq!(file.parsed_url().unwrap_err().link, "http://[::1");
+    /// ```
+    pub fn parsed_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.url)
+    }
+}
+
 /// Represents citation information in various formats.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CiteThis {
     /// Citation formatted in the Chicago style.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -596,44 +1680,376 @@ pub struct CiteThis {
     pub apa: Option<StringOrArray>,
 }
 
-/// Represents a segment within a resource.
+/// Represents a segment within a resource, such as a track of a recording or an article on a
+/// newspaper page.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Segment {
+    /// Identifier of the segment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<StringOrArray>,
+    /// Title of the segment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<StringOrArray>,
+    /// URL of the segment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<StringOrArray>,
+    /// Date of the segment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<StringOrArray>,
+    /// Extent of the segment, e.g. a duration or page range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extent: Option<StringOrArray>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+#[cfg(feature = "url")]
+impl Segment {
+    /// Parses [`Segment::url`] into a [`url::Url`].
+    pub fn parsed_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.url)
+    }
+}
+
 /// Represents related items to the current item/resource.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct RelatedItem {
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
 /// Represents "more like this" recommendations.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MoreLikeThis {
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
 /// Represents a single page in the response.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Page {
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub additional: Option<Value>,
+}
+
+/// Word coordinates of a single page, as served by the `word_coordinates` URL of a
+/// [`ResourceDetail`].
+///
+/// The service answers with a `{"width", "height", "coords": {word: [[x, y, w, h], ...]}}`
+/// object; boxes are in the pixel space of the page image.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::WordCoordinates;
+///
+/// let json = r#"{"width": "6000", "height": "8000", "coords": {"Baseball": [[10, 20, 300, 40]]}}"#;
+/// let coordinates: WordCoordinates = serde_json::from_str(json).unwrap();
+/// assert_eq!(coordinates.words[0].text, "Baseball");
+/// assert_eq!(coordinates.words[0].boxes[0].w, 300.0);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(from = "RawWordCoordinates", into = "RawWordCoordinates")]
+pub struct WordCoordinates {
+    /// Width of the page image in pixels.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::option_finite))]
+    pub width: Option<f64>,
+    /// Height of the page image in pixels.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::option_finite))]
+    pub height: Option<f64>,
+    /// The words on the page with their bounding boxes, sorted by text.
+    pub words: Vec<WordCoordinate>,
+}
+
+impl WordCoordinates {
+    /// Keeps only the words matching `term`, ignoring case.
+    pub fn matching(mut self, term: &str) -> Self {
+        self.words.retain(|word| word.text.eq_ignore_ascii_case(term.trim()));
+        self
+    }
+}
+
+/// A single word and every place it occurs on a page.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct WordCoordinate {
+    /// The word as recognized by OCR.
+    pub text: String,
+    /// The bounding boxes of each occurrence of the word.
+    pub boxes: Vec<WordBox>,
+}
+
+/// A bounding box in the pixel space of a page image.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct WordBox {
+    /// Distance from the left edge of the page.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::finite))]
+    pub x: f64,
+    /// Distance from the top edge of the page.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::finite))]
+    pub y: f64,
+    /// Width of the box.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::finite))]
+    pub w: f64,
+    /// Height of the box.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::finite))]
+    pub h: f64,
+}
+
+/// The wire format of [`WordCoordinates`], where numbers may be sent as strings.
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct RawWordCoordinates {
+    width: Option<Coordinate>,
+    height: Option<Coordinate>,
+    #[serde(default)]
+    coords: std::collections::BTreeMap<String, Vec<Vec<Coordinate>>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum Coordinate {
+    Number(f64),
+    String(String),
+}
+
+impl Coordinate {
+    fn value(&self) -> Option<f64> {
+        match self {
+            Coordinate::Number(n) => Some(*n),
+            Coordinate::String(s) => s.trim().parse().ok(),
+        }
+    }
+}
+
+impl From<RawWordCoordinates> for WordCoordinates {
+    fn from(raw: RawWordCoordinates) -> Self {
+        let words = raw
+            .coords
+            .into_iter()
+            .map(|(text, boxes)| WordCoordinate {
+                text,
+                boxes: boxes
+                    .iter()
+                    .filter_map(|b| match b.iter().map(Coordinate::value).collect::<Option<Vec<f64>>>()?[..] {
+                        [x, y, w, h] => Some(WordBox { x, y, w, h }),
+                        _ => None,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        WordCoordinates {
+            width: raw.width.as_ref().and_then(Coordinate::value),
+            height: raw.height.as_ref().and_then(Coordinate::value),
+            words,
+        }
+    }
+}
+
+impl From<WordCoordinates> for RawWordCoordinates {
+    fn from(coordinates: WordCoordinates) -> Self {
+        let coords = coordinates
+            .words
+            .into_iter()
+            .map(|word| {
+                let boxes = word
+                    .boxes
+                    .iter()
+                    .map(|b| [b.x, b.y, b.w, b.h].into_iter().map(Coordinate::Number).collect())
+                    .collect();
+                (word.text, boxes)
+            })
+            .collect();
+
+        RawWordCoordinates {
+            width: coordinates.width.map(Coordinate::Number),
+            height: coordinates.height.map(Coordinate::Number),
+            coords,
+        }
+    }
+}
+
+/// A IIIF Image API `info.json` document, as linked from [`File::info`].
+///
+/// Both IIIF Image API 2 (`@id`) and 3 (`id`) documents are read.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::ImageInfo;
+///
+/// let json = r#"{
+///     "@id": "https://tile.loc.gov/image-services/iiif/service:pnp:bbc:0000:0001f",
+///     "width": 3000, "height": 4000,
+///     "tiles": [{"width": 512, "scaleFactors": [1, 2, 4, 8]}],
+///     "sizes": [{"width": 375, "height": 500}, {"width": 750, "height": 1000}],
+///     "profile": ["http://iiif.io/api/image/2/level2.json", {"formats": ["jpg", "png"]}]
+/// }"#;
+///
+/// let info: ImageInfo = serde_json::from_str(json).unwrap();
+/// assert_eq!(info.tiles[0].scale_factors, vec![1, 2, 4, 8]);
+/// assert_eq!(info.compliance_level(), Some("http://iiif.io/api/image/2/level2.json"));
+/// assert_eq!(
+///     info.size_url(800).unwrap(),
+///     "https://tile.loc.gov/image-services/iiif/service:pnp:bbc:0000:0001f/full/800,/0/default.jpg"
+/// );
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ImageInfo {
+    /// Base URI of the image service, to which IIIF image request parameters are appended.
+    #[serde(rename = "@id", alias = "id", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Width of the full image in pixels.
+    #[serde(default)]
+    pub width: u64,
+    /// Height of the full image in pixels.
+    #[serde(default)]
+    pub height: u64,
+    /// Tile sizes and the scale factors they are available at.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiles: Vec<IiifTile>,
+    /// Preferred sizes of the full image, e.g. for thumbnails.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sizes: Vec<IiifSize>,
+    /// Compliance level URI or name, optionally followed by objects listing extra features.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::values))]
+    pub profile: Option<ItemOrArray<Value>>,
+}
+
+impl ImageInfo {
+    /// Returns the compliance level, e.g. `http://iiif.io/api/image/2/level2.json` or `level2`.
+    pub fn compliance_level(&self) -> Option<&str> {
+        // A JSON array deserializes as a single `Value::Array` item.
+        let profiles = match self.profile.as_ref()? {
+            ItemOrArray::Item(Value::Array(profiles)) | ItemOrArray::Array(profiles) => profiles.as_slice(),
+            ItemOrArray::Item(profile) => std::slice::from_ref(profile),
+        };
+        profiles.iter().find_map(Value::as_str)
+    }
+
+    /// Returns the URL of the full image scaled to `width` pixels, or `None` without a service
+    /// URI. Widths larger than the image are reduced to its full width.
+    pub fn size_url(&self, width: u64) -> Option<String> {
+        let id = self.id.as_deref()?.trim_end_matches('/');
+        let width = if self.width > 0 { width.min(self.width) } else { width };
+        Some(format!("{}/full/{},/0/default.jpg", id, width))
+    }
+}
+
+/// A tile size of an [`ImageInfo`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct IiifTile {
+    /// Width of the tiles in pixels.
+    pub width: u64,
+    /// Height of the tiles in pixels, the same as the width if missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    /// The scale factors, i.e. the downscaling ratios, at which these tiles are available.
+    #[serde(rename = "scaleFactors", default)]
+    pub scale_factors: Vec<u32>,
+}
+
+/// A preferred size of an [`ImageInfo`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct IiifSize {
+    /// Width in pixels.
+    pub width: u64,
+    /// Height in pixels.
+    pub height: u64,
+}
+
+/// The `options` block of item and resource responses: the request parameters as the server
+/// understood them, with its defaults filled in.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::ResponseOptions;
+///
+/// let json = r#"{"fo": "json", "c": 25, "sp": 1, "at": "item", "is_partial": false}"#;
+/// let options: ResponseOptions = serde_json::from_str(json).unwrap();
+/// assert_eq!(options.per_page.unwrap().as_u64(), Some(25));
+/// # #[cfg(not(feature = "lean"))]
+/// assert_eq!(options.additional.unwrap()["is_partial"], false);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ResponseOptions {
+    /// Format of the response (`fo`).
+    #[serde(rename = "fo", skip_serializing_if = "Option::is_none")]
+    pub format: Option<StringOrArray>,
+    /// Attributes selected for the response (`at`).
+    #[serde(rename = "at", skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<StringOrArray>,
+    /// Search query (`q`).
+    #[serde(rename = "q", skip_serializing_if = "Option::is_none")]
+    pub query: Option<StringOrArray>,
+    /// Facet filters (`fa`).
+    #[serde(rename = "fa", skip_serializing_if = "Option::is_none")]
+    pub filters: Option<StringOrArray>,
+    /// Number of results per page (`c`).
+    #[serde(rename = "c", skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<NumberOrString>,
+    /// Page number (`sp`).
+    #[serde(rename = "sp", skip_serializing_if = "Option::is_none")]
+    pub page: Option<NumberOrString>,
+    /// Sort order (`sb`).
+    #[serde(rename = "sb", skip_serializing_if = "Option::is_none")]
+    pub sort: Option<StringOrArray>,
+    /// Type of site section that served the request (e.g., "item").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_type: Option<StringOrArray>,
+    /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
 /// Represents the item attribute object within [`ItemResponse`] and [`ResourceResponse`].
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ItemAttribute {
     /// Place of publication.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -737,6 +2153,12 @@ pub struct ItemAttribute {
     /// Locations associated with the item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locations: Option<ItemOrArray<String>>,
+    /// Latitude and longitude of the item, as decimal degrees separated by a comma.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latlong: Option<StringOrArray>,
+    /// Bounding box of a map, e.g. `W 122°30ʹ--W 122°20ʹ/N 37°50ʹ--N 37°40ʹ`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinates: Option<ItemOrArray<String>>,
     /// Notes associated with the item.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<ItemOrArray<String>>,
@@ -772,13 +2194,25 @@ pub struct ItemAttribute {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub access_advisory: Option<ItemOrArray<String>>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+#[cfg(feature = "url")]
+impl ItemAttribute {
+    /// Parses [`ItemAttribute::url`] into a [`url::Url`].
+    pub fn parsed_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.url)
+    }
+}
+
 /// Represents a single resource object within [`ItemResponse`] and [`ResourceResponse`].
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ResourceObject {
     /// Files associated with the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -793,7 +2227,7 @@ pub struct ResourceObject {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<ItemOrArray<String>>,
     /// Type of the resource.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type", alias = "type_field", skip_serializing_if = "Option::is_none")]
     pub type_field: Option<StringOrArray>,
     /// Height of the resource in pixels.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -816,17 +2250,61 @@ pub struct ResourceObject {
     /// title of the resource.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<ItemOrArray<String>>,
+    #[cfg(not(feature = "lean"))]
     #[serde(flatten)]
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+impl ResourceObject {
+    /// Returns the files of the resource grouped by page (or segment), in order.
+    ///
+    /// Each page lists the same image in several sizes and formats.
+    pub fn pages(&self) -> Vec<Vec<&File>> {
+        self.files.iter().flatten().map(|group| group.iter().collect()).collect()
+    }
+
+    /// Returns every file of the resource, page after page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::ResourceObject;
+    ///
+    /// let resource: ResourceObject = serde_json::from_str(r#"{"files": [
+    ///     [{"url": "https://tile.loc.gov/0001.jpg"}, {"url": "https://tile.loc.gov/0001.tif"}],
+    ///     {"url": "https://tile.loc.gov/0002.jpg"}
+    /// ]}"#).unwrap();
+    /// assert_eq!(resource.all_files().len(), 3);
+    /// assert_eq!(resource.into_all_files().len(), 3);
+    /// ```
+    pub fn all_files(&self) -> Vec<&File> {
+        self.files.iter().flatten().flatten().collect()
+    }
+
+    /// Consumes the resource, returning every file, page after page.
+    pub fn into_all_files(self) -> Vec<File> {
+        self.files.into_iter().flatten().flatten().collect()
+    }
+}
+
+#[cfg(feature = "mime")]
+impl ResourceObject {
+    /// Parses [`ResourceObject::mimetype`] into [`mime::Mime`] values.
+    pub fn mime_types(&self) -> Result<Vec<mime::Mime>, mime::FromStrError> {
+        parse_mimes(&self.mimetype)
+    }
+}
+
 /// Represents the response from Search Result Endpoints like `/search/`, `/collections/`, or `/{format}/`.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SearchResultResponse {
     /// Facet information for filtering results.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<FacetRes>,
+    pub facets: Option<Vec<FacetRes>>,
     /// Pagination details for navigating through result pages.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<Pagination>,
@@ -834,17 +2312,81 @@ pub struct SearchResultResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<ResultItem>>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+impl SearchResultResponse {
+    /// Returns the facet named `name` (e.g., "subject"), if the response includes it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::SearchResultResponse;
+    ///
+    /// let json = r#"{"facets": [{"type": "subject", "filters": [{"title": "sports", "count": 42}]}]}"#;
+    /// let response: SearchResultResponse = serde_json::from_str(json).unwrap();
+    /// let subject = response.facet("subject").unwrap();
+    /// assert_eq!(subject.filters.len(), 1);
+    /// ```
+    pub fn facet(&self, name: &str) -> Option<&FacetRes> {
+        self.facets.as_ref()?.iter().find(|facet| facet.name() == Some(name))
+    }
+
+    /// Returns a [`FacetSummary`] for reading the facet counts as plain `(term, count)` pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::SearchResultResponse;
+    ///
+    /// let json = r#"{"facets": [{"type": "subject", "filters": [
+    ///     {"term": "sports", "count": 42},
+    ///     {"term": "baseball", "count": "108"},
+    ///     {"term": "players", "count": 7}
+    /// ]}]}"#;
+    /// let response: SearchResultResponse = serde_json::from_str(json).unwrap();
+    /// let top = response.facets().subject().top(2);
+    /// assert_eq!(top, vec![("baseball".to_string(), 108), ("sports".to_string(), 42)]);
+    /// ```
+    pub fn facets(&self) -> FacetSummary<'_> {
+        FacetSummary::new(self.facets.as_deref().unwrap_or_default())
+    }
+
+    /// Returns an [`ItemBrief`] for each result, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::response_models::SearchResultResponse;
+    ///
+    /// let json = r#"{"results": [{
+    ///     "id": "http://www.loc.gov/item/2014717546/",
+    ///     "title": "Baseball players practicing",
+    ///     "date": "1887",
+    ///     "url": "https://www.loc.gov/item/2014717546/"
+    /// }]}"#;
+    /// let response: SearchResultResponse = serde_json::from_str(json).unwrap();
+    /// let briefs = response.briefs();
+    /// assert_eq!(briefs[0].title.as_deref(), Some("Baseball players practicing"));
+    /// assert_eq!(briefs[0].date.as_deref(), Some("1887"));
+    /// ```
+    pub fn briefs(&self) -> Vec<ItemBrief> {
+        briefs(&self.results)
+    }
+}
+
 /// Represents the response from the `/collections/` endpoint.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CollectionsResponse {
     /// Facet information for collections.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<FacetRes>,
+    pub facets: Option<Vec<FacetRes>>,
     /// Pagination details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<Pagination>,
@@ -852,13 +2394,24 @@ pub struct CollectionsResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<CollectionItem>>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+impl CollectionsResponse {
+    /// Returns an [`ItemBrief`] for each result, in order.
+    pub fn briefs(&self) -> Vec<ItemBrief> {
+        briefs(&self.results)
+    }
+}
+
 /// Represents a single collection item in the `/collections/` response.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CollectionItem {
     /// Unique identifier of the collection.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -885,7 +2438,7 @@ pub struct CollectionItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub site_map: Option<StringOrArray>,
     /// Type of the collection.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "type", alias = "type_field", skip_serializing_if = "Option::is_none")]
     pub type_field: Option<StringOrArray>,
     /// Normalized slug for the collection.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -896,63 +2449,158 @@ pub struct CollectionItem {
     /// Timestamp when the collection was last updated.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<StringOrArray>,
+    /// Number of items in the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<NumberOrString>,
+    /// Indicates if the collection is featured on loc.gov.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub featured: Option<BoolOrString>,
+    /// Subjects covered by the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<ItemOrArray<String>>,
+    /// URL to the collection's thumbnail image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_gallery: Option<StringOrArray>,
+    /// URLs to images representing the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<ItemOrArray<String>>,
+    /// Indicates if access to the collection is restricted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_restricted: Option<BoolOrString>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+#[cfg(feature = "url")]
+impl CollectionItem {
+    /// Parses [`CollectionItem::url`] into a [`url::Url`].
+    pub fn parsed_url(&self) -> Result<Option<url::Url>, ParseLinkError> {
+        parse_link(&self.url)
+    }
+}
+
 /// Represents a single collection response (`/collections/{name_of_collection}/`).
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::CollectionResponse;
+///
+/// let json = r#"{"title": "Baseball Cards", "items": 2100, "site_type": "collection"}"#;
+/// let response: CollectionResponse = serde_json::from_str(json).unwrap();
+/// assert_eq!(response.collection.items.unwrap().as_u64(), Some(2100));
+/// # #[cfg(not(feature = "lean"))]
+/// assert_eq!(response.additional.unwrap()["site_type"], "collection");
+/// ```
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CollectionResponse {
     /// Facet information for the collection.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<FacetRes>,
+    pub facets: Option<Vec<FacetRes>>,
     /// Pagination details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<Pagination>,
     /// List of items within the collection.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<CollectionItem>>,
+    /// Collection-level metadata, such as the description and item count.
+    #[serde(flatten)]
+    pub collection: CollectionDetail,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
+impl CollectionResponse {
+    /// Returns an [`ItemBrief`] for each result, in order.
+    pub fn briefs(&self) -> Vec<ItemBrief> {
+        briefs(&self.results)
+    }
+}
+
 /// Represents a format-specific response.
+///
+/// Results are generic ResultItems by default; use a format-specific result type such as
+/// [`NewspaperResult`] with
+/// [`ApiClient::get_format_typed`](crate::loc_client::ApiClient::get_format_typed) to get its
+/// fields typed.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct FormatResponse {
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct FormatResponse<T = ResultItem> {
     /// Facet information for the format.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<FacetRes>,
+    pub facets: Option<Vec<FacetRes>>,
     /// Pagination details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<Pagination>,
     /// List of items matching the format.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub results: Option<Vec<ResultItem>>,
+    pub results: Option<Vec<T>>,
     /// Captures any additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
 
-/// Represents the detailed information about a single collection item.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl<T> FormatResponse<T>
+where
+    for<'a> ItemBrief: From<&'a T>,
+{
+    /// Returns an [`ItemBrief`] for each result, in order.
+    pub fn briefs(&self) -> Vec<ItemBrief> {
+        briefs(&self.results)
+    }
+}
+
+/// Represents the collection-level metadata of a single collection response, flattened into
+/// [`CollectionResponse::collection`].
+///
+/// Keys not modeled here remain available in [`CollectionResponse::additional`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CollectionDetail {
-    /// Captures any additional fields not explicitly defined.
+    /// Title of the collection.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(flatten)]
-    pub additional: Option<Value>,
+    pub title: Option<StringOrArray>,
+    /// Description of the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<StringOrArray>,
+    /// Curatorial notes about the scope and content of the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub curatorial_notes: Option<ItemOrArray<String>>,
+    /// Number of items in the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<NumberOrString>,
+    /// Subjects covered by the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<ItemOrArray<String>>,
+    /// Related resources, such as research guides and finding aids.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::values))]
+    pub related_resources: Option<ItemOrArray<Value>>,
 }
 
 /// Represents a generic search response for various endpoints.
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SearchResponse {
     /// Facet information for filtering results.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facets: Option<FacetRes>,
+    pub facets: Option<Vec<FacetRes>>,
     /// Pagination details.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pagination: Option<Pagination>,
@@ -960,7 +2608,281 @@ pub struct SearchResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<ResultItem>>,
     /// Additional fields not explicitly defined.
+    #[cfg(not(feature = "lean"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(flatten)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
     pub additional: Option<Value>,
 }
+
+impl SearchResponse {
+    /// Returns an [`ItemBrief`] for each result, in order.
+    pub fn briefs(&self) -> Vec<ItemBrief> {
+        briefs(&self.results)
+    }
+}
+
+/// A search result that only parses its id, title, date and URL up front.
+///
+/// The heavy nested `item` and `resources` blocks are kept as unparsed JSON and decoded on demand
+/// with [`LazyResultItem::parse_item`] and [`LazyResultItem::parse_resources`]; all other keys are
+/// skipped. Use it through [`LazySearchResultResponse`] when a page is mostly listed, not inspected.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::{ItemOrArray, LazySearchResultResponse};
+///
+/// let json = r#"{"results": [{
+///     "id": "http://www.loc.gov/item/2014717546/",
+///     "title": "Baseball players practicing",
+///     "item": {"title": "Baseball players practicing", "medium": ["1 photographic print"]}
+/// }]}"#;
+/// let page: LazySearchResultResponse = serde_json::from_str(json).unwrap();
+/// let result = &page.results.unwrap()[0];
+/// assert!(result.item.is_some());
+///
+/// let Some(ItemOrArray::Item(item)) = result.parse_item().unwrap() else { panic!() };
+/// assert!(item.medium.is_some());
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LazyResultItem {
+    /// Unique identifier of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<StringOrArray>,
+    /// Title of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<StringOrArray>,
+    /// Date of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<StringOrArray>,
+    /// URL of the item's page on loc.gov.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<StringOrArray>,
+    /// The unparsed [`ResultItem::item`] block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Value>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::raw_value))]
+    pub item: Option<Box<RawValue>>,
+    /// The unparsed `resources` block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Value>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::raw_value))]
+    pub resources: Option<Box<RawValue>>,
+}
+
+impl LazyResultItem {
+    /// Parses the `item` block, see [`ResultItem::item`].
+    pub fn parse_item(&self) -> Result<Option<ItemOrArray<ItemSummary>>, serde_json::Error> {
+        parse_raw(&self.item)
+    }
+
+    /// Parses the `resources` block.
+    pub fn parse_resources(&self) -> Result<Option<ItemOrArray<ResourceObject>>, serde_json::Error> {
+        parse_raw(&self.resources)
+    }
+}
+
+impl From<&LazyResultItem> for ItemBrief {
+    fn from(item: &LazyResultItem) -> Self {
+        ItemBrief {
+            id: first_string(&item.id).map(str::to_string),
+            title: first_string(&item.title).map(str::to_string),
+            date: first_string(&item.date).map(str::to_string),
+            url: first_string(&item.url).map(str::to_string),
+        }
+    }
+}
+
+/// A search, collection or format results page of [`LazyResultItem`]s.
+///
+/// Fetch one with [`ApiClient::fetch`](crate::loc_client::ApiClient::fetch), or with
+/// [`ApiClient::get_format_typed`](crate::loc_client::ApiClient::get_format_typed) for formats.
+pub type LazySearchResultResponse = FormatResponse<LazyResultItem>;
+
+/// An item or resource response that keeps the heavy `item`, `resources` and `views` blocks as
+/// unparsed JSON, decoded on demand with the `parse_*` methods.
+///
+/// Only the `type` key is parsed up front; all other keys are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::{ItemOrArray, LazyItemResponse};
+///
+/// let json = r#"{"type": "item", "item": {"title": "Baseball players practicing", "date": "1887"}}"#;
+/// let response: LazyItemResponse = serde_json::from_str(json).unwrap();
+///
+/// let Some(ItemOrArray::Item(item)) = response.parse_item().unwrap() else { panic!() };
+/// assert!(item.date.is_some());
+/// assert!(response.parse_resources().unwrap().is_none());
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct LazyItemResponse {
+    /// Type of the response (e.g., "Item" or "Resource").
+    #[serde(rename = "type", alias = "type_field", skip_serializing_if = "Option::is_none")]
+    pub type_field: Option<StringOrArray>,
+    /// The unparsed [`ItemResourceResponse::item`] block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Value>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::raw_value))]
+    pub item: Option<Box<RawValue>>,
+    /// The unparsed [`ItemResourceResponse::resources`] block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Value>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::raw_value))]
+    pub resources: Option<Box<RawValue>>,
+    /// The unparsed [`ItemResourceResponse::views`] block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Value>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_json::raw_value))]
+    pub views: Option<Box<RawValue>>,
+}
+
+impl LazyItemResponse {
+    /// Parses the `item` block, see [`ItemResourceResponse::item`].
+    pub fn parse_item(&self) -> Result<Option<ItemOrArray<ItemAttribute>>, serde_json::Error> {
+        parse_raw(&self.item)
+    }
+
+    /// Parses the `resources` block, see [`ItemResourceResponse::resources`].
+    pub fn parse_resources(&self) -> Result<Option<ItemOrArray<ResourceObject>>, serde_json::Error> {
+        parse_raw(&self.resources)
+    }
+
+    /// Parses the `views` block, see [`ItemResourceResponse::views`].
+    pub fn parse_views(&self) -> Result<Option<ItemOrArray<Value>>, serde_json::Error> {
+        parse_raw(&self.views)
+    }
+}
+
+/// Parses an unparsed JSON block, `None` if it was missing.
+fn parse_raw<T: DeserializeOwned>(raw: &Option<Box<RawValue>>) -> Result<Option<T>, serde_json::Error> {
+    raw.as_deref().map(|raw| serde_json::from_str(raw.get())).transpose()
+}
+
+/// Deserializes a JSON response body into `T`.
+///
+/// With the `simd` feature the body is parsed in place with simd-json, which uses it as scratch
+/// space: its content is unspecified afterwards. The [`RawValue`]-backed models
+/// ([`LazyResultItem`], [`LazySearchResultResponse`] and [`LazyItemResponse`]), which simd-json
+/// cannot build, are parsed with serde_json instead, as is everything without the feature. Other
+/// types holding a [`RawValue`] should be parsed with serde_json directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::fixtures;
+/// use loc_api::response_models::{from_slice, SearchResultResponse};
+///
+/// let mut body = fixtures::SEARCH.as_bytes().to_vec();
+/// let page: SearchResultResponse = from_slice(&mut body).unwrap();
+/// assert_eq!(page.results.unwrap().len(), 2);
+/// ```
+pub fn from_slice<T: DeserializeOwned + 'static>(body: &mut [u8]) -> Result<T, serde_json::Error> {
+    #[cfg(feature = "simd")]
+    {
+        use std::any::TypeId;
+
+        let raw_value_models = [
+            TypeId::of::<LazyResultItem>(),
+            TypeId::of::<LazySearchResultResponse>(),
+            TypeId::of::<LazyItemResponse>(),
+        ];
+        if !raw_value_models.contains(&TypeId::of::<T>()) {
+            return simd_json::serde::from_slice(body).map_err(serde::de::Error::custom);
+        }
+    }
+    serde_json::from_slice(body)
+}
+
+/// Generators for the fields whose types don't implement `Arbitrary`, producing only values that
+/// survive a JSON round trip: no top-level `null` (it would read back as `None`), and only floats
+/// with a short decimal form, since NaN and infinity serialize to `null` and serde_json may read
+/// long decimals back off by one bit.
+#[cfg(feature = "arbitrary")]
+mod arbitrary_json {
+    use arbitrary::{Result, Unstructured};
+    use serde_json::value::RawValue;
+    use serde_json::Value;
+
+    use super::ItemOrArray;
+
+    /// Returns a JSON scalar, or an array or object of at most a few scalars.
+    pub(super) fn value(u: &mut Unstructured) -> Result<Value> {
+        Ok(match u.int_in_range(0..=5)? {
+            0 => Value::Bool(u.arbitrary()?),
+            1 => Value::from(u.arbitrary::<i64>()?),
+            2 => Value::from(finite(u)?),
+            3 => Value::String(u.arbitrary()?),
+            4 => Value::Array((0..u.int_in_range(0..=3)?).map(|_| scalar(u)).collect::<Result<_>>()?),
+            _ => Value::Object((0..u.int_in_range(0..=3)?).map(|_| Ok((u.arbitrary()?, scalar(u)?))).collect::<Result<_>>()?),
+        })
+    }
+
+    fn scalar(u: &mut Unstructured) -> Result<Value> {
+        Ok(match u.int_in_range(0..=3)? {
+            0 => Value::Null,
+            1 => Value::Bool(u.arbitrary()?),
+            2 => Value::from(u.arbitrary::<i64>()?),
+            _ => Value::String(u.arbitrary()?),
+        })
+    }
+
+    pub(super) fn values(u: &mut Unstructured) -> Result<Option<ItemOrArray<Value>>> {
+        Ok(match u.int_in_range(0..=2)? {
+            0 => None,
+            1 => Some(ItemOrArray::Item(value(u)?)),
+            _ => Some(ItemOrArray::Array((0..u.int_in_range(0..=3)?).map(|_| value(u)).collect::<Result<_>>()?)),
+        })
+    }
+
+    pub(super) fn raw_value(u: &mut Unstructured) -> Result<Option<Box<RawValue>>> {
+        if !u.arbitrary::<bool>()? {
+            return Ok(None);
+        }
+        let json = value(u)?.to_string();
+        Ok(Some(RawValue::from_string(json).map_err(|_| arbitrary::Error::IncorrectFormat)?))
+    }
+
+    pub(super) fn finite(u: &mut Unstructured) -> Result<f64> {
+        Ok(f64::from(u.arbitrary::<i32>()?) / 8.0)
+    }
+
+    pub(super) fn option_finite(u: &mut Unstructured) -> Result<Option<f64>> {
+        Ok(if u.arbitrary()? { Some(finite(u)?) } else { None })
+    }
+}
+
+/// Returns the JSON Schemas of the top-level response models and of [`ResultItem`], keyed by type
+/// name, for validating stored harvests and generating bindings in other languages.
+///
+/// Requires the `schemars` feature. The schemas describe the JSON the models serialize to, which
+/// is also what they accept.
+///
+/// # Examples
+///
+/// ```rust
+/// let schemas = loc_api::schemas();
+/// let schema = serde_json::to_value(&schemas["SearchResultResponse"]).unwrap();
+/// assert!(schema["properties"]["results"].is_object());
+/// ```
+#[cfg(feature = "schemars")]
+pub fn schemas() -> std::collections::BTreeMap<&'static str, schemars::Schema> {
+    use schemars::schema_for;
+
+    std::collections::BTreeMap::from([
+        ("SearchResultResponse", schema_for!(SearchResultResponse)),
+        ("SearchResponse", schema_for!(SearchResponse)),
+        ("CollectionsResponse", schema_for!(CollectionsResponse)),
+        ("CollectionResponse", schema_for!(CollectionResponse)),
+        ("FormatResponse", schema_for!(FormatResponse)),
+        ("ItemResponse", schema_for!(ItemResponse)),
+        ("ResourceResponse", schema_for!(ResourceResponse)),
+        ("ResultItem", schema_for!(ResultItem)),
+    ])
+}
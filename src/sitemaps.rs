@@ -0,0 +1,146 @@
+//! # Sitemaps Module
+//!
+//! Parsers for the XML sitemaps of loc.gov, which list every item page of a collection. For
+//! complete-collection work, reading the sitemaps is faster and more reliable than paging
+//! through search results, whose depth is limited.
+//!
+//! A sitemap is either an index of further sitemaps or a list of pages; [`Sitemap::parse`]
+//! reads both into [`SitemapEntry`]s. [`ApiClient::get_sitemap`] downloads a single sitemap and
+//! [`ApiClient::sitemap_urls`] follows an index down to the pages. The item IDs of the pages,
+//! from [`item_ids`], feed directly into [`ApiClient::get_items_bulk`].
+//!
+//! [`ApiClient::get_items_bulk`]: crate::loc_client::ApiClient::get_items_bulk
+//! [`ApiClient::get_sitemap`]: crate::loc_client::ApiClient::get_sitemap
+//! [`ApiClient::sitemap_urls`]: crate::loc_client::ApiClient::sitemap_urls
+
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::identifiers::ItemId;
+
+/// A parsed sitemap: an index of further sitemaps, or the pages of a site section.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Sitemap {
+    /// A `<sitemapindex>`, listing further sitemaps.
+    Index(Vec<SitemapEntry>),
+    /// A `<urlset>`, listing pages.
+    UrlSet(Vec<SitemapEntry>),
+}
+
+/// A sitemap or page listed in a [`Sitemap`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SitemapEntry {
+    /// The URL of the listed sitemap or page (`<loc>`).
+    pub url: String,
+    /// When it was last modified (`<lastmod>`), as written in the sitemap, e.g. `2024-03-01`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lastmod: Option<String>,
+}
+
+impl SitemapEntry {
+    /// Returns the item ID if the entry is a loc.gov item page (`https://www.loc.gov/item/{id}/`).
+    pub fn item_id(&self) -> Option<ItemId> {
+        let is_item_page = self
+            .url
+            .split_once("://")
+            .is_some_and(|(_, rest)| rest.starts_with("www.loc.gov/item/") || rest.starts_with("loc.gov/item/"));
+        if is_item_page {
+            ItemId::parse(&self.url).ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl Sitemap {
+    /// Parses a sitemap or sitemap index document.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use loc_api::sitemaps::{item_ids, Sitemap};
+    ///
+    /// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+    ///   <url><loc>https://www.loc.gov/item/2014717546/</loc><lastmod>2024-03-01</lastmod></url>
+    ///   <url><loc>https://www.loc.gov/collections/baseball-cards/?sp=2&amp;st=list</loc></url>
+    /// </urlset>"#;
+    ///
+    /// let sitemap = Sitemap::parse(xml).unwrap();
+    /// assert_eq!(sitemap.entries().len(), 2);
+    /// assert_eq!(sitemap.entries()[0].lastmod.as_deref(), Some("2024-03-01"));
+    /// assert_eq!(sitemap.entries()[1].url, "https://www.loc.gov/collections/baseball-cards/?sp=2&st=list");
+    ///
+    /// let ids = item_ids(sitemap.entries());
+    /// assert_eq!(ids.len(), 1);
+    /// assert_eq!(ids[0].as_str(), "2014717546");
+    /// ```
+    pub fn parse(xml: &str) -> Result<Sitemap, ParseSitemapError> {
+        let is_index = match (xml.find("<sitemapindex"), xml.find("<urlset")) {
+            (Some(index), Some(urlset)) => index < urlset,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return Err(ParseSitemapError { reason: "no <urlset> or <sitemapindex> element" }),
+        };
+        let tag = if is_index { "sitemap" } else { "url" };
+
+        let mut entries = Vec::new();
+        let open = format!("<{}>", tag);
+        let close = format!("</{}>", tag);
+        let mut rest = xml;
+        while let Some(start) = rest.find(&open) {
+            let body = &rest[start + open.len()..];
+            let end = body.find(&close).ok_or(ParseSitemapError { reason: "unclosed entry" })?;
+            let entry = &body[..end];
+            let url = element(entry, "loc").ok_or(ParseSitemapError { reason: "entry without <loc>" })?;
+            entries.push(SitemapEntry { url, lastmod: element(entry, "lastmod") });
+            rest = &body[end + close.len()..];
+        }
+
+        Ok(if is_index { Sitemap::Index(entries) } else { Sitemap::UrlSet(entries) })
+    }
+
+    /// Returns the listed sitemaps or pages.
+    pub fn entries(&self) -> &[SitemapEntry] {
+        match self {
+            Sitemap::Index(entries) | Sitemap::UrlSet(entries) => entries,
+        }
+    }
+}
+
+/// Returns the item IDs of the item pages among `entries`, in order, skipping other pages.
+pub fn item_ids(entries: &[SitemapEntry]) -> Vec<ItemId> {
+    entries.iter().filter_map(SitemapEntry::item_id).collect()
+}
+
+/// Returns the trimmed, unescaped text of the first `<name>` element in `xml`.
+fn element(xml: &str, name: &str) -> Option<String> {
+    let open = format!("<{}>", name);
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&format!("</{}>", name))?;
+    let text = xml[start..end].trim();
+    let text = text.strip_prefix("<![CDATA[").and_then(|t| t.strip_suffix("]]>")).unwrap_or(text);
+    Some(
+        text.replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&"),
+    )
+}
+
+/// The error returned by [`Sitemap::parse`] when the document is not a sitemap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSitemapError {
+    reason: &'static str,
+}
+
+impl fmt::Display for ParseSitemapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid sitemap: {}", self.reason)
+    }
+}
+
+impl Error for ParseSitemapError {}
@@ -0,0 +1,105 @@
+//! # Snapshot Module
+//!
+//! Saving typed responses to disk and loading them back, so analyses can be reproduced offline
+//! and responses shared.
+//!
+//! A snapshot is a JSON file holding the response in a small [`Snapshot`] envelope that records
+//! the URL it was fetched from and when it was fetched. The [`Persist`] trait adds `save`/`load`
+//! to every response model.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::response_models::{
+    CollectionResponse, CollectionsResponse, FormatResponse, ItemResourceResponse, SearchResponse,
+    SearchResultResponse,
+};
+
+/// A response together with the URL it was fetched from and the time it was fetched.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Snapshot<T> {
+    /// The URL the response was fetched from.
+    pub url: String,
+    /// When the response was fetched, in seconds since the Unix epoch.
+    pub fetched_at: u64,
+    /// The response.
+    pub response: T,
+}
+
+impl<T: Serialize + DeserializeOwned> Snapshot<T> {
+    /// Wraps `response`, recording the current time as its fetch time.
+    pub fn new(url: impl Into<String>, response: T) -> Self {
+        Snapshot { url: url.into(), fetched_at: now(), response }
+    }
+
+    /// Writes the snapshot to `path` as pretty-printed JSON, creating missing parent directories.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        write_json(path.as_ref(), self)
+    }
+
+    /// Reads a snapshot written by [`Snapshot::save`].
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+/// Saving and loading of response models as [`Snapshot`]s.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::response_models::SearchResultResponse;
+/// use loc_api::snapshot::Persist;
+///
+/// let response: SearchResultResponse = serde_json::from_str(
+///     r#"{"results": [{"id": "http://www.loc.gov/item/1/"}]}"#,
+/// ).unwrap();
+///
+/// let path = std::env::temp_dir().join("loc_api_snapshot_example.json");
+/// response.save("https://www.loc.gov/search/?fo=json&q=baseball", &path).unwrap();
+///
+/// let snapshot = SearchResultResponse::load(&path).unwrap();
+/// assert_eq!(snapshot.url, "https://www.loc.gov/search/?fo=json&q=baseball");
+/// assert_eq!(snapshot.response.results.unwrap().len(), 1);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub trait Persist: Serialize + DeserializeOwned {
+    /// Saves the response fetched from `url` to `path`, see [`Snapshot::save`].
+    fn save(&self, url: &str, path: impl AsRef<Path>) -> io::Result<()> {
+        let snapshot = Snapshot { url: url.to_string(), fetched_at: now(), response: self };
+        write_json(path.as_ref(), &snapshot)
+    }
+
+    /// Loads a response saved with [`Persist::save`], together with its URL and fetch time.
+    fn load(path: impl AsRef<Path>) -> io::Result<Snapshot<Self>> {
+        Snapshot::load(path)
+    }
+}
+
+impl Persist for SearchResultResponse {}
+impl Persist for SearchResponse {}
+impl Persist for CollectionsResponse {}
+impl Persist for CollectionResponse {}
+impl<T: Serialize + DeserializeOwned> Persist for FormatResponse<T> {}
+impl<R: Serialize + DeserializeOwned> Persist for ItemResourceResponse<R> {}
+
+/// Returns the current time in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+/// Writes `value` to `path` as pretty-printed JSON, creating missing parent directories.
+fn write_json(path: &Path, value: &impl Serialize) -> io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, value)?;
+    writer.flush()
+}
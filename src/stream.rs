@@ -0,0 +1,404 @@
+//! # Stream Module
+//!
+//! Page-by-page harvesting of search results, returned by [`ApiClient::search_stream`].
+//!
+//! A [`SearchStream`] is a plain iterator: it requests the next page only when the consumer asks
+//! for it, so nothing is buffered. [`SearchStream::prefetch`] and
+//! [`SearchStream::for_each_prefetched`] overlap fetching with processing on a background thread,
+//! while bounding how many pages may wait ahead of a slow consumer so memory stays flat during
+//! large harvests.
+//!
+//! A [`ResultStream`], returned by [`ApiClient::stream_results`], works one level down: it decodes
+//! the `results` array of a single page while the body is still arriving and yields each
+//! [`ResultItem`] as soon as it is parsed, so a page with a large `c=` never sits in memory whole.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{BufReader, Read};
+use std::sync::mpsc;
+use std::thread;
+
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Deserializer;
+
+use crate::endpoints::Endpoints;
+use crate::loc_client::ApiClient;
+#[cfg(feature = "rayon")]
+use crate::param_models::CommonParams;
+#[cfg(feature = "rayon")]
+use crate::response_models::Pagination;
+use crate::response_models::{first_string, ResultItem, SearchResultResponse};
+
+/// How many decoded results may wait for the consumer of a [`ResultStream`].
+const RESULT_BUFFER: usize = 64;
+
+/// An iterator over the pages of a search, following each page's `pagination.next` link.
+///
+/// The iterator ends after the last page, after [`SearchStream::max_pages`] pages, or after
+/// yielding the first error.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use loc_api::loc_client::ApiClient;
+///
+/// let client = ApiClient::new();
+/// for page in client.search_stream("baseball", false, None, None, Some(100), None).max_pages(5) {
+///     let page = page.unwrap();
+///     println!("{} results", page.results.map_or(0, |r| r.len()));
+/// }
+/// ```
+pub struct SearchStream<'a> {
+    client: &'a ApiClient,
+    first: Option<Endpoints>,
+    next_url: Option<String>,
+    max_pages: Option<usize>,
+    fetched: usize,
+}
+
+impl<'a> SearchStream<'a> {
+    pub(crate) fn new(client: &'a ApiClient, endpoint: Endpoints) -> Self {
+        SearchStream {
+            client,
+            first: Some(endpoint),
+            next_url: None,
+            max_pages: None,
+            fetched: 0,
+        }
+    }
+
+    /// Stops the stream after `pages` pages.
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = Some(pages);
+        self
+    }
+
+    /// Turns the stream into a [`PrefetchStream`] that fetches the next pages on a background
+    /// thread while the consumer processes the current one.
+    ///
+    /// At most `buffer` fetched pages wait for the consumer, plus the one being fetched; when the
+    /// buffer is full the fetching thread blocks until the consumer catches up. A `buffer` of `0`
+    /// only overlaps fetching the next page with processing the current one, and `1` or `2` is
+    /// usually enough for the harvest to run at network speed.
+    ///
+    /// The background thread sends its requests through a clone of the client, sharing its
+    /// connection pool, cache and throttle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// for page in client.search_stream("baseball", false, None, None, Some(100), None).prefetch(1) {
+    ///     let page = page.unwrap();
+    ///     // page N + 1 is already downloading while this one is processed
+    ///     println!("{} results", page.results.map_or(0, |r| r.len()));
+    /// }
+    /// ```
+    pub fn prefetch(self, buffer: usize) -> PrefetchStream {
+        let (sender, receiver) = mpsc::sync_channel(buffer);
+        let client = self.client.clone();
+        let SearchStream { first, next_url, max_pages, fetched, .. } = self;
+        thread::spawn(move || {
+            let stream = SearchStream { client: &client, first, next_url, max_pages, fetched };
+            for page in stream {
+                // Errors are not `Send`, so only their message crosses the channel. A send error
+                // means the consumer stopped early.
+                if sender.send(page.map_err(|e| e.to_string())).is_err() {
+                    break;
+                }
+            }
+        });
+        PrefetchStream { receiver }
+    }
+
+    /// Fetches pages on a background thread and passes them to `sink` in order, see
+    /// [`SearchStream::prefetch`] for the meaning of `buffer`.
+    ///
+    /// # Parameters
+    ///
+    /// - `buffer`: The maximum number of pages fetched ahead of `sink`.
+    /// - `sink`: Called with every page. Returning an error stops the stream.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of pages passed to `sink`, or the first fetch or sink error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let pages = client
+    ///     .search_stream("baseball", false, None, None, Some(100), None)
+    ///     .for_each_prefetched(2, |page| {
+    ///         // write page.results to a database
+    ///         Ok(())
+    ///     })
+    ///     .unwrap();
+    /// println!("harvested {} pages", pages);
+    /// ```
+    pub fn for_each_prefetched<F>(self, buffer: usize, mut sink: F) -> Result<usize, Box<dyn Error>>
+    where
+        F: FnMut(SearchResultResponse) -> Result<(), Box<dyn Error>>,
+    {
+        let mut consumed = 0;
+        for page in self.prefetch(buffer) {
+            sink(page?)?;
+            consumed += 1;
+        }
+        Ok(consumed)
+    }
+}
+
+impl Iterator for SearchStream<'_> {
+    type Item = Result<SearchResultResponse, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.max_pages.is_some_and(|max| self.fetched >= max) {
+            return None;
+        }
+
+        let page = match (self.first.take(), self.next_url.take()) {
+            (Some(endpoint), _) => self.client.fetch::<SearchResultResponse>(&endpoint),
+            (None, Some(url)) => self.client.fetch_url::<SearchResultResponse>(url),
+            (None, None) => return None,
+        };
+        self.fetched += 1;
+
+        match page {
+            Ok((page, _)) => {
+                self.next_url = page
+                    .pagination
+                    .as_ref()
+                    .and_then(|pagination| first_string(&pagination.next))
+                    .filter(|next| !next.is_empty())
+                    .map(str::to_string);
+                Some(Ok(page))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// An iterator over the pages of a [`SearchStream`], fetched ahead of the consumer on a
+/// background thread. Returned by [`SearchStream::prefetch`].
+///
+/// The iterator ends after the last page or after yielding the first error. Dropping it stops
+/// the background thread once the page it is fetching arrives.
+pub struct PrefetchStream {
+    receiver: mpsc::Receiver<Result<SearchResultResponse, String>>,
+}
+
+impl Iterator for PrefetchStream {
+    type Item = Result<SearchResultResponse, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok().map(|page| page.map_err(Into::into))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl SearchStream<'_> {
+    /// Harvests the remaining pages on the rayon thread pool and maps every result with `f` in
+    /// parallel, so CPU-bound post-processing overlaps with waiting for the network.
+    ///
+    /// The first page is fetched as usual; the remaining pages are then requested by page number,
+    /// up to the page of `pagination.last` and [`SearchStream::max_pages`], instead of following
+    /// `next` links one by one. If the pagination links cannot be parsed the pages are fetched in
+    /// order, with only `f` running in parallel. Every request still goes through the client's
+    /// [`throttle`](crate::loc_client::ApiClientBuilder::throttle), which bounds how fast the
+    /// pool can fan out.
+    ///
+    /// # Returns
+    ///
+    /// Returns the mapped results in page order, or the first fetch error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use loc_api::loc_client::ApiClient;
+    ///
+    /// let client = ApiClient::new();
+    /// let titles = client
+    ///     .search_stream("baseball", false, None, None, Some(100), None)
+    ///     .max_pages(10)
+    ///     .par_map_results(|item| item.to_string())
+    ///     .unwrap();
+    /// println!("{} results", titles.len());
+    /// ```
+    pub fn par_map_results<F, R>(mut self, f: F) -> Result<Vec<R>, Box<dyn Error>>
+    where
+        F: Fn(ResultItem) -> R + Send + Sync,
+        R: Send,
+    {
+        use rayon::prelude::*;
+
+        let Some(first) = self.next() else { return Ok(Vec::new()) };
+        let first = first?;
+        let remaining = self.max_pages.map_or(usize::MAX, |max| max.saturating_sub(self.fetched));
+        let endpoints = match self.next_url.as_deref() {
+            Some(next) => page_endpoints(next, first.pagination.as_ref(), remaining),
+            None => Some(Vec::new()),
+        };
+
+        let map_page =
+            |page: SearchResultResponse| page.results.unwrap_or_default().into_par_iter().map(&f).collect::<Vec<R>>();
+        let client = self.client;
+        // Errors are not `Send`, so only their message leaves the pool.
+        let (first, rest) = rayon::join(
+            || map_page(first),
+            || match endpoints {
+                Some(endpoints) => endpoints
+                    .par_iter()
+                    .map(|endpoint| {
+                        let (page, _) = client.fetch::<SearchResultResponse>(endpoint).map_err(|e| e.to_string())?;
+                        Ok(map_page(page))
+                    })
+                    .collect::<Result<Vec<_>, String>>(),
+                None => self.map(|page| page.map(map_page).map_err(|e| e.to_string())).collect(),
+            },
+        );
+
+        Ok(first.into_iter().chain(rest?.into_iter().flatten()).collect())
+    }
+}
+
+/// Returns the endpoints of the pages from `next` to the page of `pagination.last`, at most
+/// `limit` of them, or `None` if the page numbers cannot be read from the links.
+#[cfg(feature = "rayon")]
+fn page_endpoints(next: &str, pagination: Option<&Pagination>, limit: usize) -> Option<Vec<Endpoints>> {
+    let mut last = Endpoints::parse(first_string(&pagination?.last)?).ok()?;
+    let last = common_params(&mut last)?.page?;
+    let mut next = Endpoints::parse(next).ok()?;
+    let start = common_params(&mut next)?.page?;
+
+    let pages = (start..=last).take(limit).map(|page| {
+        let mut endpoint = next.clone();
+        if let Some(params) = common_params(&mut endpoint) {
+            params.page = Some(page);
+        }
+        endpoint
+    });
+    Some(pages.collect())
+}
+
+/// Returns the paging parameters of a list endpoint, `None` for item and resource endpoints.
+#[cfg(feature = "rayon")]
+fn common_params(endpoint: &mut Endpoints) -> Option<&mut CommonParams> {
+    match endpoint {
+        Endpoints::Search(params) => Some(&mut params.common),
+        Endpoints::Collections(params) | Endpoints::Collection { params, .. } | Endpoints::Format { params, .. } => {
+            Some(params)
+        }
+        Endpoints::Item { .. } | Endpoints::Resource { .. } => None,
+    }
+}
+
+/// An iterator over the results of one page, decoded from the response body as it arrives.
+///
+/// The body is parsed on a background thread that stays at most a few dozen results ahead of the
+/// consumer. Keys other than `results` are skipped. The iterator ends after the last result or
+/// after yielding the first parse error; dropping it early stops the background thread.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use loc_api::endpoints::Endpoints;
+/// use loc_api::loc_client::ApiClient;
+/// use loc_api::param_models::{CommonParams, SearchParams};
+///
+/// let client = ApiClient::new();
+/// let endpoint = Endpoints::Search(SearchParams {
+///     common: CommonParams { query: Some("baseball".to_string()), per_page: Some(1000), ..Default::default() },
+///     include_collections: false,
+/// });
+/// for result in client.stream_results(&endpoint).unwrap() {
+///     println!("{:?}", result.unwrap().id);
+/// }
+/// ```
+pub struct ResultStream {
+    receiver: mpsc::Receiver<Result<ResultItem, serde_json::Error>>,
+}
+
+impl ResultStream {
+    pub(crate) fn new(body: Box<dyn Read + Send>) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(RESULT_BUFFER);
+        thread::spawn(move || {
+            let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(body));
+            if let Err(e) = ResultsPage(&sender).deserialize(&mut deserializer) {
+                // Fails only if the consumer is gone, which is also the cause of a `stopped` error.
+                let _ = sender.send(Err(e));
+            }
+        });
+        ResultStream { receiver }
+    }
+}
+
+impl Iterator for ResultStream {
+    type Item = Result<ResultItem, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.receiver.recv().ok().map(|result| result.map_err(Into::into))
+    }
+}
+
+type ResultSender = mpsc::SyncSender<Result<ResultItem, serde_json::Error>>;
+
+/// Visits a results page, sending the items of its `results` array.
+struct ResultsPage<'a>(&'a ResultSender);
+
+impl<'de> DeserializeSeed<'de> for ResultsPage<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ResultsPage<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a results page")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "results" {
+                map.next_value_seed(Results(self.0))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Visits the `results` array, sending each item as soon as it is decoded.
+struct Results<'a>(&'a ResultSender);
+
+impl<'de> DeserializeSeed<'de> for Results<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Results<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array of results")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(item) = seq.next_element::<ResultItem>()? {
+            self.0.send(Ok(item)).map_err(|_| de::Error::custom("stopped: the result stream was dropped"))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+//! # Testing Module
+//!
+//! Helpers for testing code built on [`ApiClient`], enabled with the `testing` feature.
+//!
+//! [`MockServer`] is a [`wiremock`] server usable from synchronous tests.
+//! [`MockServer::with_fixtures`] pre-stubs the search, item, resource and collection endpoints
+//! with the responses in [`fixtures`], so an integration test only needs a few lines:
+//!
+//! ```rust
+//! use loc_api::testing::MockServer;
+//!
+//! let server = MockServer::with_fixtures();
+//! let (page, _) = server.client().search("baseball", false, None, None, None, None, None).unwrap();
+//! assert_eq!(page.results.unwrap().len(), 2);
+//! ```
+//!
+//! Tests that need request matching or expectations register their own [`Mock`]s, which take
+//! precedence over the fixtures. Expectations are verified when the server is dropped.
+//!
+//! The client returned by [`MockServer::client`] uses the default transport, so one of the
+//! `blocking` or `ureq` features must be enabled too.
+
+use std::future::Future;
+
+use tokio::runtime::{Builder, Runtime};
+use wiremock::matchers::{method, path_regex};
+use wiremock::{Mock, Request, ResponseTemplate};
+
+use crate::loc_client::ApiClient;
+
+pub use crate::fixtures;
+pub use wiremock;
+
+/// The priority of the fixture mocks: lower than the default, so registered mocks win.
+const FIXTURE_PRIORITY: u8 = 10;
+
+/// A [`wiremock::MockServer`] driven from synchronous code.
+///
+/// The server runs on its own thread; the methods block until the server has applied the
+/// change. Unmatched requests get a `404`.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::testing::MockServer;
+/// use loc_api::testing::wiremock::matchers::{method, path, query_param};
+/// use loc_api::testing::wiremock::{Mock, ResponseTemplate};
+///
+/// let server = MockServer::with_fixtures();
+/// server.register(
+///     Mock::given(method("GET"))
+///         .and(path("/item/missing/"))
+///         .and(query_param("fo", "json"))
+///         .respond_with(ResponseTemplate::new(404))
+///         .expect(1),
+/// );
+///
+/// let client = server.client();
+/// assert!(client.get_item("2014717546", None).is_ok());
+/// assert!(client.get_item("missing", None).is_err());
+/// assert_eq!(server.requests().len(), 2);
+/// server.verify();
+/// ```
+pub struct MockServer {
+    server: wiremock::MockServer,
+    runtime: Runtime,
+}
+
+impl MockServer {
+    /// Starts a server without mocks on a free local port.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime driving the server cannot be created.
+    pub fn start() -> Self {
+        let runtime = Builder::new_current_thread().enable_all().build().expect("failed to start the mock server runtime");
+        let server = runtime.block_on(wiremock::MockServer::start());
+        MockServer { server, runtime }
+    }
+
+    /// Starts a server stubbing `/search/`, `/item/`, `/resource/` and `/collections/` with
+    /// [`fixture_mocks`].
+    pub fn with_fixtures() -> Self {
+        let server = Self::start();
+        for mock in fixture_mocks() {
+            server.register(mock);
+        }
+        server
+    }
+
+    /// Mounts `mock` on the server.
+    pub fn register(&self, mock: Mock) -> &Self {
+        self.block_on(self.server.register(mock));
+        self
+    }
+
+    /// Panics if the expectations of a registered [`Mock`] are not met yet.
+    pub fn verify(&self) {
+        self.block_on(self.server.verify());
+    }
+
+    /// Returns the base URL of the server, e.g. `http://127.0.0.1:51234`.
+    pub fn base_url(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Returns an [`ApiClient`] sending its requests to this server.
+    pub fn client(&self) -> ApiClient {
+        ApiClient::builder().base_url(self.base_url()).build()
+    }
+
+    /// Returns every request received so far, in order.
+    pub fn received_requests(&self) -> Vec<Request> {
+        self.block_on(self.server.received_requests()).unwrap_or_default()
+    }
+
+    /// Returns the path and query of every request received so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.received_requests()
+            .iter()
+            .map(|request| match request.url.query() {
+                Some(query) => format!("{}?{}", request.url.path(), query),
+                None => request.url.path().to_string(),
+            })
+            .collect()
+    }
+
+    /// Returns the underlying [`wiremock::MockServer`].
+    pub fn server(&self) -> &wiremock::MockServer {
+        &self.server
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// Returns [`Mock`]s answering `GET` requests under `/search/`, `/item/`, `/resource/` and
+/// `/collections/` with [`fixtures`], at a priority below the default one.
+pub fn fixture_mocks() -> Vec<Mock> {
+    [
+        ("^/search/", fixtures::SEARCH),
+        ("^/item/", fixtures::ITEM),
+        ("^/resource/", fixtures::RESOURCE),
+        ("^/collections/", fixtures::COLLECTION),
+    ]
+    .into_iter()
+    .map(|(path, body)| {
+        Mock::given(method("GET"))
+            .and(path_regex(path))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+            .with_priority(FIXTURE_PRIORITY)
+    })
+    .collect()
+}
@@ -0,0 +1,134 @@
+//! # Throttle Module
+//!
+//! Client-side rate limiting, configured with [`ThrottleConfig`] on
+//! [`ApiClientBuilder::throttle`](crate::loc_client::ApiClientBuilder::throttle).
+//!
+//! loc.gov publishes separate limits for item/resource requests, newspaper requests and all
+//! other requests, and blocks clients that exceed them. The throttle keeps one token bucket per
+//! category: up to `burst` requests go out immediately, after which requests are spaced to the
+//! configured requests per minute.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Rate limits applied by the client before each request.
+///
+/// The defaults follow the limits published by the Library of Congress for the loc.gov API.
+///
+/// # Examples
+///
+/// ```rust
+/// use loc_api::loc_client::ApiClient;
+/// use loc_api::throttle::ThrottleConfig;
+///
+/// let client = ApiClient::builder()
+///     .throttle(ThrottleConfig {
+///         requests_per_minute: 40,
+///         ..ThrottleConfig::default()
+///     })
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThrottleConfig {
+    /// Sustained rate for search, collection and format requests.
+    pub requests_per_minute: u32,
+    /// Requests that may be sent back to back before `requests_per_minute` applies.
+    pub burst: u32,
+    /// Sustained rate for `/item/` and `/resource/` requests.
+    pub item_requests_per_minute: u32,
+    /// Burst size for `/item/` and `/resource/` requests.
+    pub item_burst: u32,
+    /// Sustained rate for newspaper requests.
+    pub newspapers_requests_per_minute: u32,
+    /// Burst size for newspaper requests.
+    pub newspapers_burst: u32,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        ThrottleConfig {
+            requests_per_minute: 80,
+            burst: 20,
+            item_requests_per_minute: 200,
+            item_burst: 40,
+            newspapers_requests_per_minute: 20,
+            newspapers_burst: 20,
+        }
+    }
+}
+
+impl ThrottleConfig {
+    /// Applies a single sustained rate to every category, keeping the default burst sizes.
+    pub fn uniform(requests_per_minute: u32) -> Self {
+        ThrottleConfig {
+            requests_per_minute,
+            item_requests_per_minute: requests_per_minute,
+            newspapers_requests_per_minute: requests_per_minute,
+            ..ThrottleConfig::default()
+        }
+    }
+}
+
+/// The token buckets enforcing a [`ThrottleConfig`].
+pub(crate) struct Throttle {
+    general: Mutex<Bucket>,
+    items: Mutex<Bucket>,
+    newspapers: Mutex<Bucket>,
+}
+
+impl Throttle {
+    pub(crate) fn new(config: ThrottleConfig) -> Self {
+        Throttle {
+            general: Mutex::new(Bucket::new(config.requests_per_minute, config.burst)),
+            items: Mutex::new(Bucket::new(config.item_requests_per_minute, config.item_burst)),
+            newspapers: Mutex::new(Bucket::new(config.newspapers_requests_per_minute, config.newspapers_burst)),
+        }
+    }
+
+    /// Takes a token for `url` and returns how long the caller must wait before sending it.
+    pub(crate) fn acquire(&self, url: &str) -> Duration {
+        let lower = url.to_lowercase();
+        let bucket = if lower.contains("/newspapers/") || lower.contains("chronicling") {
+            &self.newspapers
+        } else if lower.contains("/item/") || lower.contains("/resource/") {
+            &self.items
+        } else {
+            &self.general
+        };
+        bucket.lock().map(|mut bucket| bucket.take()).unwrap_or_default()
+    }
+}
+
+struct Bucket {
+    capacity: f64,
+    per_second: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(requests_per_minute: u32, burst: u32) -> Self {
+        let capacity = f64::from(burst.max(1));
+        Bucket {
+            capacity,
+            per_second: f64::from(requests_per_minute.max(1)) / 60.0,
+            tokens: capacity,
+            updated: Instant::now(),
+        }
+    }
+
+    /// Takes a token, going into debt if none is left, and returns the wait until it is covered.
+    fn take(&mut self) -> Duration {
+        let now = Instant::now();
+        let refill = now.duration_since(self.updated).as_secs_f64() * self.per_second;
+        self.tokens = (self.tokens + refill).min(self.capacity);
+        self.updated = now;
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.per_second)
+        }
+    }
+}
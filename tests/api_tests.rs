@@ -1,3 +1,4 @@
+#![cfg(feature = "blocking")]
 #![allow(unused_imports)]
 
 #![allow(unused_imports)]
@@ -26,7 +27,7 @@ fn test_search_endpoint() {
         Some(FacetReq { filters: vec![Facet::Subject { value: "sports".to_string() }] }),
         Some(25),
         Some(1),
-        Some(SortField::DateDesc),
+        SortField::DateDesc.into(),
     ).unwrap();
 
     // Assertions based on expected response structure
@@ -47,6 +48,7 @@ fn test_item_endpoint() {
             cite_this: Some(true),
             item: Some(true),
             resources: Some(true),
+            ..Default::default()
         }),
     ).unwrap();
 
@@ -105,7 +107,7 @@ fn test_format_endpoint() {
         Some(FacetReq { filters: vec![Facet::Subject { value: "geography".to_string() }] }),
         Some(10),
         Some(1),
-        Some(SortField::TitleS),
+        SortField::TitleS.into(),
     ).unwrap();
 
     // Assertions based on expected response structure
@@ -0,0 +1,1413 @@
+#![cfg(feature = "client")]
+
+use loc_api::attribute_models::{AttributesError, AttributesSelect, SortField};
+use loc_api::download::BulkDownloader;
+use loc_api::endpoints::Endpoints;
+use loc_api::feed::Feed;
+use loc_api::format_models::MediaType;
+use loc_api::identifiers::ItemId;
+use loc_api::loc_client::{ApiClient, DEFAULT_BASE_URL};
+use loc_api::metrics::Metrics;
+use loc_api::middleware::{Middleware, Request, Response};
+use loc_api::param_models::{CommonParams, Facet, FacetReq, ItemParams, SearchParams};
+use loc_api::pool::{BulkStats, ClientPool};
+use loc_api::response_models::{File, FilterItem, ImageSize, ItemResponse, ResourceDetail, WordBox};
+use loc_api::sitemaps::{item_ids, Sitemap};
+use loc_api::throttle::ThrottleConfig;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Serves `body` as a JSON response to each of the next `requests` connections and
+/// returns the base URL of the local server.
+fn serve(body: &'static str, requests: usize) -> String {
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nX-Test: yes\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    serve_raw(vec![response; requests])
+}
+
+/// Writes each raw HTTP response to the next connection, in order, and returns the base URL
+/// of the local server.
+fn serve_raw(responses: Vec<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    thread::spawn(move || {
+        for (stream, response) in listener.incoming().zip(responses) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    format!("http://{}", addr)
+}
+
+/// Serves search pages `1` to `last`, picked by the `sp` parameter, each with two results and
+/// `next`/`last` pagination links, and returns the base URL of the local server.
+fn serve_search_pages(last: u32) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let link_base = base_url.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            let page: u32 = request_line
+                .split(['?', '&', ' '])
+                .find_map(|pair| pair.strip_prefix("sp="))
+                .map_or(1, |page| page.parse().unwrap());
+            let link = |page: u32| format!("\"{}/search/?q=maps&sp={}&fo=json\"", link_base, page);
+            let next = if page < last { link(page + 1) } else { "null".to_string() };
+            let body = format!(
+                r#"{{"pagination": {{"current": {}, "next": {}, "last": {}}}, "results": [{{"id": "http://www.loc.gov/item/{}a/"}}, {{"id": "http://www.loc.gov/item/{}b/"}}]}}"#,
+                page, next, link(last), page, page
+            );
+            let _ = write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+        }
+    });
+    base_url
+}
+
+const SEARCH_PAGE: &str = r#"{"results": [{"id": "http://www.loc.gov/item/2014717546/", "title": "Baseball"}], "pagination": {"current": 1}}"#;
+
+#[test]
+fn test_hooks_and_response_meta() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let requested = Arc::new(Mutex::new(Vec::new()));
+    let responded = Arc::new(Mutex::new(Vec::new()));
+
+    let client = {
+        let requested = requested.clone();
+        let responded = responded.clone();
+        ApiClient::builder()
+            .base_url(base_url.clone())
+            .on_request(move |url| requested.lock().unwrap().push(url.to_string()))
+            .on_response(move |meta| responded.lock().unwrap().push((meta.status, meta.body_size)))
+            .build()
+    };
+
+    let (response, url) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+    assert!(url.starts_with(&base_url));
+
+    assert_eq!(*requested.lock().unwrap(), vec![url.clone()]);
+    assert_eq!(responded.lock().unwrap().as_slice(), &[(200, SEARCH_PAGE.len())]);
+
+    let meta = client.last_response_meta().unwrap();
+    assert_eq!(meta.final_url, url);
+    assert_eq!(meta.header("x-test"), Some("yes"));
+    assert_eq!(meta.retries, 0);
+}
+
+#[test]
+fn test_search_with_raw_single_request() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let client = ApiClient::builder().base_url(base_url).build();
+
+    let (typed, raw, _) = client.search_with_raw("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(typed.results.unwrap().len(), 1);
+    assert_eq!(raw["results"][0]["title"], "Baseball");
+}
+
+struct Fixture;
+
+impl Middleware for Fixture {
+    fn handle_request(&self, request: &mut Request) -> Option<Response> {
+        request.set_header("X-Fixture", "1");
+        Some(Response::ok(SEARCH_PAGE))
+    }
+}
+
+struct CountResults(Arc<Mutex<usize>>);
+
+impl Middleware for CountResults {
+    fn handle_response(&self, _request: &Request, response: &mut Response) {
+        let page: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        *self.0.lock().unwrap() += page["results"].as_array().unwrap().len();
+    }
+}
+
+#[test]
+fn test_middleware_short_circuit() {
+    let seen = Arc::new(Mutex::new(0));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Fixture)
+        .middleware(CountResults(seen.clone()))
+        .build();
+
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+    assert_eq!(*seen.lock().unwrap(), 1);
+    assert_eq!(client.last_response_meta().unwrap().status, 200);
+}
+
+/// Answers every request with `SEARCH_PAGE` and records the requested URLs.
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Middleware for Recorder {
+    fn handle_request(&self, request: &mut Request) -> Option<Response> {
+        self.0.lock().unwrap().push(request.url.clone());
+        Some(Response::ok(SEARCH_PAGE))
+    }
+}
+
+#[test]
+fn test_default_attributes() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(urls.clone()))
+        .default_attributes(AttributesSelect {
+            include: vec!["pagination".to_string(), "results".to_string()],
+            exclude: vec![],
+        })
+        .build();
+
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+    client
+        .search("baseball", false, Some(AttributesSelect { include: vec!["facets".to_string()], exclude: vec![] }), None, None, None, None)
+        .unwrap();
+
+    let urls = urls.lock().unwrap();
+    assert!(urls[0].contains("at=pagination,results"));
+    assert!(urls[1].contains("at=facets") && !urls[1].contains("pagination"));
+}
+
+#[test]
+fn test_conflicting_attributes_fail_before_request() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(urls.clone()))
+        .build();
+
+    let attributes = AttributesSelect {
+        include: vec!["results".to_string(), "facets".to_string()],
+        exclude: vec!["facets".to_string()],
+    };
+    let error = client.search("baseball", false, Some(attributes), None, None, None, None).unwrap_err();
+    assert_eq!(error.downcast_ref::<AttributesError>(), Some(&AttributesError::Conflict(vec!["facets".to_string()])));
+    assert!(urls.lock().unwrap().is_empty());
+
+    // Unknown names are only warnings, reported in the response metadata.
+    let attributes = AttributesSelect { include: vec!["results".to_string(), "paginaton".to_string()], exclude: vec![] };
+    client.search("baseball", false, Some(attributes), None, None, None, None).unwrap();
+    assert_eq!(urls.lock().unwrap().len(), 1);
+    assert_eq!(client.last_response_meta().unwrap().warnings, vec!["paginaton".to_string()]);
+}
+
+#[test]
+fn test_attribute_warnings_reach_on_response() {
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let seen = warnings.clone();
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(Arc::new(Mutex::new(Vec::new()))))
+        .on_response(move |meta| seen.lock().unwrap().push(meta.warnings.clone()))
+        .build();
+
+    let attributes = AttributesSelect { include: vec!["facets".to_string(), "resuts".to_string()], exclude: vec![] };
+    client.search("baseball", false, Some(attributes), None, None, None, None).unwrap();
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+
+    assert_eq!(*warnings.lock().unwrap(), vec![vec!["resuts".to_string()], vec![]]);
+}
+
+#[test]
+fn test_default_base_url_is_canonical() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder().base_url(DEFAULT_BASE_URL).middleware(Recorder(urls.clone())).build();
+
+    client.search("baseball", false, None, None, Some(10), Some(2), None).unwrap();
+    client.get_item("2014717546", None).unwrap();
+
+    let urls = urls.lock().unwrap();
+    assert_eq!(urls[0], "https://www.loc.gov/search/?fo=json&q=baseball&c=10&sp=2");
+    assert_eq!(urls[1], "https://www.loc.gov/item/2014717546/?fo=json");
+}
+
+#[test]
+fn test_default_paging_and_sort() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(urls.clone()))
+        .default_per_page(100)
+        .default_sort(SortField::DateDesc)
+        .build();
+
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+    client.search("baseball", false, None, None, Some(10), Some(2), Some(SortField::Date.then(SortField::TitleS))).unwrap();
+
+    let urls = urls.lock().unwrap();
+    assert!(urls[0].contains("&c=100") && urls[0].contains("&sb=date_desc"));
+    assert!(urls[1].contains("&c=10") && urls[1].contains("&sp=2") && urls[1].contains("&sb=date,title_s"));
+}
+
+#[test]
+fn test_redirect_chain() {
+    let moved = "HTTP/1.1 301 Moved Permanently\r\nLocation: /search/?fo=json&q=moved\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string();
+    let ok = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        SEARCH_PAGE.len(),
+        SEARCH_PAGE
+    );
+    let base_url = serve_raw(vec![moved, ok]);
+    let client = ApiClient::builder().base_url(&base_url).build();
+
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+
+    let meta = client.last_response_meta().unwrap();
+    assert_eq!(meta.redirects, vec![meta.final_url.clone()]);
+    assert!(meta.final_url.contains("q=baseball"));
+    assert_eq!(meta.resolved_url, format!("{}/search/?fo=json&q=moved", base_url));
+}
+
+#[test]
+fn test_get_item_from_search_result() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(urls.clone()))
+        .build();
+
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    let result = &response.results.unwrap()[0];
+    client.get_item(result.item_id().unwrap(), None).unwrap();
+    client.get_item("/item/2014717546/", None).unwrap();
+
+    let urls = urls.lock().unwrap();
+    assert!(urls[1].starts_with("http://unreachable.invalid/item/2014717546/?fo=json"));
+    assert_eq!(urls[1], urls[2]);
+}
+
+#[test]
+fn test_get_resource_page() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(urls.clone()))
+        .build();
+
+    client.get_resource("mal.0440500", None, Some(3)).unwrap();
+
+    let urls = urls.lock().unwrap();
+    assert!(urls[0].starts_with("http://unreachable.invalid/resource/mal.0440500/?fo=json"));
+    assert!(urls[0].ends_with("&sp=3"));
+}
+
+#[test]
+fn test_catalog_records_from_item_urls() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder().middleware(Recorder(urls.clone())).build();
+
+    client.get_marcxml("https://www.loc.gov/item/2014717546/").unwrap();
+    client.get_mods(ItemId::parse("https://lccn.loc.gov/2014717546").unwrap()).unwrap();
+
+    // Each record is looked up in the item first, which lists no renditions here.
+    let urls = urls.lock().unwrap();
+    assert!(urls[0].starts_with("https://www.loc.gov/item/2014717546/?fo=json"));
+    assert_eq!(urls[1], "https://lccn.loc.gov/2014717546/marcxml");
+    assert_eq!(urls[3], "https://lccn.loc.gov/2014717546/mods");
+}
+
+#[test]
+fn test_catalog_records_from_item_resources() {
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Routes(vec![
+            (
+                "/item/2014717546/",
+                r#"{"resources": [{"url": "https://www.loc.gov/resource/bbc.0001f/"}, {"files": [[{"url": "https://catalog.invalid/2014717546/marcxml/"}]]}]}"#,
+            ),
+            ("catalog.invalid/2014717546/marcxml", "<record/>"),
+        ]))
+        .build();
+
+    let (marcxml, url) = client.get_marcxml("2014717546").unwrap();
+    assert_eq!(marcxml, "<record/>");
+    assert_eq!(url, "https://catalog.invalid/2014717546/marcxml/");
+}
+
+#[test]
+fn test_word_coordinates() {
+    let base_url = serve(r#"{"width": "600", "height": "800", "coords": {"Baseball": [[10, 20, 30, 40]], "Game": [["5", "6", "7", "8"]]}}"#, 1);
+    let detail: ResourceDetail = serde_json::from_value(serde_json::json!({ "word_coordinates": format!("{}/coords.json", base_url) })).unwrap();
+    let client = ApiClient::new();
+
+    let (coordinates, _) = client.get_word_coordinates(&detail, "baseball").unwrap();
+    assert_eq!(coordinates.height, Some(800.0));
+    assert_eq!(coordinates.words.len(), 1);
+    assert_eq!(coordinates.words[0].boxes[0], WordBox { x: 10.0, y: 20.0, w: 30.0, h: 40.0 });
+}
+
+#[test]
+fn test_get_iiif_info() {
+    let base_url = serve(
+        r#"{"id": "https://tile.invalid/iiif/service:pnp:bbc:0001f", "type": "ImageService3", "profile": "level2",
+            "width": 3000, "height": 4000, "tiles": [{"width": 512, "height": 256, "scaleFactors": [1, 2, 4]}]}"#,
+        1,
+    );
+    let file: File = serde_json::from_value(serde_json::json!({ "info": format!("{}/info.json", base_url) })).unwrap();
+    let client = ApiClient::new();
+
+    let (info, _) = client.get_iiif_info(&file).unwrap();
+    assert_eq!((info.width, info.height), (3000, 4000));
+    assert_eq!(info.tiles[0].height, Some(256));
+    assert_eq!(info.compliance_level(), Some("level2"));
+    assert_eq!(info.size_url(5000).unwrap(), "https://tile.invalid/iiif/service:pnp:bbc:0001f/full/3000,/0/default.jpg");
+    let no_info: File = serde_json::from_str("{}").unwrap();
+    assert!(client.get_iiif_info(&no_info).is_err());
+}
+
+/// Answers requests whose URL contains a route's pattern with that route's body.
+struct Routes(Vec<(&'static str, &'static str)>);
+
+impl Middleware for Routes {
+    fn handle_request(&self, request: &mut Request) -> Option<Response> {
+        self.0
+            .iter()
+            .find(|(pattern, _)| request.url.contains(pattern))
+            .map(|(_, body)| Response::ok(*body))
+    }
+}
+
+#[test]
+fn test_get_page_text_from_alto() {
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Routes(vec![
+            ("/resource/sn83045462/1914-07-28/ed-1/", r#"{"resource": {"fulltext_file": "//tile.invalid/0001.xml"}}"#),
+            (
+                "tile.invalid/0001.xml",
+                r#"<alto><TextLine><String CONTENT="Play"/><String CONTENT="&amp;"/><String CONTENT="Ball"/></TextLine><TextLine><String CONTENT="Today"/></TextLine></alto>"#,
+            ),
+        ]))
+        .build();
+
+    let (text, url) = client
+        .get_page_text("https://www.loc.gov/resource/sn83045462/1914-07-28/ed-1/?sp=1", 1)
+        .unwrap();
+    assert_eq!(text, "Play & Ball\nToday\n");
+    assert_eq!(url, "https://tile.invalid/0001.xml");
+}
+
+#[test]
+fn test_get_page_text_reads_alto_markup() {
+    let alto = concat!(
+        r#"<alto:alto xmlns:alto="http://www.loc.gov/standards/alto/ns-v4#"><alto:TextLine>"#,
+        r#"<alto:String CONTENT='&quot;Tinker' /><alto:SP/><alto:String CONTENT="&amp;#x20AC;&#x20AC;&#8212;"/><alto:SP/>"#,
+        r#"<alto:String CONTENT="Evers" SUBS_TYPE="HypPart1" SUBS_CONTENT="Everslike"/><alto:HYP CONTENT="-"/></alto:TextLine>"#,
+        r#"<alto:TextLine><alto:String CONTENT="like" SUBS_TYPE="HypPart2" SUBS_CONTENT="Everslike"/><alto:SP/>"#,
+        r#"<alto:String CONTENT="Chance&apos;s"/><alto:SP/><alto:String CONTENT="dou"/><alto:HYP CONTENT="-"/></alto:TextLine>"#,
+        r#"<alto:TextLine><alto:String CONTENT="ble"/></alto:TextLine></alto:alto>"#,
+    );
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Routes(vec![
+            ("/resource/sn83045462/1914-07-28/ed-1/", r#"{"resource": {"fulltext_file": "//tile.invalid/0001.xml"}}"#),
+            ("tile.invalid/0001.xml", alto),
+        ]))
+        .build();
+
+    let (text, _) = client
+        .get_page_text("https://www.loc.gov/resource/sn83045462/1914-07-28/ed-1/?sp=1", 1)
+        .unwrap();
+    assert_eq!(text, "\"Tinker &#x20AC;\u{20AC}\u{2014} Everslike\nChance's dou-\nble\n");
+}
+
+#[test]
+fn test_download_all_pages() {
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Routes(vec![
+            (
+                "/item/2014717546/",
+                r#"{"resources": [{"files": [
+                    [{"mimetype": "image/jpeg", "width": 150, "url": "http://img.invalid/1-small.jpg"}, {"mimetype": "image/jpeg", "width": 1024, "url": "http://img.invalid/1-large.jpg"}],
+                    [{"mimetype": "image/gif", "width": 150, "url": "http://img.invalid/2-small.gif"}]
+                ]}]}"#,
+            ),
+            ("1-large.jpg", "large"),
+            ("2-small.gif", "gif"),
+        ]))
+        .build();
+    let dest = std::env::temp_dir().join(format!("loc_api_pages_{}", std::process::id()));
+
+    let manifest = client.download_all_pages("2014717546", &dest, ImageSize::Medium).unwrap();
+
+    assert_eq!(manifest.len(), 2);
+    assert_eq!(manifest[0].path, dest.join("0001.jpg"));
+    assert_eq!(std::fs::read_to_string(&manifest[0].path).unwrap(), "large");
+    assert_eq!(manifest[1].path, dest.join("0002.gif"));
+    std::fs::remove_dir_all(&dest).unwrap();
+}
+
+#[test]
+fn test_bulk_downloader_report() {
+    let unavailable = "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string();
+    let ok = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello".to_string();
+    let missing = "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string();
+    let base_url = serve_raw(vec![unavailable, ok, missing]);
+    let dest = std::env::temp_dir().join(format!("loc_api_bulk_{}", std::process::id()));
+
+    let client = ApiClient::new();
+    let urls = ["a.txt", "b.txt"].map(|name| format!("{}/files/{}", base_url, name));
+    let report = BulkDownloader::new(&client)
+        .concurrency(1)
+        .backoff(Duration::from_millis(10))
+        .run(urls.clone(), &dest);
+
+    assert_eq!(report.succeeded.len(), 1);
+    assert_eq!(std::fs::read_to_string(&report.succeeded[0].path).unwrap(), "hello");
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].attempts, 1);
+
+    // The server is gone: a restarted run skips the file it already has.
+    let again = BulkDownloader::new(&client).run(vec![urls[0].clone()], &dest);
+    assert_eq!(again.skipped[0].path, report.succeeded[0].path);
+    std::fs::remove_dir_all(&dest).unwrap();
+}
+
+#[test]
+fn test_bulk_downloader_keeps_urls_sharing_a_file_name_apart() {
+    let client = ApiClient::offline();
+    let urls = vec![
+        "https://www.loc.gov/default.jpg?q=baseball".to_string(),
+        "https://tile.loc.gov/default.jpg?q=baseball".to_string(),
+        "https://tile.loc.gov/default.jpg?q=baseball".to_string(),
+    ];
+    let dest = std::env::temp_dir().join(format!("loc_api_bulk_names_{}", std::process::id()));
+
+    let report = BulkDownloader::new(&client).concurrency(3).run(urls.clone(), &dest);
+    assert_eq!(report.succeeded.len(), 2);
+    assert_eq!(report.skipped.len(), 1);
+    assert_ne!(report.succeeded[0].path, report.succeeded[1].path);
+    for file in &report.succeeded {
+        assert!(file.path.file_name().unwrap().to_str().unwrap().starts_with("default-"));
+        assert_eq!(std::fs::read(&file.path).unwrap().len() as u64, file.bytes);
+    }
+
+    // A run killed while writing leaves only a `.part` file, so the restarted run downloads the
+    // file again and skips the complete one.
+    let interrupted = &report.succeeded[0];
+    let mut part = interrupted.path.clone().into_os_string();
+    part.push(".part");
+    std::fs::rename(&interrupted.path, &part).unwrap();
+    std::fs::write(&part, "trunc").unwrap();
+    let again = BulkDownloader::new(&client).run(urls, &dest);
+    assert_eq!(again.succeeded.len(), 1);
+    assert_eq!(again.succeeded[0].path, interrupted.path);
+    assert_eq!(again.skipped.len(), 2);
+    assert_eq!(std::fs::read(&interrupted.path).unwrap().len() as u64, interrupted.bytes);
+    assert_eq!(std::fs::read_dir(&dest).unwrap().count(), 2);
+    std::fs::remove_dir_all(&dest).unwrap();
+}
+
+struct RateLimited(Arc<Mutex<Vec<Duration>>>);
+
+impl Metrics for RateLimited {
+    fn rate_limited(&self, _url: &str, wait: Duration) {
+        self.0.lock().unwrap().push(wait);
+    }
+}
+
+#[test]
+fn test_throttle_delays_requests_over_burst() {
+    let base_url = serve(SEARCH_PAGE, 2);
+    let waits = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url(base_url)
+        .metrics(RateLimited(waits.clone()))
+        .throttle(ThrottleConfig { requests_per_minute: 600, burst: 1, ..ThrottleConfig::default() })
+        .build();
+
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+
+    let waits = waits.lock().unwrap();
+    assert_eq!(waits.len(), 1);
+    assert!(waits[0] > Duration::from_millis(50) && waits[0] <= Duration::from_millis(100));
+}
+
+#[derive(Clone, Default)]
+struct CacheHits(Arc<Mutex<Vec<String>>>);
+
+impl Metrics for CacheHits {
+    fn cache_hit(&self, url: &str) {
+        self.0.lock().unwrap().push(url.to_string());
+    }
+}
+
+#[test]
+fn test_cache_dir_serves_repeated_requests() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let dir = std::env::temp_dir().join(format!("loc_api_cache_{}", std::process::id()));
+    let hits = CacheHits::default();
+    let client = ApiClient::builder()
+        .base_url(base_url)
+        .cache_dir(&dir)
+        .metrics(hits.clone())
+        .build();
+
+    let (first, url) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    let (second, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+
+    assert_eq!(first.results.unwrap().len(), second.results.unwrap().len());
+    assert_eq!(*hits.0.lock().unwrap(), vec![url]);
+    // The cached response replays the status and headers it was received with.
+    let meta = client.last_response_meta().unwrap();
+    assert_eq!(meta.status, 200);
+    assert_eq!(meta.header("x-test"), Some("yes"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_cache_dir_skips_expired_and_non_json_responses() {
+    let dir = std::env::temp_dir().join(format!("loc_api_cache_ttl_{}", std::process::id()));
+    let hits = CacheHits::default();
+    let client = ApiClient::builder()
+        .base_url(serve(SEARCH_PAGE, 2))
+        .cache_dir(&dir)
+        .cache_ttl(Duration::ZERO)
+        .metrics(hits.clone())
+        .build();
+
+    // Both requests reach the server, since nothing is fresh for a zero TTL.
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert!(hits.0.lock().unwrap().is_empty());
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let base_url = serve("<urlset><url><loc>https://www.loc.gov/item/2014717546/</loc></url></urlset>", 1);
+    let client = ApiClient::builder().base_url(&base_url).cache_dir(&dir).build();
+    client.get_sitemap(&format!("{}/sitemap.xml", base_url)).unwrap();
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_clones_share_cache_and_meta() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let dir = std::env::temp_dir().join(format!("loc_api_clone_cache_{}", std::process::id()));
+    let client = ApiClient::builder().base_url(base_url).cache_dir(&dir).build();
+
+    let worker = client.clone();
+    let url = thread::spawn(move || worker.search("baseball", false, None, None, None, None, None).unwrap().1)
+        .join()
+        .unwrap();
+
+    // The server answers a single request, so the clone's response must come from the cache.
+    assert_eq!(client.last_response_meta().unwrap().final_url, url);
+    assert!(client.search("baseball", false, None, None, None, None, None).is_ok());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_client_pool_keeps_input_order() {
+    let client = ApiClient::offline();
+    let item = |id: &str| Endpoints::Item { item_id: id.to_string(), params: ItemParams::default() };
+    let endpoints = vec![item("2014717546"), item("missing"), item("2014717546"), item("missing")];
+
+    let results = ClientPool::new(&client).workers(3).fetch_all::<ItemResponse>(&endpoints);
+
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_ok() && results[2].is_ok());
+    for result in [&results[1], &results[3]] {
+        let error = result.as_ref().unwrap_err();
+        assert_eq!(error.status, Some(404));
+        assert!(error.url.contains("/item/missing/"));
+    }
+}
+
+#[test]
+fn test_client_pool_fetch_items_counts_retries() {
+    let unavailable = "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string();
+    let body = r#"{"item": {"title": "Baseball"}}"#;
+    let ok = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+    let base_url = serve_raw(vec![unavailable.clone(), unavailable, ok]);
+    let client = ApiClient::builder().base_url(base_url).build();
+
+    let report = ClientPool::new(&client).workers(1).retries(2).fetch_items(&[ItemId::from("2014717546")]);
+
+    assert_eq!(report.items().count(), 1);
+    assert_eq!(report.stats.retries, 2);
+    assert_eq!(report.stats.succeeded, 1);
+}
+
+#[cfg(feature = "async")]
+#[tokio::test(flavor = "current_thread")]
+async fn test_async_client_search() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let client = loc_api::async_client::AsyncApiClient::with_base_url(base_url.clone());
+
+    let (response, url) = client.search("baseball", false, None, None, None, None, None).await.unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+    assert!(url.starts_with(&base_url));
+}
+
+#[cfg(feature = "ureq")]
+#[test]
+fn test_ureq_transport() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let client = ApiClient::builder()
+        .base_url(base_url)
+        .transport(loc_api::transport::UreqTransport::default())
+        .build();
+
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+    assert_eq!(client.last_response_meta().unwrap().header("X-Test"), Some("yes"));
+}
+
+#[cfg(feature = "ureq")]
+#[test]
+fn test_ureq_transport_pool_options() {
+    use loc_api::transport::{PoolConfig, TransportOptions, UreqTransport};
+
+    let pool = PoolConfig { max_idle_per_host: Some(2), max_idle: Some(8), ..Default::default() };
+    assert!(UreqTransport::with_options(&TransportOptions { pool, ..Default::default() }).is_ok());
+
+    let http2 = PoolConfig { http2_prior_knowledge: true, ..Default::default() };
+    assert!(UreqTransport::with_options(&TransportOptions { pool: http2, ..Default::default() }).is_err());
+}
+
+#[test]
+fn test_pool_options_keep_connections_working() {
+    let base_url = serve(SEARCH_PAGE, 3);
+    let client = ApiClient::builder()
+        .base_url(base_url)
+        .pool_max_idle_per_host(1)
+        .pool_max_idle(4)
+        .pool_idle_timeout(Duration::from_secs(5))
+        .tcp_keepalive(Duration::from_secs(30))
+        .build();
+
+    for batch in 0..3 {
+        let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+        assert_eq!(response.results.unwrap().len(), 1, "batch {}", batch);
+        client.recycle_connections();
+    }
+}
+
+#[test]
+fn test_resolve_overrides_dns() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let port = base_url.rsplit(':').next().unwrap();
+    let client = ApiClient::builder()
+        .base_url(format!("http://mirror.loc.invalid:{}", port))
+        .resolve("Mirror.loc.invalid", std::net::Ipv4Addr::LOCALHOST.into())
+        .build();
+
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+}
+
+#[cfg(feature = "ureq")]
+#[test]
+fn test_ureq_transport_resolve() {
+    use loc_api::transport::{TransportOptions, UreqTransport};
+
+    let base_url = serve(SEARCH_PAGE, 1);
+    let port = base_url.rsplit(':').next().unwrap();
+    let options = TransportOptions {
+        resolve: vec![("mirror.loc.invalid".to_string(), std::net::Ipv4Addr::LOCALHOST.into())],
+        ..Default::default()
+    };
+    let client = ApiClient::builder()
+        .base_url(format!("http://mirror.loc.invalid:{}", port))
+        .transport(UreqTransport::with_options(&options).unwrap())
+        .build();
+
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+}
+
+type Headers = Vec<(String, String)>;
+
+/// Answers every request with `SEARCH_PAGE` and records the headers it was sent with.
+struct HeaderRecorder(Arc<Mutex<Vec<Headers>>>);
+
+impl loc_api::transport::HttpTransport for HeaderRecorder {
+    fn get(&self, request: &Request) -> Result<Response, loc_api::transport::TransportError> {
+        self.0.lock().unwrap().push(request.headers.clone());
+        Ok(Response::ok(SEARCH_PAGE))
+    }
+}
+
+/// Answers with a `503` until `failures` requests were made, then with [`SEARCH_PAGE`].
+struct Flaky {
+    failures: usize,
+    requests: Arc<Mutex<usize>>,
+}
+
+impl loc_api::transport::HttpTransport for Flaky {
+    fn get(&self, _request: &Request) -> Result<Response, loc_api::transport::TransportError> {
+        let mut requests = self.requests.lock().unwrap();
+        *requests += 1;
+        if *requests <= self.failures {
+            return Ok(Response { status: 503, headers: Vec::new(), body: Vec::new() });
+        }
+        Ok(Response::ok(SEARCH_PAGE))
+    }
+}
+
+#[test]
+fn test_fetch_retries_transient_failures() {
+    let endpoint = Endpoints::Search(SearchParams { common: CommonParams::default(), include_collections: false });
+    let flaky = |failures: usize, requests: &Arc<Mutex<usize>>| Flaky { failures, requests: requests.clone() };
+
+    let requests = Arc::new(Mutex::new(0));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .transport(flaky(1, &requests))
+        .retries(2)
+        .retry_backoff(Duration::from_millis(1))
+        .build();
+    let (page, _) = client.fetch::<loc_api::response_models::SearchResultResponse>(&endpoint).unwrap();
+    assert_eq!(page.results.unwrap().len(), 1);
+    assert_eq!(*requests.lock().unwrap(), 2);
+    assert_eq!(client.last_response_meta().unwrap().retries, 1);
+
+    // Without retries, the 503 is returned.
+    let requests = Arc::new(Mutex::new(0));
+    let client = ApiClient::builder().base_url("http://unreachable.invalid").transport(flaky(1, &requests)).build();
+    let error = client.fetch::<loc_api::response_models::SearchResultResponse>(&endpoint).unwrap_err();
+    assert_eq!(error.downcast_ref::<loc_api::loc_client::HttpStatusError>().unwrap().status, 503);
+    assert_eq!(*requests.lock().unwrap(), 1);
+}
+
+#[test]
+fn test_default_headers_on_every_request() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .transport(HeaderRecorder(sent.clone()))
+        .default_header("From", "old@example.edu")
+        .default_header("from", "archivist@example.edu")
+        .default_header("X-Institution", "example")
+        .build();
+
+    client.search("baseball", false, None, None, None, None, None).unwrap();
+    let dest = std::env::temp_dir().join(format!("loc_api_headers_{}", std::process::id()));
+    let report = BulkDownloader::new(&client).run(vec!["http://unreachable.invalid/files/a.json".to_string()], &dest);
+    assert_eq!(report.succeeded.len(), 1);
+    std::fs::remove_dir_all(&dest).unwrap();
+
+    #[allow(unused_mut)]
+    let mut expected = vec![
+        ("from".to_string(), "archivist@example.edu".to_string()),
+        ("X-Institution".to_string(), "example".to_string()),
+    ];
+    #[cfg(all(feature = "gzip", not(feature = "brotli")))]
+    expected.push(("accept-encoding".to_string(), "gzip".to_string()));
+    #[cfg(all(feature = "gzip", feature = "brotli"))]
+    expected.push(("accept-encoding".to_string(), "gzip, br".to_string()));
+    #[cfg(all(feature = "brotli", not(feature = "gzip")))]
+    expected.push(("accept-encoding".to_string(), "br".to_string()));
+    assert_eq!(*sent.lock().unwrap(), vec![expected.clone(), expected]);
+}
+
+#[test]
+fn test_requests_go_through_http_proxy() {
+    let proxy = serve(SEARCH_PAGE, 1);
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .http_proxy(proxy)
+        .no_proxy(["localhost"])
+        .build();
+
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+
+    assert!(ApiClient::builder().proxy("not a proxy url").try_build().is_err());
+}
+
+#[test]
+fn test_download_timeout_overrides_client_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    thread::spawn(move || {
+        for stream in listener.incoming().take(2) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            thread::sleep(Duration::from_millis(300));
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\nConnection: close\r\n\r\nslow");
+        }
+    });
+
+    let client = ApiClient::builder()
+        .timeout(Duration::from_millis(50))
+        .download_timeout(Duration::from_secs(5))
+        .build();
+    let dest = std::env::temp_dir().join(format!("loc_api_timeout_{}", std::process::id()));
+    let url = format!("{}/files/slow.txt", base_url);
+
+    let report = BulkDownloader::new(&client).retries(0).timeout(Duration::from_millis(50)).run(vec![url.clone()], &dest);
+    assert_eq!(report.failed.len(), 1);
+    let report = BulkDownloader::new(&client).retries(0).run(vec![url], &dest);
+    assert_eq!(report.succeeded.len(), 1);
+    std::fs::remove_dir_all(&dest).unwrap();
+}
+
+#[test]
+fn test_search_stream_follows_next_links() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let next_base = base_url.clone();
+    thread::spawn(move || {
+        for (request, stream) in listener.incoming().take(5).enumerate() {
+            let page = request % 3;
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            let next = if page < 2 { format!("\"{}/search/?q=maps&sp={}&fo=json\"", next_base, page + 2) } else { "null".to_string() };
+            let body = format!(r#"{{"pagination": {{"current": {}, "next": {}}}, "results": []}}"#, page + 1, next);
+            let _ = write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+        }
+    });
+
+    let client = ApiClient::builder().base_url(base_url).build();
+    let pages: Vec<_> = client.search_stream("maps", false, None, None, None, None).map(|page| page.unwrap()).collect();
+    assert_eq!(pages.len(), 3);
+
+    let mut seen = Vec::new();
+    let consumed = client
+        .search_stream("maps", false, None, None, None, None)
+        .max_pages(2)
+        .for_each_prefetched(1, |page| {
+            seen.push(page.pagination.and_then(|p| p.current).and_then(|n| n.as_u64()).unwrap_or(0));
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(consumed, 2);
+    assert_eq!(seen, vec![1, 2]);
+}
+
+#[test]
+fn test_prefetch_yields_pages_in_order() {
+    let base_url = serve_search_pages(4);
+    let client = ApiClient::builder().base_url(base_url).build();
+
+    let ids: Vec<String> = client
+        .search_stream("maps", false, None, None, None, None)
+        .prefetch(2)
+        .flat_map(|page| page.unwrap().results.unwrap())
+        .map(|item| item.item_id().unwrap().to_string())
+        .collect();
+    assert_eq!(ids, vec!["1a", "1b", "2a", "2b", "3a", "3b", "4a", "4b"]);
+
+    let mut pages = client.search_stream("maps", false, None, None, None, None).max_pages(3).prefetch(0);
+    assert!(pages.next().unwrap().is_ok());
+    assert_eq!(pages.count(), 2);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_map_results_keeps_page_order() {
+    let base_url = serve_search_pages(4);
+    let client = ApiClient::builder().base_url(base_url).build();
+    let ids = client
+        .search_stream("maps", false, None, None, None, None)
+        .par_map_results(|item| item.item_id().unwrap().to_string())
+        .unwrap();
+    assert_eq!(ids, vec!["1a", "1b", "2a", "2b", "3a", "3b", "4a", "4b"]);
+
+    let ids = client
+        .search_stream("maps", false, None, None, None, None)
+        .max_pages(2)
+        .par_map_results(|item| item.item_id().unwrap().to_string())
+        .unwrap();
+    assert_eq!(ids, vec!["1a", "1b", "2a", "2b"]);
+}
+
+#[test]
+fn test_stream_results_yields_items() {
+    let body = r#"{"facets": [], "results": [{"id": "http://www.loc.gov/item/1/"}, {"id": "http://www.loc.gov/item/2/"}, {"id": "http://www.loc.gov/item/3/"}], "pagination": {"current": 1}}"#;
+    let base_url = serve_raw(vec![
+        "HTTP/1.1 301 Moved Permanently\r\nLocation: /moved/?fo=json\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+        format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body),
+        format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body),
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+    ]);
+    let responses = Arc::new(Mutex::new(Vec::new()));
+    let seen = responses.clone();
+    let client = ApiClient::builder()
+        .base_url(base_url)
+        .on_response(move |meta| seen.lock().unwrap().push((meta.status, meta.resolved_url.clone(), meta.redirects.len())))
+        .build();
+    let endpoint = Endpoints::Search(SearchParams { common: CommonParams::default(), include_collections: false });
+
+    let ids: Vec<String> = client
+        .stream_results(&endpoint)
+        .unwrap()
+        .map(|item| item.unwrap().item_id().unwrap().to_string())
+        .collect();
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    assert_eq!(client.last_response_meta().unwrap().body_size, body.len());
+
+    let first = client.stream_results(&endpoint).unwrap().next().unwrap().unwrap();
+    assert_eq!(first.item_id().unwrap().as_str(), "1");
+
+    assert!(client.stream_results(&endpoint).is_err());
+
+    let responses = responses.lock().unwrap();
+    assert_eq!(responses.iter().map(|(status, _, _)| *status).collect::<Vec<_>>(), vec![200, 200, 404]);
+    assert!(responses[0].1.ends_with("/moved/?fo=json") && responses[0].2 == 1);
+}
+
+#[derive(Clone, Default)]
+struct Completed(Arc<Mutex<Vec<Option<u16>>>>);
+
+impl Metrics for Completed {
+    fn request_completed(&self, _url: &str, status: Option<u16>, _elapsed: Duration) {
+        self.0.lock().unwrap().push(status);
+    }
+}
+
+/// Redirects every request to another page of itself.
+struct RedirectLoop;
+
+impl loc_api::transport::HttpTransport for RedirectLoop {
+    fn get(&self, request: &Request) -> Result<Response, loc_api::transport::TransportError> {
+        let location = format!("{}x", request.url);
+        Ok(Response { status: 302, headers: vec![("location".to_string(), location)], body: Vec::new() })
+    }
+}
+
+#[test]
+fn test_stream_results_uses_the_request_pipeline() {
+    let endpoint = Endpoints::Search(SearchParams { common: CommonParams::default(), include_collections: false });
+
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(urls.clone()))
+        .build();
+    assert_eq!(client.stream_results(&endpoint).unwrap().count(), 1);
+    assert_eq!(urls.lock().unwrap().len(), 1);
+    assert_eq!(client.last_response_meta().unwrap().body_size, SEARCH_PAGE.len());
+
+    let requests = Arc::new(Mutex::new(0));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .transport(Flaky { failures: 1, requests: requests.clone() })
+        .retries(1)
+        .retry_backoff(Duration::from_millis(1))
+        .build();
+    assert_eq!(client.stream_results(&endpoint).unwrap().count(), 1);
+    assert_eq!(*requests.lock().unwrap(), 2);
+    assert_eq!(client.last_response_meta().unwrap().retries, 1);
+
+    let completed = Completed::default();
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .transport(RedirectLoop)
+        .metrics(completed.clone())
+        .build();
+    assert!(client.stream_results(&endpoint).is_err());
+    assert_eq!(*completed.0.lock().unwrap(), vec![None]);
+}
+
+/// Answers every request with a facets-only page and records the requested URLs.
+struct FacetsOnly(Arc<Mutex<Vec<String>>>);
+
+impl Middleware for FacetsOnly {
+    fn handle_request(&self, request: &mut Request) -> Option<Response> {
+        self.0.lock().unwrap().push(request.url.clone());
+        Some(Response::ok(r#"{"facets": [{"type": "subject", "filters": [{"title": "sports", "count": 42}]}]}"#))
+    }
+}
+
+#[test]
+fn test_get_facets_requests_no_results() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(FacetsOnly(urls.clone()))
+        .default_per_page(100)
+        .build();
+
+    let filters = FacetReq { filters: vec![Facet::Location { value: "ohio".to_string() }] };
+    let (facets, _) = client.get_facets("baseball cards", Some(filters)).unwrap();
+    assert_eq!(facets.len(), 1);
+    assert_eq!(facets[0].name(), Some("subject"));
+
+    let urls = urls.lock().unwrap();
+    assert_eq!(urls[0], "http://unreachable.invalid/search/?fo=json&at=facets&q=baseball+cards&fa=location:ohio&c=0");
+}
+
+#[test]
+fn test_facet_drill_down() {
+    let urls = Arc::new(Mutex::new(Vec::new()));
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Recorder(urls.clone()))
+        .build();
+
+    let filter: FilterItem = serde_json::from_str(
+        r#"{"term": "baseball", "count": 12,
+            "on": "https://www.loc.gov/photos/?q=cards&fa=subject:baseball&st=list",
+            "off": "//www.loc.gov/photos/?q=cards",
+            "not": "https://www.loc.gov/photos/?q=cards&fa=subject!:baseball&fo=json"}"#,
+    )
+    .unwrap();
+    let (response, _) = client.apply_filter(&filter).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+    client.remove_filter(&filter).unwrap();
+    client.exclude_filter(&filter).unwrap();
+
+    let urls = urls.lock().unwrap();
+    assert_eq!(urls[0], "http://unreachable.invalid/photos/?fo=json&q=cards&fa=subject:baseball");
+    assert_eq!(urls[1], "http://unreachable.invalid/photos/?fo=json&q=cards");
+    assert_eq!(urls[2], "http://unreachable.invalid/photos/?fo=json&q=cards&fa=subject!:baseball");
+}
+
+#[cfg(feature = "record")]
+#[test]
+fn test_record_and_replay_cassette() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let cassette = std::env::temp_dir().join(format!("loc_api_cassette_{}", std::process::id())).join("search.json");
+
+    let recording = ApiClient::builder().base_url(base_url.clone()).record(&cassette).build();
+    let (recorded, _) = recording.search("baseball", false, None, None, None, None, None).unwrap();
+
+    // The server is gone: only the cassette can answer now.
+    let replaying = ApiClient::builder().base_url(base_url).replay(&cassette).build();
+    let (replayed, _) = replaying.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(replayed.results.unwrap().len(), recorded.results.unwrap().len());
+    assert!(replaying.search("football", false, None, None, None, None, None).is_err());
+
+    std::fs::remove_dir_all(cassette.parent().unwrap()).unwrap();
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_mock_server_fixtures() {
+    let server = loc_api::testing::MockServer::with_fixtures();
+    let client = server.client();
+
+    let (item, _) = client.get_item("2014717546", None).unwrap();
+    assert!(item.cite_this.is_some() && item.item.is_some());
+    let (resource, _) = client.get_resource("bbc.0001f", None, None).unwrap();
+    assert!(resource.resources.is_some());
+    let (collection, _) = client.get_collection("baseball cards", None, None, None, None, None, None).unwrap();
+    assert_eq!(collection.results.unwrap().len(), 1);
+    let (facets, _) = client.get_facets("baseball", None).unwrap();
+    assert_eq!(facets.len(), 2);
+
+    assert_eq!(server.requests()[0], "/item/2014717546/?fo=json");
+
+    // Registered mocks take precedence over the fixtures.
+    use loc_api::testing::wiremock::matchers::{path, query_param};
+    use loc_api::testing::wiremock::{Mock, ResponseTemplate};
+    server.register(
+        Mock::given(path("/search/"))
+            .and(query_param("q", "football"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1),
+    );
+    assert!(client.search("football", false, None, None, None, None, None).is_err());
+    assert!(client.search("baseball", false, None, None, None, None, None).is_ok());
+    server.verify();
+}
+
+/// Answers two requests with `wire` as a body encoded with `encoding` and returns the base URL
+/// of the local server with the `Accept-Encoding` values it received.
+#[cfg(any(feature = "gzip", feature = "brotli"))]
+fn serve_encoded(encoding: &'static str, wire: Vec<u8>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let accept_encoding = Arc::new(Mutex::new(Vec::new()));
+    let seen = accept_encoding.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming().take(2) {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                if let Some((_, value)) = line.split_once(':').filter(|(name, _)| name.eq_ignore_ascii_case("accept-encoding")) {
+                    seen.lock().unwrap().push(value.trim().to_string());
+                }
+                line.clear();
+            }
+            let head = format!(
+                "HTTP/1.1 200 OK\r\nContent-Encoding: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                encoding,
+                wire.len()
+            );
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(&wire).unwrap();
+        }
+    });
+    (base_url, accept_encoding)
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn test_gzip_responses_are_decompressed() {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    let body = SEARCH_PAGE.as_bytes();
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(body).unwrap();
+    let compressed = encoder.finish().unwrap();
+    let (base_url, accept_encoding) = serve_encoded("gzip", compressed.clone());
+
+    let client = ApiClient::builder().base_url(base_url).build();
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+
+    let meta = client.last_response_meta().unwrap();
+    assert_eq!(meta.body_size, body.len());
+    // `ureq` decompresses on its own, so only the reqwest transport hands over the wire bytes.
+    #[cfg(feature = "blocking")]
+    assert_eq!(meta.wire_size, compressed.len());
+
+    let endpoint = Endpoints::Search(SearchParams { common: CommonParams::default(), include_collections: false });
+    assert_eq!(client.stream_results(&endpoint).unwrap().count(), 1);
+    assert!(accept_encoding.lock().unwrap().iter().all(|value| value.contains("gzip")));
+}
+
+#[cfg(feature = "brotli")]
+#[test]
+fn test_brotli_responses_are_decompressed() {
+    let body = SEARCH_PAGE.as_bytes();
+    let mut compressed = Vec::new();
+    {
+        let mut encoder = brotli::CompressorWriter::new(&mut compressed, 4096, 11, 22);
+        encoder.write_all(body).unwrap();
+    }
+    let (base_url, accept_encoding) = serve_encoded("br", compressed.clone());
+
+    let client = ApiClient::builder().base_url(base_url).build();
+    let (response, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(response.results.unwrap().len(), 1);
+
+    let meta = client.last_response_meta().unwrap();
+    assert_eq!(meta.body_size, body.len());
+    assert_eq!(meta.wire_size, compressed.len());
+
+    let endpoint = Endpoints::Search(SearchParams { common: CommonParams::default(), include_collections: false });
+    assert_eq!(client.stream_results(&endpoint).unwrap().count(), 1);
+    assert!(accept_encoding.lock().unwrap().iter().all(|value| value.contains("br")));
+    #[cfg(feature = "gzip")]
+    assert!(accept_encoding.lock().unwrap().iter().all(|value| value == "gzip, br"));
+}
+
+#[test]
+fn test_feed_search_sorts_by_date() {
+    let base_url = serve(SEARCH_PAGE, 1);
+    let client = ApiClient::builder().base_url(base_url).build();
+    let params = SearchParams {
+        common: CommonParams { query: Some("baseball".to_string()), sort: SortField::TitleS.into(), ..CommonParams::default() },
+        include_collections: false,
+    };
+
+    let feed = Feed::search(&client, "Baseball", params).unwrap();
+    assert!(client.last_response_meta().unwrap().final_url.contains("sb=date_desc"));
+    assert_eq!(feed.link, "https://www.loc.gov/search/?q=baseball&sb=date_desc");
+    assert_eq!(feed.entries.len(), 1);
+    assert_eq!(feed.entries[0].link, "http://www.loc.gov/item/2014717546/");
+}
+
+#[test]
+fn test_sitemap_urls_follow_the_index() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let link_base = base_url.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+            let path = request_line.split(' ').nth(1).unwrap().to_string();
+            let body = match path.as_str() {
+                "/sitemap.xml" => format!(
+                    "<sitemapindex><sitemap><loc>{0}/a.xml</loc></sitemap><sitemap><loc>{0}/b.xml</loc><lastmod>2024-03-01</lastmod></sitemap></sitemapindex>",
+                    link_base
+                ),
+                _ => format!(
+                    "<urlset><url><loc>https://www.loc.gov/item/{0}1/</loc></url><url><loc>https://www.loc.gov/item/{0}2/</loc></url></urlset>",
+                    path.trim_start_matches('/').trim_end_matches(".xml")
+                ),
+            };
+            let _ = write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+        }
+    });
+    let client = ApiClient::builder().base_url(&base_url).build();
+
+    let (index, _) = client.get_sitemap(&format!("{}/sitemap.xml", base_url)).unwrap();
+    assert!(matches!(&index, Sitemap::Index(entries) if entries[1].lastmod.as_deref() == Some("2024-03-01")));
+
+    let pages = client.sitemap_urls(&format!("{}/sitemap.xml", base_url), 1).unwrap();
+    let ids: Vec<String> = item_ids(&pages).iter().map(|id| id.to_string()).collect();
+    assert_eq!(ids, ["a1", "a2", "b1", "b2"]);
+    assert!(client.sitemap_urls(&format!("{}/sitemap.xml", base_url), 0).is_err());
+}
+
+#[test]
+fn test_get_items_bulk_reports_partial_failures() {
+    let unavailable = "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string();
+    let body = r#"{"item": {"title": "Baseball"}}"#;
+    let ok = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+    let missing = "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string();
+    let base_url = serve_raw(vec![unavailable, ok, missing]);
+    let client = ApiClient::builder().base_url(base_url).build();
+
+    let ids = vec![ItemId::from("2014717546"), ItemId::from("missing")];
+    let report = client.get_items_bulk(&ids, 1);
+
+    assert_eq!(report.results.len(), 2);
+    assert_eq!(report.results[0].0, ids[0]);
+    assert!(report.results[0].1.is_ok());
+    assert_eq!(report.results[1].1.as_ref().unwrap_err().status, Some(404));
+    assert_eq!(
+        report.stats,
+        BulkStats { requested: 2, succeeded: 1, failed: 1, not_found: 1, retries: 1, elapsed: report.stats.elapsed }
+    );
+}
+
+/// Returns the HTTP status carried by a client error, if it is an [`HttpStatusError`].
+fn http_status(error: &(dyn std::error::Error + 'static)) -> Option<u16> {
+    error.downcast_ref::<loc_api::loc_client::HttpStatusError>().map(|e| e.status)
+}
+
+#[test]
+fn test_bad_ids_fail_before_any_request() {
+    let completed = Completed(Arc::new(Mutex::new(Vec::new())));
+    let client = ApiClient::builder()
+        .base_url(DEFAULT_BASE_URL)
+        .transport(loc_api::transport::OfflineTransport)
+        .metrics(Completed(completed.0.clone()))
+        .build();
+
+    assert!(client.get_item("", None).is_err());
+    assert!(client.get_item("  ", None).is_err());
+    assert!(client.get_item_with_raw("", None).is_err());
+    assert!(client.get_resource("", None, None).is_err());
+    assert!(client.get_resource_with_raw("", None, None).is_err());
+    assert!(client.resolve_lccn("").is_err());
+    assert!(client.resolve_lccn("https://www.loc.gov/search/?q=baseball").is_err());
+    assert!(client.get_marcxml("").is_err());
+    assert!(completed.0.lock().unwrap().is_empty());
+    assert!(client.last_response_meta().is_none());
+}
+
+#[test]
+fn test_error_statuses_surface_from_every_method() {
+    let completed = Completed(Arc::new(Mutex::new(Vec::new())));
+    let client = ApiClient::builder()
+        .base_url(DEFAULT_BASE_URL)
+        .transport(loc_api::transport::OfflineTransport)
+        .metrics(Completed(completed.0.clone()))
+        .build();
+
+    let errors = [
+        client.get_item("missing", None).unwrap_err(),
+        client.get_item_with_raw("https://www.loc.gov/item/missing/", None).unwrap_err(),
+        client.get_resource("missing", None, None).unwrap_err(),
+        client.get_resource_with_raw("missing", None, None).unwrap_err(),
+        client.get_collection("missing", None, None, None, None, None, None).unwrap_err(),
+        client.get_collection_with_raw("missing", None, None, None, None, None, None).unwrap_err(),
+        client.get_collections(Some("football"), None, None, None, None, None).unwrap_err(),
+        client.get_collections_with_raw(Some("football"), None, None, None, None, None).unwrap_err(),
+        client.search("football", false, None, None, None, None, None).unwrap_err(),
+        client.search_with_raw("football", false, None, None, None, None, None).unwrap_err(),
+        client.get_format(MediaType::Photos, Some("football"), None, None, None, None, None).unwrap_err(),
+        client.get_format_with_raw(MediaType::Photos, Some("football"), None, None, None, None, None).unwrap_err(),
+    ];
+    for error in &errors {
+        assert_eq!(http_status(error.as_ref()), Some(404), "{}", error);
+    }
+
+    let missing = Endpoints::Item { item_id: "missing".to_string(), params: ItemParams::default() };
+    let error = client.fetch_with_meta::<ItemResponse>(&missing).unwrap_err();
+    assert_eq!(http_status(error.as_ref()), Some(404));
+    assert!(client.search_stream("football", false, None, None, None, None).next().unwrap().is_err());
+
+    assert_eq!(client.last_response_meta().unwrap().status, 404);
+    let statuses = completed.0.lock().unwrap();
+    assert!(!statuses.is_empty());
+    assert!(statuses.iter().all(|status| *status == Some(404)));
+}
+
+#[test]
+fn test_malformed_payloads_are_errors() {
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Routes(vec![
+            ("/item/", "not json"),
+            ("/collections/", r#"{"results": 5}"#),
+            ("/photos/", r#"{"results": [1, 2]}"#),
+            ("/search/", r#"{"results": "#),
+            ("lccn.loc.gov/", "<html></html>"),
+        ]))
+        .build();
+
+    assert!(client.get_item("2014717546", None).is_err());
+    assert!(client.get_item_with_raw("2014717546", None).is_err());
+    assert!(client.search("baseball", false, None, None, None, None, None).is_err());
+    assert!(client.search_with_raw("baseball", false, None, None, None, None, None).is_err());
+    assert!(client.get_collection("baseball-cards", None, None, None, None, None, None).is_err());
+    assert!(client.get_collections_with_raw(None, None, None, None, None, None).is_err());
+    assert!(client
+        .get_format_typed::<ItemResponse>(MediaType::Photos, None, None, None, None, None, None)
+        .is_err());
+    let item = Endpoints::Item { item_id: "2014717546".to_string(), params: ItemParams::default() };
+    assert!(client.fetch_with_meta::<ItemResponse>(&item).is_err());
+    // The page came back without being redirected to an item, so there is nothing to resolve.
+    assert!(client.resolve_lccn("2014717546").is_err());
+}
+
+#[test]
+fn test_empty_results_are_not_errors() {
+    let empty = r#"{"results": [], "pagination": {"current": 1}}"#;
+    let client = ApiClient::builder()
+        .base_url("http://unreachable.invalid")
+        .middleware(Routes(vec![("unreachable.invalid/", empty)]))
+        .build();
+
+    let (search, _) = client.search("baseball", false, None, None, None, None, None).unwrap();
+    assert_eq!(search.results.unwrap().len(), 0);
+    let (collection, _) = client.get_collection("baseball-cards", None, None, None, None, None, None).unwrap();
+    assert_eq!(collection.results.unwrap().len(), 0);
+    let (format, _) = client.get_format(MediaType::Photos, None, None, None, None, None, None).unwrap();
+    assert_eq!(format.results.unwrap().len(), 0);
+
+    let pages: Vec<_> = client.search_stream("baseball", false, None, None, None, None).collect();
+    assert_eq!(pages.len(), 1);
+    assert!(pages[0].is_ok());
+
+    let endpoint = Endpoints::Search(SearchParams { common: CommonParams::default(), include_collections: false });
+    assert_eq!(client.stream_results(&endpoint).unwrap().count(), 0);
+}
@@ -0,0 +1,40 @@
+use loc_api::diff::{content_hash, diff, diff_jsonl};
+use loc_api::response_models::ResultItem;
+
+fn item(json: &str) -> ResultItem {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_diff_without_timestamps_compares_content() {
+    let old = vec![
+        item(r#"{"id": "http://www.loc.gov/item/1/", "title": "Baseball"}"#),
+        item(r#"{"id": "http://www.loc.gov/item/2/", "title": "Football"}"#),
+        item(r#"{"title": "No id"}"#),
+    ];
+    let new = vec![
+        item(r#"{"id": "http://www.loc.gov/item/2/", "title": "Football"}"#),
+        item(r#"{"id": "http://www.loc.gov/item/1/", "title": "Baseball cards"}"#),
+    ];
+
+    let changes = diff(old, new);
+    assert!(changes.added.is_empty() && changes.removed.is_empty());
+    assert_eq!(changes.changed.len(), 1);
+    assert_eq!(changes.changed[0].id, "1");
+    assert_ne!(content_hash(&changes.changed[0].old), content_hash(&changes.changed[0].new));
+}
+
+#[test]
+fn test_diff_jsonl_harvests() {
+    let old = "{\"id\": \"http://www.loc.gov/item/1/\", \"extract_timestamp\": \"2024-01-01\"}\n\n\
+               {\"id\": \"http://www.loc.gov/item/2/\", \"extract_timestamp\": \"2024-01-01\"}\n";
+    let new = "{\"id\": \"http://www.loc.gov/item/1/\", \"extract_timestamp\": \"2024-01-01\", \"title\": \"Ignored\"}\n\
+               {\"id\": \"http://www.loc.gov/item/3/\"}\n";
+
+    let changes = diff_jsonl(old.as_bytes(), new.as_bytes()).unwrap();
+    assert!(changes.changed.is_empty());
+    assert_eq!(changes.added[0].item_id().unwrap().to_string(), "3");
+    assert_eq!(changes.removed[0].item_id().unwrap().to_string(), "2");
+    assert!(!changes.is_empty());
+    assert!(diff_jsonl("not json".as_bytes(), "".as_bytes()).is_err());
+}
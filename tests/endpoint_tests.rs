@@ -0,0 +1,346 @@
+use loc_api::attribute_models::{AttributesError, AttributesSelect, ExcludedAttributes, ItemAttributes, ResourceAttributes, SortField};
+use loc_api::endpoints::Endpoints;
+use loc_api::format_models::{Format, MediaType};
+use loc_api::param_models::{CommonParams, Facet, FacetReq, ItemParams, ResourceParams, SearchParams};
+
+/// Parameters exercising every common query pair, with unsorted and duplicated values.
+fn full_params() -> CommonParams {
+    CommonParams {
+        format: None,
+        attributes: Some(AttributesSelect {
+            include: vec!["results".to_string(), "pagination".to_string(), "results".to_string()],
+            exclude: vec!["more_like_this".to_string()],
+        }),
+        query: Some("civil+war".to_string()),
+        filter: Some(FacetReq {
+            filters: vec![
+                Facet::Subject { value: "maps".to_string() },
+                Facet::Location { value: "new york".to_string() },
+                Facet::Subject { value: "maps".to_string() },
+            ],
+        }),
+        per_page: Some(50),
+        page: Some(2),
+        sort: Some(SortField::DateDesc.then(SortField::TitleS)),
+    }
+}
+
+const FULL_QUERY: &str = "fo=json&at=pagination,results&at!=more_like_this&q=civil+war&fa=location:new+york|subject:maps&c=50&sp=2&sb=date_desc,title_s";
+
+#[test]
+fn test_query_string_has_no_empty_pairs() {
+    let endpoint = Endpoints::Search(SearchParams {
+        common: CommonParams {
+            attributes: Some(AttributesSelect::default()),
+            query: Some("baseball".to_string()),
+            ..Default::default()
+        },
+        include_collections: false,
+    });
+
+    let url = endpoint.to_url().unwrap();
+    assert_eq!(url, "https://www.loc.gov/search/?fo=json&q=baseball");
+}
+
+#[test]
+fn test_query_values_are_encoded() {
+    let endpoint = Endpoints::Collections(CommonParams {
+        query: Some("rock & roll#1".to_string()),
+        ..Default::default()
+    });
+
+    let url = endpoint.to_url().unwrap();
+    assert_eq!(url, "https://www.loc.gov/collections/?fo=json&q=rock+%26+roll%231");
+}
+
+#[test]
+fn test_item_query_pairs() {
+    let endpoint = Endpoints::Item {
+        item_id: "2014717546".to_string(),
+        params: ItemParams {
+            format: None,
+            attributes: Some(ItemAttributes { item: Some(true), resources: Some(false), cite_this: Some(true), ..Default::default() }),
+        },
+    };
+
+    assert_eq!(
+        endpoint.query_pairs(),
+        vec![("fo", "json".to_string()), ("at", "cite_this,item".to_string())]
+    );
+}
+
+#[test]
+fn test_canonical_search_url() {
+    let endpoint = Endpoints::Search(SearchParams { common: full_params(), include_collections: false });
+    assert_eq!(endpoint.to_url().unwrap(), format!("https://www.loc.gov/search/?{}", FULL_QUERY));
+}
+
+#[test]
+fn test_canonical_collections_url() {
+    let endpoint = Endpoints::Collections(full_params());
+    assert_eq!(endpoint.to_url().unwrap(), format!("https://www.loc.gov/collections/?{}", FULL_QUERY));
+}
+
+#[test]
+fn test_canonical_collection_url() {
+    let endpoint = Endpoints::Collection { name: "civil war maps".to_string(), params: full_params() };
+    assert_eq!(
+        endpoint.to_url().unwrap(),
+        format!("https://www.loc.gov/collections/civil-war-maps/?{}", FULL_QUERY)
+    );
+}
+
+#[test]
+fn test_canonical_format_url() {
+    let endpoint = Endpoints::Format { format: MediaType::Maps, params: full_params() };
+    assert_eq!(endpoint.to_url().unwrap(), format!("https://www.loc.gov/maps/?{}", FULL_QUERY));
+}
+
+#[test]
+fn test_canonical_item_url() {
+    let endpoint = Endpoints::Item {
+        item_id: "2014717546".to_string(),
+        params: ItemParams {
+            format: None,
+            attributes: Some(ItemAttributes { item: Some(true), resources: Some(true), cite_this: None, ..Default::default() }),
+        },
+    };
+    assert_eq!(endpoint.to_url().unwrap(), "https://www.loc.gov/item/2014717546/?fo=json&at=item,resources");
+
+    let lean = Endpoints::Item {
+        item_id: "2014717546".to_string(),
+        params: ItemParams {
+            format: None,
+            attributes: Some(ItemAttributes {
+                exclude: ExcludedAttributes { related_items: Some(true), more_like_this: Some(true), ..Default::default() },
+                ..Default::default()
+            }),
+        },
+    };
+    let url = lean.to_url().unwrap();
+    assert_eq!(url, "https://www.loc.gov/item/2014717546/?fo=json&at!=more_like_this,related_items");
+    assert_eq!(Endpoints::parse(&url).unwrap().to_url().unwrap(), url);
+
+    let conflict = ItemAttributes {
+        item: Some(true),
+        exclude: ExcludedAttributes { item: Some(true), ..Default::default() },
+        ..Default::default()
+    };
+    assert!(conflict.validate().is_err());
+
+    let bare = Endpoints::Item { item_id: "2014717546".to_string(), params: ItemParams::default() };
+    assert_eq!(bare.to_url().unwrap(), "https://www.loc.gov/item/2014717546/?fo=json");
+}
+
+#[test]
+fn test_nested_attribute_paths() {
+    let endpoint = Endpoints::Item {
+        item_id: "2014717546".to_string(),
+        params: ItemParams {
+            format: None,
+            attributes: Some(ItemAttributes {
+                fields: vec!["item.title".to_string(), "item.date".to_string()],
+                ..Default::default()
+            }),
+        },
+    };
+    assert_eq!(endpoint.validate(), Ok(vec![]));
+    let url = endpoint.to_url().unwrap();
+    assert_eq!(url, "https://www.loc.gov/item/2014717546/?fo=json&at=item.date,item.title");
+    assert_eq!(Endpoints::parse(&url).unwrap().to_url().unwrap(), url);
+
+    let invalid = ItemAttributes { fields: vec!["item.".to_string(), "Item.title".to_string()], ..Default::default() };
+    assert_eq!(invalid.validate(), Err(AttributesError::InvalidPath("Item.title".to_string())));
+
+    let excluded = ItemAttributes {
+        fields: vec!["item.title".to_string()],
+        exclude: ExcludedAttributes { item: Some(true), ..Default::default() },
+        ..Default::default()
+    };
+    assert_eq!(excluded.validate(), Err(AttributesError::Conflict(vec!["item.title".to_string()])));
+
+    // A sibling sharing a prefix is not nested under an excluded block.
+    let search = AttributesSelect { include: vec!["resources.url".to_string()], exclude: vec!["resource".to_string()] };
+    assert_eq!(search.validate(), Ok(vec![]));
+}
+
+#[test]
+fn test_canonical_resource_url() {
+    let endpoint = Endpoints::Resource {
+        resource_id: "mal.0440500".to_string(),
+        params: ResourceParams {
+            format: None,
+            attributes: Some(ResourceAttributes { segments: Some(true), page: Some(true), ..Default::default() }),
+            page: Some(3),
+        },
+    };
+    assert_eq!(
+        endpoint.to_url().unwrap(),
+        "https://www.loc.gov/resource/mal.0440500/?fo=json&at=page,segments&sp=3"
+    );
+}
+
+#[test]
+fn test_parse_round_trips_canonical_urls() {
+    let endpoints = [
+        Endpoints::Search(SearchParams { common: full_params(), include_collections: false }),
+        Endpoints::Collections(full_params()),
+        Endpoints::Collection { name: "civil-war-maps".to_string(), params: full_params() },
+        Endpoints::Format { format: MediaType::FilmAndVideos, params: full_params() },
+        Endpoints::Item {
+            item_id: "2014717546".to_string(),
+            params: ItemParams { format: None, attributes: Some(ItemAttributes { item: Some(true), ..Default::default() }) },
+        },
+        Endpoints::Resource {
+            resource_id: "sn83045462/1914-07-28/ed-1".to_string(),
+            params: ResourceParams { format: None, attributes: None, page: Some(4) },
+        },
+    ];
+
+    for endpoint in endpoints {
+        let url = endpoint.to_url().unwrap();
+        assert_eq!(Endpoints::parse(&url).unwrap().to_url().unwrap(), url);
+    }
+}
+
+#[test]
+fn test_parse_facet_urls() {
+    let on = "https://www.loc.gov/photos/?fa=subject%3Abaseball%7Clocation!:new+york&fo=json&q=players";
+    let Endpoints::Format { format, params } = Endpoints::parse(on).unwrap() else { panic!("expected a format endpoint") };
+
+    assert_eq!(format, MediaType::Photos);
+    assert_eq!(params.query.as_deref(), Some("players"));
+    assert_eq!(params.filter.unwrap().to_query_param(), "subject:baseball|location!:new+york");
+}
+
+#[test]
+fn test_parse_decodes_the_query() {
+    let endpoint = Endpoints::Search(SearchParams {
+        common: CommonParams { query: Some("café & ñandú".to_string()), ..Default::default() },
+        include_collections: false,
+    });
+    let url = endpoint.to_url().unwrap();
+    assert_eq!(url, "https://www.loc.gov/search/?fo=json&q=caf%C3%A9+%26+%C3%B1and%C3%BA");
+
+    let Endpoints::Search(params) = Endpoints::parse(&url).unwrap() else { panic!("expected a search endpoint") };
+    assert_eq!(params.common.query.as_deref(), Some("café & ñandú"));
+    assert_eq!(Endpoints::Search(params).to_url().unwrap(), url);
+}
+
+#[test]
+fn test_parse_rejects_non_endpoints() {
+    assert!(Endpoints::parse("https://www.loc.gov/search/?c=many").is_err());
+    assert!(Endpoints::parse("https://www.loc.gov/item/2014717546/marcxml/").is_err());
+    assert!(Endpoints::parse("https://www.loc.gov/").is_err());
+}
+
+#[test]
+fn test_catalog_matches_urls() {
+    let endpoints = [
+        Endpoints::Search(SearchParams { common: full_params(), include_collections: false }),
+        Endpoints::Collections(full_params()),
+        Endpoints::Collection { name: "civil war maps".to_string(), params: full_params() },
+        Endpoints::Format { format: MediaType::Maps, params: full_params() },
+        Endpoints::Item {
+            item_id: "2014717546".to_string(),
+            params: ItemParams { format: None, attributes: Some(ItemAttributes { cite_this: Some(true), item: Some(true), resources: Some(true), ..Default::default() }) },
+        },
+        Endpoints::Resource {
+            resource_id: "gdcmassbookdig.americanwarpoems00".to_string(),
+            params: ResourceParams {
+                format: None,
+                attributes: Some(ResourceAttributes { segments: Some(true), ..ResourceAttributes::default() }),
+                page: Some(3),
+            },
+        },
+    ];
+    assert_eq!(Endpoints::catalog().len(), endpoints.len());
+
+    for endpoint in &endpoints {
+        let info = endpoint.info();
+        assert!(Endpoints::catalog().contains(info));
+
+        let url = endpoint.url_with_base("");
+        let (path, query) = url.split_once('?').unwrap();
+        let pattern: Vec<&str> = info.path.split('/').collect();
+        let segments: Vec<&str> = path.split('/').collect();
+        assert_eq!(pattern.len(), segments.len(), "{}: {}", info.name, url);
+        for (expected, segment) in pattern.iter().zip(&segments) {
+            if expected.starts_with('{') {
+                let key = expected.trim_matches(|c| c == '{' || c == '}');
+                let param = info.path_params.iter().find(|param| param.key == key).unwrap();
+                assert!(param.values.is_empty() || param.values.contains(segment), "{}: {}", info.name, segment);
+            } else {
+                assert_eq!(expected, segment, "{}", info.name);
+            }
+        }
+
+        for pair in query.split('&') {
+            let key = pair.split('=').next().unwrap();
+            assert!(info.query_params.iter().any(|param| param.key == key), "{}: {}", info.name, key);
+        }
+    }
+}
+
+/// Every excluded block, so that `at!` is emitted.
+fn all_excluded() -> ExcludedAttributes {
+    ExcludedAttributes {
+        articles_and_essays: Some(true),
+        cite_this: Some(true),
+        item: Some(true),
+        more_like_this: Some(true),
+        page: Some(true),
+        related_items: Some(true),
+        resource: Some(true),
+        resources: Some(true),
+        segments: Some(true),
+        traditional_knowledge_labels: Some(true),
+    }
+}
+
+#[test]
+fn test_catalog_lists_every_query_key() {
+    // Struct literals without `..Default::default()`, so that a new parameter field fails to
+    // compile here until it is set and its key checked against the catalog.
+    let item = ItemParams {
+        format: Some(Format::Json),
+        attributes: Some(ItemAttributes {
+            cite_this: Some(true),
+            item: Some(true),
+            resources: Some(true),
+            fields: vec!["item.title".to_string()],
+            exclude: all_excluded(),
+        }),
+    };
+    let resource = ResourceParams {
+        format: Some(Format::Json),
+        attributes: Some(ResourceAttributes {
+            cite_this: Some(true),
+            item: Some(true),
+            page: Some(true),
+            resource: Some(true),
+            resources: Some(true),
+            segments: Some(true),
+            fields: vec!["resource.title".to_string()],
+            exclude: all_excluded(),
+        }),
+        page: Some(2),
+    };
+    let endpoints = [
+        Endpoints::Search(SearchParams { common: full_params(), include_collections: true }),
+        Endpoints::Collections(full_params()),
+        Endpoints::Collection { name: "civil-war-maps".to_string(), params: full_params() },
+        Endpoints::Format { format: MediaType::Maps, params: full_params() },
+        Endpoints::Item { item_id: "2014717546".to_string(), params: item },
+        Endpoints::Resource { resource_id: "mal.0440500".to_string(), params: resource },
+    ];
+
+    for endpoint in &endpoints {
+        let info = endpoint.info();
+        let mut emitted: Vec<&str> = endpoint.query_pairs().into_iter().map(|(key, _)| key).collect();
+        let mut listed: Vec<&str> = info.query_params.iter().map(|param| param.key).collect();
+        emitted.sort_unstable();
+        listed.sort_unstable();
+        assert_eq!(emitted, listed, "{}", info.name);
+    }
+}
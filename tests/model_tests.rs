@@ -0,0 +1,294 @@
+use loc_api::export::bibtex;
+use loc_api::export::zotero::{self, ZoteroItem};
+use loc_api::response_models::{ItemBrief, ItemOrArray, LazySearchResultResponse, ResourceResponse, SearchResultResponse, StringOrArray};
+use loc_api::snapshot::Persist;
+
+const SEARCH: &str = include_str!("fixtures/search.json");
+const RESOURCE: &str = include_str!("fixtures/resource.json");
+
+#[test]
+fn test_type_field_from_type_key() {
+    let response: SearchResultResponse = serde_json::from_str(SEARCH).unwrap();
+    let result = &response.results.unwrap()[0];
+
+    assert!(matches!(&result.type_field, Some(StringOrArray::Array(types)) if types == &["photograph"]));
+    #[cfg(not(feature = "lean"))]
+    assert!(result.additional.as_ref().unwrap().get("type").is_none());
+}
+
+#[test]
+fn test_type_and_use_fields_in_resource() {
+    let response: ResourceResponse = serde_json::from_str(RESOURCE).unwrap();
+
+    assert_eq!(response.type_field.as_ref().and_then(StringOrArray::first_str), Some("resource"));
+    let Some(ItemOrArray::Item(detail)) = &response.resource else { panic!("expected a single resource") };
+    assert_eq!(detail.type_field.as_ref().and_then(StringOrArray::first_str), Some("image"));
+
+    let Some(ItemOrArray::Array(resources)) = &response.resources else { panic!("expected resources") };
+    let files = resources[0].all_files();
+    assert_eq!(files[1].use_field.as_ref().and_then(StringOrArray::first_str), Some("master"));
+}
+
+#[test]
+fn test_type_field_serializes_as_type() {
+    let response: SearchResultResponse = serde_json::from_str(SEARCH).unwrap();
+    let json = serde_json::to_value(&response).unwrap();
+
+    assert_eq!(json["results"][0]["type"][0], "photograph");
+    assert!(json["results"][0].get("type_field").is_none());
+}
+
+#[test]
+fn test_facet_summary_counts() {
+    let response: SearchResultResponse = serde_json::from_str(SEARCH).unwrap();
+    let facets = response.facets();
+
+    assert_eq!(facets.subject().top(10), vec![("baseball".to_string(), 1203)]);
+    assert_eq!(facets.subject().count("baseball"), Some(1203));
+    assert!(facets.location().is_empty());
+}
+
+#[test]
+fn test_snapshot_round_trip() {
+    let response: ResourceResponse = serde_json::from_str(RESOURCE).unwrap();
+    let path = std::env::temp_dir().join(format!("loc_api_snapshot_{}", std::process::id())).join("resource.json");
+
+    response.save("https://www.loc.gov/resource/mal.0440500/?fo=json", &path).unwrap();
+    let snapshot = ResourceResponse::load(&path).unwrap();
+
+    assert_eq!(snapshot.url, "https://www.loc.gov/resource/mal.0440500/?fo=json");
+    assert!(snapshot.fetched_at > 0);
+    assert_eq!(serde_json::to_value(&snapshot.response).unwrap(), serde_json::to_value(&response).unwrap());
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_briefs_of_search_results() {
+    let response: SearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+    let briefs = response.briefs();
+
+    assert_eq!(briefs.len(), 2);
+    assert_eq!(
+        briefs[1],
+        ItemBrief {
+            id: Some("http://www.loc.gov/item/2007683735/".to_string()),
+            title: Some("Base ball. Champions of the world".to_string()),
+            date: Some("1889".to_string()),
+            url: Some("https://www.loc.gov/item/2007683735/".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_lazy_results_match_full_results() {
+    let full: SearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+    let lazy: LazySearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+
+    assert_eq!(lazy.briefs(), full.briefs());
+    assert!(lazy.pagination.is_some() && full.pagination.is_some());
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn test_schemas_describe_result_fields() {
+    let schemas = loc_api::schemas();
+    assert!(schemas.contains_key("ItemResponse"));
+
+    let search = serde_json::to_value(&schemas["SearchResultResponse"]).unwrap();
+    let fixture: serde_json::Value = serde_json::from_str(SEARCH).unwrap();
+    for key in ["results", "pagination", "facets"] {
+        assert!(fixture.get(key).is_some());
+        assert!(search["properties"].get(key).is_some(), "missing {}", key);
+    }
+
+    let result = serde_json::to_value(&schemas["ResultItem"]).unwrap();
+    for key in ["id", "title", "date", "url"] {
+        assert!(result["properties"].get(key).is_some(), "missing {}", key);
+    }
+}
+
+#[test]
+fn test_zotero_item_from_item_response() {
+    let response: loc_api::response_models::ItemResponse = serde_json::from_str(loc_api::fixtures::ITEM).unwrap();
+    let item = ZoteroItem::from_response(&response).unwrap();
+    assert_eq!(item.item_type, "artwork");
+    assert_eq!(item.title, "Baseball players practicing");
+    assert_eq!(item.date, "1887");
+    assert_eq!(item.extra.lines().count(), 3);
+    assert!(item.extra.starts_with("Chicago: Baseball players practicing."));
+
+    let mut writer = zotero::Writer::new(Vec::new());
+    writer.write_item(&item).unwrap();
+    let rdf = String::from_utf8(writer.finish().unwrap()).unwrap();
+    assert!(rdf.contains("<bib:Illustration"));
+    assert!(rdf.contains("<z:itemType>artwork</z:itemType>"));
+    assert_eq!(rdf.matches("<dc:description>").count(), 1);
+}
+
+#[test]
+fn test_bibtex_keys_are_stable_and_unique() {
+    let response: SearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+    let results = response.results.unwrap();
+
+    let mut writer = bibtex::Writer::new(Vec::new());
+    let keys: Vec<_> = results.iter().map(|result| writer.write_item(&result.into()).unwrap()).collect();
+    assert!(keys.iter().all(|key| key.as_deref().is_some_and(|key| key.starts_with("loc"))));
+    // A second page repeating the same items adds nothing.
+    assert!(results.iter().all(|result| writer.write_item(&result.into()).unwrap().is_none()));
+    assert_eq!(writer.written(), results.len());
+
+    let untitled = ZoteroItem { title: "Untitled".to_string(), ..ZoteroItem::default() };
+    assert_eq!(writer.write_item(&untitled).unwrap().as_deref(), Some("locuntitled"));
+    assert_eq!(writer.write_item(&untitled).unwrap().as_deref(), Some("locuntitled-2"));
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn test_sqlite_store_keeps_item_details() {
+    use loc_api::export::sqlite::Store;
+
+    let item: loc_api::response_models::ItemResponse = serde_json::from_str(loc_api::fixtures::ITEM).unwrap();
+    let search: SearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+    let mut store = Store::open_in_memory().unwrap();
+    assert!(store.upsert_item(&item).unwrap());
+    let stored = store.upsert_response(&search).unwrap();
+    // Storing the same page again only updates the rows.
+    assert_eq!(store.upsert_response(&search).unwrap(), stored);
+
+    let count = |sql: &str| -> i64 { store.connection().query_row(sql, [], |row| row.get(0)).unwrap() };
+    assert_eq!(stored, 2);
+    assert_eq!(count("SELECT count(*) FROM items"), 2);
+    assert_eq!(count("SELECT count(*) FROM items WHERE result_json IS NOT NULL AND item_json IS NOT NULL"), 1);
+}
+
+#[cfg(feature = "tantivy")]
+#[test]
+fn test_index_searches_and_counts_facets() {
+    use loc_api::index::Index;
+
+    let search: SearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+    let mut index = Index::in_memory().unwrap();
+    for result in search.results.iter().flatten() {
+        assert!(index.add_result(result, None).unwrap());
+    }
+    // Adding an item again replaces its document, here with its full text.
+    let first = &search.results.as_ref().unwrap()[0];
+    index.add_result(first, Some("Players warming up before the game")).unwrap();
+    index.commit().unwrap();
+
+    assert_eq!(index.len(), 2);
+    let hits = index.search("warming", &[], 10).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].id, "2014717546");
+    assert_eq!(index.count("baseball", &[("subject", "sports")]).unwrap(), 1);
+    assert_eq!(
+        index.facet_counts("subject", "", &[]).unwrap(),
+        vec![("baseball".to_string(), 2), ("sports".to_string(), 1)]
+    );
+    assert!(index.search("title:(", &[], 10).is_err());
+}
+
+#[cfg(feature = "polars")]
+#[test]
+fn test_dataframe_of_normalized_items() {
+    use loc_api::export::polars::to_dataframe;
+    use loc_api::mapping::NormalizedItem;
+    use loc_api::response_models::ItemResponse;
+
+    let search: SearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+    let item: ItemResponse = serde_json::from_str(loc_api::fixtures::ITEM).unwrap();
+    let mut items: Vec<NormalizedItem> = search.results.iter().flatten().map(NormalizedItem::from).collect();
+    if let Some(ItemOrArray::Item(attribute)) = &item.item {
+        items.push(NormalizedItem::from(attribute));
+    }
+    assert_eq!(items[0].id, items[2].id);
+
+    let df = to_dataframe(&items).unwrap();
+    assert_eq!(df.shape(), (3, 13));
+    let subjects = df.column("subjects").unwrap().list().unwrap();
+    assert_eq!(subjects.get_as_series(0).unwrap().len(), 2);
+    assert_eq!(df.column("year").unwrap().i32().unwrap().get(1), Some(1889));
+    assert_eq!(to_dataframe(&[]).unwrap().column("subjects").unwrap().dtype(), df.column("subjects").unwrap().dtype());
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd_parses_fixtures_like_serde_json() {
+    use loc_api::fixtures;
+    use loc_api::response_models::{from_slice, CollectionResponse, ItemResponse, LazyItemResponse};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    fn both_ways<T: DeserializeOwned + Serialize + 'static>(json: &str) {
+        let simd: T = simd_json::serde::from_slice(&mut json.as_bytes().to_vec()).unwrap();
+        let serde: T = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&simd).unwrap(), serde_json::to_value(&serde).unwrap());
+        let decoded: T = from_slice(&mut json.as_bytes().to_vec()).unwrap();
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&serde).unwrap());
+    }
+
+    both_ways::<SearchResultResponse>(fixtures::SEARCH);
+    both_ways::<ItemResponse>(fixtures::ITEM);
+    both_ways::<ResourceResponse>(fixtures::RESOURCE);
+    both_ways::<CollectionResponse>(fixtures::COLLECTION);
+
+    // The RawValue-backed models, which simd-json cannot build, go to serde_json.
+    let lazy: LazySearchResultResponse = from_slice(&mut fixtures::SEARCH.as_bytes().to_vec()).unwrap();
+    assert_eq!(lazy.results.unwrap().len(), 2);
+    assert!(from_slice::<LazyItemResponse>(&mut fixtures::ITEM.as_bytes().to_vec()).unwrap().item.is_some());
+}
+
+#[test]
+fn test_geojson_of_map_bounding_box() {
+    use loc_api::export::geojson::{Exporter, Geometry};
+    use loc_api::response_models::ItemAttribute;
+
+    let map: ItemAttribute = serde_json::from_str(
+        r#"{"id": "http://www.loc.gov/item/2011585214/", "title": "Map of San Francisco",
+            "coordinates": ["(W 122°30ʹ--W 122°21ʹ/N 37°49ʹ--N 37°42ʹ)"]}"#,
+    )
+    .unwrap();
+    let unplaced: ItemAttribute =
+        serde_json::from_str(r#"{"id": "http://www.loc.gov/item/2014717546/", "locations": ["atlantis"]}"#).unwrap();
+
+    let mut exporter = Exporter::new().geocoder(|_: &str| None);
+    assert!(exporter.add_item(&map));
+    assert!(!exporter.add_item(&unplaced));
+    assert_eq!(exporter.skipped(), 1);
+
+    let collection = exporter.finish();
+    let Geometry::Polygon(rings) = &collection.features[0].geometry else { panic!("expected a polygon") };
+    assert_eq!(rings[0].len(), 5);
+    assert_eq!(rings[0][0], [-122.5, 37.7]);
+    assert_eq!(rings[0][2], [-122.35, 49.0 / 60.0 + 37.0]);
+    assert_eq!(collection.features[0].id.as_deref(), Some("2011585214"));
+}
+
+#[test]
+fn test_item_metadata_is_shared_by_results_and_items() {
+    use loc_api::response_models::{HasItemMetadata, ItemResponse};
+
+    fn metadata(item: &impl HasItemMetadata) -> (Option<&str>, Option<&str>, Vec<&str>) {
+        (item.title(), item.date(), item.subjects())
+    }
+
+    let search: SearchResultResponse = serde_json::from_str(loc_api::fixtures::SEARCH).unwrap();
+    let item: ItemResponse = serde_json::from_str(loc_api::fixtures::ITEM).unwrap();
+    let Some(ItemOrArray::Item(attribute)) = &item.item else { panic!("expected a single item") };
+    let result = &search.results.as_ref().unwrap()[0];
+    assert_eq!(metadata(result), metadata(attribute));
+    assert_eq!(metadata(attribute), (Some("Baseball players practicing"), Some("1887"), vec!["baseball", "sports"]));
+}
+
+#[test]
+fn test_master_files_with_oversized_dimensions() {
+    let json = r#"{"resources": [{"files": [[
+        {"mimetype": "image/tiff", "url": "https://tile.loc.gov/0001f.tif", "width": 4000, "height": 2560},
+        {"mimetype": "image/jp2", "url": "https://tile.loc.gov/0001f.jp2", "width": "18446744073709551615", "height": 2}
+    ]]}]}"#;
+    let item: loc_api::response_models::ItemResponse = serde_json::from_str(json).unwrap();
+
+    let masters = item.master_files();
+    assert_eq!(masters[0].url, "https://tile.loc.gov/0001f.jp2");
+    assert_eq!(masters[1].url, "https://tile.loc.gov/0001f.tif");
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use loc_api::attribute_models::{AttributesSelect, SortOrder};
+use loc_api::param_models::{CommonParams, Facet, FacetReq, ItemParams, ResourceParams, SearchParams};
+use loc_api::response_models::{
+    CollectionResponse, CollectionsResponse, FormatResponse, ItemBrief, ItemOrArray, LazyItemResponse,
+    LazySearchResultResponse, NumberOrString, Pagination, ResultItem, SearchResponse,
+    SearchResultResponse, StringOrArray, WordCoordinates,
+};
+
+/// Number of generated values checked per type.
+const CASES: usize = 300;
+
+/// Checks that every generated `T` reads back from its JSON and serializes to the same JSON.
+fn assert_round_trips<T>(name: &str)
+where
+    T: for<'a> Arbitrary<'a> + Serialize + DeserializeOwned,
+{
+    // A fixed xorshift sequence keeps failures reproducible.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut checked = 0;
+    for case in 0..CASES {
+        let bytes: Vec<u8> = (0..64 + case * 16)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 24) as u8
+            })
+            .collect();
+        let Ok(value) = T::arbitrary(&mut Unstructured::new(&bytes)) else { continue };
+
+        let json = serde_json::to_string(&value).unwrap();
+        let parsed: T = serde_json::from_str(&json).unwrap_or_else(|e| panic!("{} case {}: {}\n{}", name, case, e, json));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json, "{} case {}", name, case);
+        checked += 1;
+    }
+    assert!(checked > CASES / 2, "{}: only {} values generated", name, checked);
+}
+
+#[test]
+fn test_union_types_round_trip() {
+    assert_round_trips::<StringOrArray>("StringOrArray");
+    assert_round_trips::<NumberOrString>("NumberOrString");
+    assert_round_trips::<ItemOrArray<String>>("ItemOrArray<String>");
+    assert_round_trips::<ItemOrArray<NumberOrString>>("ItemOrArray<NumberOrString>");
+    assert_round_trips::<ItemOrArray<ItemOrArray<u32>>>("ItemOrArray<ItemOrArray<u32>>");
+}
+
+#[test]
+fn test_param_models_round_trip() {
+    assert_round_trips::<CommonParams>("CommonParams");
+    assert_round_trips::<SearchParams>("SearchParams");
+    assert_round_trips::<ItemParams>("ItemParams");
+    assert_round_trips::<ResourceParams>("ResourceParams");
+    assert_round_trips::<FacetReq>("FacetReq");
+    assert_round_trips::<Facet>("Facet");
+    assert_round_trips::<AttributesSelect>("AttributesSelect");
+    assert_round_trips::<SortOrder>("SortOrder");
+}
+
+#[test]
+fn test_response_models_round_trip() {
+    assert_round_trips::<Pagination>("Pagination");
+    assert_round_trips::<ResultItem>("ResultItem");
+    assert_round_trips::<ItemBrief>("ItemBrief");
+    assert_round_trips::<SearchResultResponse>("SearchResultResponse");
+    assert_round_trips::<SearchResponse>("SearchResponse");
+    assert_round_trips::<CollectionsResponse>("CollectionsResponse");
+    assert_round_trips::<CollectionResponse>("CollectionResponse");
+    assert_round_trips::<FormatResponse>("FormatResponse");
+    // With `lean`, `Page`, `RelatedItem` and `MoreLikeThis` have no fields left, so an empty
+    // array of them reads back as a single empty item.
+    #[cfg(not(feature = "lean"))]
+    assert_round_trips::<loc_api::response_models::ResourceResponse>("ResourceResponse");
+    assert_round_trips::<WordCoordinates>("WordCoordinates");
+    assert_round_trips::<LazySearchResultResponse>("LazySearchResultResponse");
+    assert_round_trips::<LazyItemResponse>("LazyItemResponse");
+}